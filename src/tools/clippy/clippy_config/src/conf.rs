@@ -2,12 +2,13 @@
 use crate::types::{DisallowedPath, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename};
 use crate::ClippyConfiguration;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::Applicability;
-use rustc_session::Session;
+use rustc_errors::{Applicability, DiagCtxt};
 use rustc_span::edit_distance::edit_distance;
+use rustc_span::source_map::SourceMap;
 use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
 use serde::de::{IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
 use std::path::PathBuf;
@@ -39,6 +40,7 @@
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
+const DEFAULT_HARDCODED_PATH_PREFIXES: &[&str] = &["/tmp", "/home/", "/Users/", "C:\\"];
 
 /// Conf with parse errors
 #[derive(Default)]
@@ -110,6 +112,12 @@ macro_rules! default_text {
     };
 }
 
+fn toml_value(value: &impl Serialize) -> Option<String> {
+    let mut text = String::new();
+    value.serialize(toml::ser::ValueSerializer::new(&mut text)).ok()?;
+    Some(text)
+}
+
 macro_rules! define_Conf {
     ($(
         $(#[doc = $doc:literal])+
@@ -133,6 +141,14 @@ fn default() -> Self {
             }
         }
 
+        impl Conf {
+            /// Returns the name of every option with its value serialized as TOML, or `None` if the
+            /// option is not set
+            pub fn values(&self) -> Vec<(&'static str, Option<String>)> {
+                vec![$((stringify!($name), toml_value(&self.$name)),)*]
+            }
+        }
+
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
@@ -258,11 +274,11 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// arithmetic-side-effects-allowed-unary = ["SomeType", "AnotherType"]
     /// ```
     (arithmetic_side_effects_allowed_unary: FxHashSet<String> = <_>::default()),
-    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS, SINGLE_CALL_FN.
+    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS, SINGLE_CALL_FN, NEEDLESS_PASS_BY_VALUE.
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, MANUAL_C_STR_LITERALS.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, MANUAL_C_STR_LITERALS, FLOAT_SORT_BY_PARTIAL_CMP, UNCHECKED_INSTANT_DIFFERENCE, MANUAL_OPTION_ZIP, MANUAL_OPTION_XOR, CAST_SIGNED_REM_TO_USIZE, CONSTANT_FLOAT_COMPUTATION.
     ///
     /// The minimum rust version that the project supports. Defaults to the `rust-version` field in `Cargo.toml`
     #[default_text = ""]
@@ -367,7 +383,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     (stack_size_threshold: u64 = 512_000),
     /// Lint: VEC_BOX.
     ///
-    /// The size of the boxed type in bytes, where boxing in a `Vec` is allowed
+    /// The size of the boxed type in bytes, where boxing in a `Vec` or another collection is allowed
     (vec_box_size_threshold: u64 = 4096),
     /// Lint: TYPE_REPETITION_IN_BOUNDS.
     ///
@@ -589,6 +605,187 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// 2. Paths with any segment that containing the word 'prelude'
     /// are already allowed by default.
     (allowed_wildcard_imports: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: REGEX_CREATION_IN_LOOPS.
+    ///
+    /// Additional constructors of values that are expensive to create, written as fully qualified
+    /// paths. Calls to these with constant arguments are linted when made in a loop.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// expensive-constructors = [ "my_crate::Grammar::compile" ]
+    /// ```
+    (expensive_constructors: Vec<String> = Vec::new()),
+    /// Lint: MISSING_DEBUG_IMPL.
+    ///
+    /// Names or paths of exported types that are not required to implement `Debug`. Paths start
+    /// with the name of the crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// missing-debug-impl-ignore-types = [ "Handle", "my_crate::ffi::RawContext" ]
+    /// ```
+    (missing_debug_impl_ignore_types: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: EXIT.
+    ///
+    /// Names or paths of functions that are allowed to call `std::process::exit` and
+    /// `std::process::abort`, in addition to `main`. Paths start with the name of the crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-exit-callers = [ "fatal_error", "my_crate::cli::run" ]
+    /// ```
+    (allowed_exit_callers: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: LIBRARY_ENV_ACCESS.
+    ///
+    /// Paths of modules, starting with the name of the crate, that are allowed to access the
+    /// environment of the process. Their submodules are allowed as well.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-env-modules = [ "my_crate::config" ]
+    /// ```
+    (allowed_env_modules: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: HARDCODED_PATHS.
+    ///
+    /// Prefixes of path literals that only exist on some platforms.
+    (hardcoded_path_prefixes: Vec<String> = DEFAULT_HARDCODED_PATH_PREFIXES.iter().map(ToString::to_string).collect()),
+    /// Lint: CAST_POSSIBLE_TRUNCATION, TRANSMUTE_POINTER_SIZED_INT.
+    ///
+    /// The pointer widths, in bits, of the targets the crate is built for. Casts and transmutes
+    /// are only linted when they behave differently on one of these targets.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// supported-pointer-widths = [ 64 ]
+    /// ```
+    (supported_pointer_widths: Vec<u64> = vec![32, 64]),
+    /// Lint: PATTERN_TYPE_MISMATCH.
+    ///
+    /// Whether to only lint patterns that implicitly match through `&mut` references, i.e. the ones
+    /// introducing `ref mut` bindings
+    (pattern_type_mismatch_mut_only: bool = false),
+    /// Lint: SHADOW_SAME, SHADOW_REUSE, SHADOW_UNRELATED.
+    ///
+    /// Names that may always be shadowed, e.g. `buf` or `s`.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-shadow-names = [ "buf", "s" ]
+    /// ```
+    (allowed_shadow_names: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: SIGNIFICANT_DROP_IN_SCRUTINEE.
+    ///
+    /// A list of paths to types that have a significant `Drop`, in addition to the types marked
+    /// with the `#[clippy::has_significant_drop]` attribute
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// significant-drop-types = [ "my_crate::sync::Guard" ]
+    /// ```
+    (significant_drop_types: Vec<String> = Vec::new()),
+    /// Lint: EXCESSIVE_NESTING.
+    ///
+    /// Whether to measure the nesting of each function body on its own, ignoring the nesting of
+    /// items, and point at its most deeply nested block
+    (excessive_nesting_per_fn: bool = false),
+    /// Lint: RESULT_ERASED_ERR.
+    ///
+    /// Paths of error types which erase the type of the underlying error, in addition to
+    /// `Box<dyn Error>`.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// erased-error-types = [ "anyhow::Error", "eyre::Report" ]
+    /// ```
+    (erased_error_types: Vec<String> = Vec::new()),
+    /// Lint: EXPENSIVE_LOG_ARGUMENTS.
+    ///
+    /// Paths of additional logging macros, besides the ones of the `log` and `tracing` crates
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// logging-macros = [ "my_crate::log_event" ]
+    /// ```
+    (logging_macros: Vec<String> = Vec::new()),
+    /// Lint: EXPENSIVE_LOG_ARGUMENTS.
+    ///
+    /// Paths of functions which are expensive to call, and should not be called to compute the
+    /// arguments of logging macros
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// expensive-log-argument-fns = [ "my_crate::dump_state" ]
+    /// ```
+    (expensive_log_argument_fns: Vec<String> = Vec::new()),
+    /// Lint: SUBOPTIMAL_FLOPS.
+    ///
+    /// Whether to also suggest `x.recip()` for `1.0 / x` when the result is multiplied further
+    (check_reciprocal_division: bool = false),
+    /// Lint: SUBOPTIMAL_FLOPS.
+    ///
+    /// Whether to emit suggestions that only improve performance, like `mul_add` or `powi`. On targets
+    /// without an FPU these can be counterproductive, so disabling this only keeps the accuracy related ones
+    (flops_performance_suggestions: bool = true),
+    /// Lint: SUBOPTIMAL_FLOPS.
+    ///
+    /// Whether to emit the suggestions that only improve performance in test functions or `#[cfg(test)]`, if
+    /// `flops-performance-suggestions` is enabled
+    (flops_performance_suggestions_in_tests: bool = true),
+    /// Lint: IMPRECISE_FLOPS, SUBOPTIMAL_FLOPS.
+    ///
+    /// Whether to suggest the functions of the `libm` crate in `#![no_std]` crates, where float methods
+    /// like `ln_1p`, `exp_m1` and `mul_add` are not available
+    (suggest_libm: bool = false),
+    /// Lint: INDEXING_SLICING.
+    ///
+    /// Whether indexing and slicing should be allowed in test functions or `#[cfg(test)]`
+    (allow_indexing_slicing_in_tests: bool = false),
+    /// Custom lint groups, each registered as `clippy::<name>`. A group can contain Clippy lints, rustc
+    /// lints and other lint groups, and is enabled or disabled like any other lint group
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// lint-groups = { numerics = ["clippy::float_cmp", "clippy::imprecise_flops", "clippy::suboptimal_flops"] }
+    /// ```
+    (lint_groups: BTreeMap<String, Vec<String>> = BTreeMap::new()),
+    /// Lints and lint groups to allow in every compiled target, like passing `-A` to each of them.
+    /// Attributes in the code and lint levels from the command line or `Cargo.toml` take precedence
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allow = ["clippy::needless_return"]
+    /// ```
+    (allow: Vec<String> = Vec::new()),
+    /// Lints and lint groups to warn on in every compiled target, like passing `-W` to each of them.
+    /// Attributes in the code and lint levels from the command line or `Cargo.toml` take precedence
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// warn = ["clippy::pedantic"]
+    /// ```
+    (warn: Vec<String> = Vec::new()),
+    /// Lints and lint groups to deny in every compiled target, like passing `-D` to each of them.
+    /// Attributes in the code and lint levels from the command line or `Cargo.toml` take precedence
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// deny = ["clippy::float_cmp"]
+    /// ```
+    (deny: Vec<String> = Vec::new()),
 }
 
 /// Search for the configuration file.
@@ -644,6 +841,30 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     }
 }
 
+/// The lint levels set with the `allow`, `warn` and `deny` options.
+///
+/// The driver has to know them before the compiler session is created, so they are read on their
+/// own ahead of the rest of the configuration. Errors in them are reported by [`Conf::read`].
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct LintLevels {
+    pub allow: Vec<String>,
+    pub warn: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl LintLevels {
+    pub fn read(path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Self {
+        match path {
+            Ok((Some(path), _)) => fs::read_to_string(path)
+                .ok()
+                .and_then(|src| toml::from_str(&src).ok())
+                .unwrap_or_default(),
+            _ => Self::default(),
+        }
+    }
+}
+
 fn deserialize(file: &SourceFile) -> TryConf {
     match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
         Ok(mut conf) => {
@@ -669,21 +890,24 @@ fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
 }
 
 impl Conf {
-    pub fn read(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> &'static Conf {
+    pub fn read(
+        dcx: &DiagCtxt,
+        source_map: &SourceMap,
+        path: &io::Result<(Option<PathBuf>, Vec<String>)>,
+    ) -> &'static Conf {
         static CONF: OnceLock<Conf> = OnceLock::new();
-        CONF.get_or_init(|| Conf::read_inner(sess, path))
+        CONF.get_or_init(|| Conf::read_inner(dcx, source_map, path))
     }
 
-    fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Conf {
+    fn read_inner(dcx: &DiagCtxt, source_map: &SourceMap, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Conf {
         match path {
             Ok((_, warnings)) => {
                 for warning in warnings {
-                    sess.dcx().warn(warning.clone());
+                    dcx.warn(warning.clone());
                 }
             },
             Err(error) => {
-                sess.dcx()
-                    .err(format!("error finding Clippy's configuration file: {error}"));
+                dcx.err(format!("error finding Clippy's configuration file: {error}"));
             },
         }
 
@@ -692,21 +916,21 @@ fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>)
             errors,
             warnings,
         } = match path {
-            Ok((Some(path), _)) => match sess.source_map().load_file(path) {
+            Ok((Some(path), _)) => match source_map.load_file(path) {
                 Ok(file) => deserialize(&file),
                 Err(error) => {
-                    sess.dcx().err(format!("failed to read `{}`: {error}", path.display()));
+                    dcx.err(format!("failed to read `{}`: {error}", path.display()));
                     TryConf::default()
                 },
             },
             _ => TryConf::default(),
         };
 
-        conf.msrv.read_cargo(sess);
+        conf.msrv.read_cargo(dcx);
 
         // all conf errors are non-fatal, we just use the default conf in case of error
         for error in errors {
-            let mut diag = sess.dcx().struct_span_err(
+            let mut diag = dcx.struct_span_err(
                 error.span,
                 format!("error reading Clippy's configuration file: {}", error.message),
             );
@@ -719,7 +943,7 @@ fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>)
         }
 
         for warning in warnings {
-            sess.dcx().span_warn(
+            dcx.span_warn(
                 warning.span,
                 format!("error reading Clippy's configuration file: {}", warning.message),
             );
@@ -21,5 +21,5 @@
 pub mod msrvs;
 pub mod types;
 
-pub use conf::{get_configuration_metadata, lookup_conf_file, Conf};
+pub use conf::{get_configuration_metadata, lookup_conf_file, Conf, LintLevels};
 pub use metadata::ClippyConfiguration;
@@ -110,6 +110,9 @@ fn parse_config_field_doc(doc_comment: &str) -> Option<(Vec<String>, String)> {
         documentation = documentation.trim_start_matches('.').trim().replace("\n ", "\n    ");
 
         Some((lints, documentation))
+    } else if !doc_comment.contains("Lint:") {
+        // Options that don't configure specific lints, like `lint-groups`
+        Some((Vec::new(), doc_comment.trim().replace("\n ", "\n    ")))
     } else {
         None
     }
@@ -1,8 +1,9 @@
 use rustc_ast::Attribute;
+use rustc_errors::DiagCtxt;
 use rustc_semver::RustcVersion;
 use rustc_session::Session;
 use rustc_span::{sym, Symbol};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 macro_rules! msrv_aliases {
@@ -23,24 +24,25 @@ macro_rules! msrv_aliases {
     1,70,0 { OPTION_RESULT_IS_VARIANT_AND, BINARY_HEAP_RETAIN }
     1,68,0 { PATH_MAIN_SEPARATOR_STR }
     1,65,0 { LET_ELSE, POINTER_CAST_CONSTNESS }
-    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE }
+    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE, TOTAL_CMP }
     1,59,0 { THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST }
     1,58,0 { FORMAT_ARGS_CAPTURE, PATTERN_TRAIT_CHAR_ARRAY }
     1,55,0 { SEEK_REWIND }
     1,54,0 { INTO_KEYS }
-    1,53,0 { OR_PATTERNS, MANUAL_BITS, BTREE_MAP_RETAIN, BTREE_SET_RETAIN, ARRAY_INTO_ITERATOR }
+    1,53,0 { OR_PATTERNS, MANUAL_BITS, BTREE_MAP_RETAIN, BTREE_SET_RETAIN, ARRAY_INTO_ITERATOR, DURATION_SATURATING_SUB }
     1,52,0 { STR_SPLIT_ONCE, REM_EUCLID_CONST }
     1,51,0 { BORROW_AS_PTR, SEEK_FROM_CURRENT, UNSIGNED_ABS }
     1,50,0 { BOOL_THEN, CLAMP }
     1,47,0 { TAU, IS_ASCII_DIGIT_CONST, ARRAY_IMPL_ANY_LEN }
-    1,46,0 { CONST_IF_MATCH }
+    1,46,0 { CONST_IF_MATCH, OPTION_ZIP }
     1,45,0 { STR_STRIP_PREFIX }
     1,43,0 { LOG2_10, LOG10_2 }
     1,42,0 { MATCHES_MACRO, SLICE_PATTERNS, PTR_SLICE_RAW_PARTS }
     1,41,0 { RE_REBALANCING_COHERENCE, RESULT_MAP_OR_ELSE }
     1,40,0 { MEM_TAKE, NON_EXHAUSTIVE, OPTION_AS_DEREF }
+    1,39,0 { INSTANT_SATURATING_DURATION_SINCE }
     1,38,0 { POINTER_CAST, REM_EUCLID }
-    1,37,0 { TYPE_ALIAS_ENUM_VARIANTS }
+    1,37,0 { TYPE_ALIAS_ENUM_VARIANTS, OPTION_XOR }
     1,36,0 { ITERATOR_COPIED }
     1,35,0 { OPTION_COPIED, RANGE_CONTAINS }
     1,34,0 { TRY_FROM }
@@ -84,12 +86,25 @@ fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     }
 }
 
+// An unset MSRV is serialized as `None`, which has no TOML representation
+impl Serialize for Msrv {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.current() {
+            Some(msrv) => serializer.collect_str(&msrv),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 impl Msrv {
     pub fn empty() -> Msrv {
         Msrv { stack: Vec::new() }
     }
 
-    pub fn read_cargo(&mut self, sess: &Session) {
+    pub fn read_cargo(&mut self, dcx: &DiagCtxt) {
         let cargo_msrv = std::env::var("CARGO_PKG_RUST_VERSION")
             .ok()
             .and_then(|v| RustcVersion::parse(&v).ok());
@@ -98,7 +113,7 @@ pub fn read_cargo(&mut self, sess: &Session) {
             (None, Some(cargo_msrv)) => self.stack = vec![cargo_msrv],
             (Some(clippy_msrv), Some(cargo_msrv)) => {
                 if clippy_msrv != cargo_msrv {
-                    sess.dcx().warn(format!(
+                    dcx.warn(format!(
                         "the MSRV in `clippy.toml` and `Cargo.toml` differ; using `{clippy_msrv}` from `clippy.toml`"
                     ));
                 }
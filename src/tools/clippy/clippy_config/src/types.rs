@@ -1,18 +1,23 @@
 use serde::de::{self, Deserializer, Visitor};
-use serde::{ser, Deserialize, Serialize};
+use serde::ser::{self, SerializeStruct};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rename {
     pub path: String,
     pub rename: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum DisallowedPath {
     Simple(String),
-    WithReason { path: String, reason: Option<String> },
+    WithReason {
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
 }
 
 impl DisallowedPath {
@@ -104,29 +109,18 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     }
 }
 
-// these impls are never actually called but are used by the various config options that default to
-// empty lists
-macro_rules! unimplemented_serialize {
-    ($($t:ty,)*) => {
-        $(
-            impl Serialize for $t {
-                fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
-                where
-                    S: ser::Serializer,
-                {
-                    Err(ser::Error::custom("unimplemented"))
-                }
-            }
-        )*
+impl Serialize for MacroMatcher {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut matcher = serializer.serialize_struct("MacroMatcher", 2)?;
+        matcher.serialize_field("name", &self.name)?;
+        matcher.serialize_field("brace", &self.braces.0)?;
+        matcher.end()
     }
 }
 
-unimplemented_serialize! {
-    DisallowedPath,
-    Rename,
-    MacroMatcher,
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum PubUnderscoreFieldsBehaviour {
     PubliclyExported,
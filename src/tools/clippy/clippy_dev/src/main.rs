@@ -40,6 +40,7 @@ fn main() {
                 matches.get_one::<String>("category").map(String::as_str),
                 matches.get_one::<String>("type").map(String::as_str),
                 matches.get_flag("msrv"),
+                matches.get_one::<String>("conf").map(String::as_str),
             ) {
                 Ok(()) => update_lints::update(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {e}"),
@@ -205,6 +206,18 @@ fn get_clap_config() -> ArgMatches {
                         .long("msrv")
                         .action(ArgAction::SetTrue)
                         .help("Add MSRV config code to the lint"),
+                    Arg::new("conf")
+                        .long("conf")
+                        .help(
+                            "Add a `clippy.toml` configuration option used by the lint, \
+                            ex: \"max_fn_lines: u64 = 100\"",
+                        )
+                        .conflicts_with("type")
+                        .value_parser(|conf: &str| {
+                            new_lint::ConfOption::parse(conf)
+                                .map(|_| conf.to_string())
+                                .ok_or("expected a configuration option of the form `name: type = default`")
+                        }),
                 ]),
             Command::new("setup")
                 .about("Support for setting up your personal development environment")
@@ -1,5 +1,5 @@
 use crate::clippy_project_root;
-use indoc::{formatdoc, writedoc};
+use indoc::{formatdoc, indoc, writedoc};
 use std::fmt;
 use std::fmt::Write as _;
 use std::fs::{self, OpenOptions};
@@ -12,9 +12,52 @@ struct LintData<'a> {
     name: &'a str,
     category: &'a str,
     ty: Option<&'a str>,
+    conf: Option<ConfOption<'a>>,
     project_root: PathBuf,
 }
 
+/// A `clippy.toml` configuration option, as written in `define_Conf!`: `name: type = default`
+#[derive(Clone, Copy)]
+pub struct ConfOption<'a> {
+    name: &'a str,
+    ty: &'a str,
+    default: &'a str,
+}
+
+impl<'a> ConfOption<'a> {
+    #[must_use]
+    pub fn parse(conf: &'a str) -> Option<Self> {
+        let (name, rest) = conf.split_once(':')?;
+        let (ty, default) = rest.split_once('=')?;
+        let (name, ty, default) = (name.trim(), ty.trim(), default.trim());
+        let is_ident = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        (is_ident(name) && !ty.is_empty() && !default.is_empty()).then_some(Self { name, ty, default })
+    }
+
+    /// Whether the option can be destructured out of `Conf` by value
+    fn is_copy(self) -> bool {
+        matches!(
+            self.ty,
+            "bool"
+                | "char"
+                | "u8"
+                | "u16"
+                | "u32"
+                | "u64"
+                | "u128"
+                | "usize"
+                | "i8"
+                | "i16"
+                | "i32"
+                | "i64"
+                | "i128"
+                | "isize"
+                | "f32"
+                | "f64"
+        )
+    }
+}
+
 trait Context {
     fn context<C: AsRef<str>>(self, text: C) -> Self;
 }
@@ -43,6 +86,7 @@ pub fn create(
     category: Option<&str>,
     mut ty: Option<&str>,
     msrv: bool,
+    conf: Option<&str>,
 ) -> io::Result<()> {
     if category == Some("cargo") && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
@@ -54,6 +98,7 @@ pub fn create(
         name: lint_name.expect("`name` argument is validated by clap"),
         category: category.expect("`category` argument is validated by clap"),
         ty,
+        conf: conf.map(|conf| ConfOption::parse(conf).expect("`conf` argument is validated by clap")),
         project_root: clippy_project_root(),
     };
 
@@ -64,6 +109,17 @@ pub fn create(
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
     }
 
+    if let Some(conf) = lint.conf {
+        add_conf(&lint, conf).context("Unable to add the configuration option")?;
+        create_conf_test(&lint, conf).context("Unable to create a test for the configuration option")?;
+        println!(
+            "\n\
+            NOTE: Document the configuration option in `clippy_config/src/conf.rs`, then run\n\
+            `cargo collect-metadata` and `cargo bless` to update the book and the\n\
+            `toml_unknown_key` test"
+        );
+    }
+
     if pass == "early" {
         println!(
             "\n\
@@ -145,13 +201,25 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
 
     let comment_start = lib_rs.find("// add lints here,").expect("Couldn't find comment");
 
-    let new_lint = if enable_msrv {
+    let new_lint = if enable_msrv || lint.conf.is_some() {
+        let mut args = Vec::new();
+        if let Some(conf) = lint.conf {
+            args.push(if conf.is_copy() {
+                conf.name.to_string()
+            } else {
+                format!("{}.clone()", conf.name)
+            });
+        }
+        if enable_msrv {
+            args.push(String::from("msrv()"));
+        }
         format!(
-            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new(msrv())));\n    ",
+            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new({args})));\n    ",
             lint_pass = lint.pass,
             ctor_arg = if lint.pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
+            args = args.join(", "),
         )
     } else {
         format!(
@@ -165,9 +233,82 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
 
     lib_rs.insert_str(comment_start, &new_lint);
 
+    if let Some(conf) = lint.conf {
+        let destructure_start = lib_rs
+            .find("    let Conf {\n")
+            .expect("Couldn't find the `Conf` destructuring")
+            + "    let Conf {\n".len();
+        // Deprecated options are destructured last, after an empty line
+        let destructure_end = lib_rs[destructure_start..]
+            .find("\n        blacklisted_names: _,")
+            .expect("Couldn't find the end of the `Conf` destructuring")
+            + destructure_start;
+        // Insert before the first option sorting after the new one, ignoring `ref` and `: _`
+        let mut pos = destructure_start;
+        for line in lib_rs[destructure_start..destructure_end].split_inclusive('\n') {
+            let name = line.trim().trim_start_matches("ref ").trim_end_matches(',');
+            let name = name.split(':').next().unwrap_or(name);
+            if name.is_empty() || name > conf.name {
+                break;
+            }
+            pos += line.len();
+        }
+        let binding = if conf.is_copy() { "" } else { "ref " };
+        lib_rs.insert_str(pos, &format!("        {binding}{},\n", conf.name));
+    }
+
     fs::write(path, lib_rs).context("writing")
 }
 
+fn add_conf(lint: &LintData<'_>, conf: ConfOption<'_>) -> io::Result<()> {
+    let path = "clippy_config/src/conf.rs";
+    let mut conf_rs = fs::read_to_string(path).context("reading")?;
+
+    let define_conf_end = conf_rs
+        .find("\n}\n\n/// Search for the configuration file.")
+        .expect("Couldn't find the end of `define_Conf!`");
+    conf_rs.insert_str(
+        define_conf_end,
+        &format!(
+            "\n    /// Lint: {}.\n    ///\n    /// TODO: describe the configuration option\n    ({}: {} = {}),",
+            lint.name.to_uppercase(),
+            conf.name,
+            conf.ty,
+            conf.default,
+        ),
+    );
+
+    fs::write(path, conf_rs).context("writing")?;
+    println!("Added configuration option `{}` to `{path}`", conf.name);
+
+    Ok(())
+}
+
+fn create_conf_test(lint: &LintData<'_>, conf: ConfOption<'_>) -> io::Result<()> {
+    let relative_test_dir = format!("tests/ui-toml/{}", lint.name);
+    let test_dir = lint.project_root.join(&relative_test_dir);
+    fs::create_dir(&test_dir)?;
+
+    write_file(
+        test_dir.join("clippy.toml"),
+        format!(
+            "# TODO: set the option to a value other than its default\n{} = {}\n",
+            conf.name.replace('_', "-"),
+            conf.default,
+        ),
+    )?;
+    write_file(
+        test_dir.join(format!("{}.rs", lint.name)),
+        get_test_file_contents(lint.name, false),
+    )?;
+    // Empty until the test code is written and the expected output blessed with `cargo bless`
+    write_file(test_dir.join(format!("{}.stderr", lint.name)), "")?;
+
+    println!("Generated configuration test directory: `{relative_test_dir}`");
+
+    Ok(())
+}
+
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
     fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
         OpenOptions::new()
@@ -277,62 +418,83 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
     let name_camel = to_camel_case(lint.name);
     let name_upper = lint_name.to_uppercase();
 
-    result.push_str(&if enable_msrv {
-        formatdoc!(
-            r#"
-            use clippy_utils::msrvs::{{self, Msrv}};
-            {pass_import}
-            use rustc_lint::{{{context_import}, {pass_type}, LintContext}};
-            use rustc_session::impl_lint_pass;
-
-        "#
-        )
+    let needs_struct = enable_msrv || lint.conf.is_some();
+    if enable_msrv {
+        result.push_str("use clippy_utils::msrvs::{self, Msrv};\n");
+    }
+    // `LintContext` is needed by `extract_msrv_attr!`
+    let lint_context_import = if enable_msrv { ", LintContext" } else { "" };
+    let lint_pass_macro = if needs_struct {
+        "impl_lint_pass"
     } else {
-        formatdoc!(
-            r#"
-            {pass_import}
-            use rustc_lint::{{{context_import}, {pass_type}}};
-            use rustc_session::declare_lint_pass;
+        "declare_lint_pass"
+    };
+    result.push_str(&formatdoc!(
+        r#"
+        {pass_import}
+        use rustc_lint::{{{context_import}, {pass_type}{lint_context_import}}};
+        use rustc_session::{lint_pass_macro};
 
-        "#
-        )
-    });
+    "#
+    ));
 
     let _: fmt::Result = writeln!(result, "{}", get_lint_declaration(&name_upper, category));
 
-    result.push_str(&if enable_msrv {
-        formatdoc!(
+    if needs_struct {
+        let mut fields = Vec::new();
+        if let Some(conf) = lint.conf {
+            fields.push((conf.name, conf.ty));
+        }
+        if enable_msrv {
+            fields.push(("msrv", "Msrv"));
+        }
+        let field_decls: String = fields.iter().map(|(name, ty)| format!("\n    {name}: {ty},")).collect();
+        let params = fields
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let field_names = fields.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ");
+        let extract_msrv = if enable_msrv {
+            format!("\n    extract_msrv_attr!({context_import});\n")
+        } else {
+            String::new()
+        };
+
+        let _: fmt::Result = writedoc!(
+            result,
             r#"
-            pub struct {name_camel} {{
-                msrv: Msrv,
+            pub struct {name_camel} {{{field_decls}
             }}
 
             impl {name_camel} {{
                 #[must_use]
-                pub fn new(msrv: Msrv) -> Self {{
-                    Self {{ msrv }}
+                pub fn new({params}) -> Self {{
+                    Self {{ {field_names} }}
                 }}
             }}
 
             impl_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{
-                extract_msrv_attr!({context_import});
-            }}
-
-            // TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.
-            // TODO: Update msrv config comment in `clippy_config/src/conf.rs`
+            impl {pass_type}{pass_lifetimes} for {name_camel} {{{extract_msrv}}}
         "#
-        )
+        );
+        if enable_msrv {
+            result.push_str(indoc! {"
+
+                // TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.
+                // TODO: Update msrv config comment in `clippy_config/src/conf.rs`
+            "});
+        }
     } else {
-        formatdoc!(
+        result.push_str(&formatdoc!(
             r#"
             declare_lint_pass!({name_camel} => [{name_upper}]);
 
             impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
         "#
-        )
-    });
+        ));
+    }
 
     result
 }
@@ -3,6 +3,7 @@
 use rustc_ast::ast::{FloatTy, LitFloatType, LitKind};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_semver::RustcVersion;
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol;
@@ -15,7 +16,8 @@
     /// [`std::f32::consts`](https://doc.rust-lang.org/stable/std/f32/consts/#constants)
     /// or
     /// [`std::f64::consts`](https://doc.rust-lang.org/stable/std/f64/consts/#constants),
-    /// respectively, suggesting to use the predefined constant.
+    /// respectively, suggesting to use the predefined constant. Digit separators
+    /// are ignored, so `6.283_185_3` is recognized as an approximation of `TAU`.
     ///
     /// ### Why is this bad?
     /// Usually, the definition in the standard library is more
@@ -87,17 +89,23 @@ fn check_lit(&self, cx: &LateContext<'_>, lit: &LitKind, e: &Expr<'_>) {
     }
 
     fn check_known_consts(&self, cx: &LateContext<'_>, e: &Expr<'_>, s: symbol::Symbol, module: &str) {
-        let s = s.as_str();
+        // Digit separators don't count towards the precision of the literal
+        let s = s.as_str().replace('_', "");
         if s.parse::<f64>().is_ok() {
             for &(constant, name, min_digits, msrv) in &KNOWN_CONSTS {
-                if is_approx_const(constant, s, min_digits) && msrv.map_or(true, |msrv| self.msrv.meets(msrv)) {
+                if is_approx_const(constant, &s, min_digits) && msrv.map_or(true, |msrv| self.msrv.meets(msrv)) {
+                    // Unsuffixed literals have their type inferred, which tells us the module to suggest
+                    let ty = match cx.typeck_results().expr_ty(e).kind() {
+                        ty::Float(fty) => fty.name_str(),
+                        _ => "f64",
+                    };
                     span_lint_and_help(
                         cx,
                         APPROX_CONSTANT,
                         e.span,
                         &format!("approximate value of `{module}::consts::{}` found", &name),
                         None,
-                        "consider using the constant directly",
+                        &format!("consider using the constant directly: `std::{ty}::consts::{name}`"),
                     );
                     return;
                 }
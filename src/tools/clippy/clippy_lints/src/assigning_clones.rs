@@ -1,13 +1,16 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::HirNode;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{is_trait_method, path_to_local};
+use clippy_utils::usage::variable_moves;
+use clippy_utils::{
+    get_enclosing_block, get_enclosing_loop_or_multi_call_closure, is_trait_method, path_to_local, path_to_local_id,
+};
 use rustc_errors::Applicability;
-use rustc_hir::{self as hir, Expr, ExprKind, Node};
+use rustc_hir::{self as hir, Body, Expr, ExprKind, HirId, HirIdMap, Node, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Instance, Mutability};
-use rustc_session::declare_lint_pass;
-use rustc_span::def_id::DefId;
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::symbol::sym;
 use rustc_span::ExpnKind;
 
@@ -49,7 +52,15 @@
     perf,
     "assigning the result of cloning may be inefficient"
 }
-declare_lint_pass!(AssigningClones => [ASSIGNING_CLONES]);
+
+#[derive(Default)]
+pub struct AssigningClones {
+    /// Stack of (body owner, moves of the local variables of the body) pairs, computed the first
+    /// time a local of the body is assigned.
+    body_moves: Vec<(LocalDefId, HirIdMap<Vec<HirId>>)>,
+}
+
+impl_lint_pass!(AssigningClones => [ASSIGNING_CLONES]);
 
 impl<'tcx> LateLintPass<'tcx> for AssigningClones {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, assign_expr: &'tcx hir::Expr<'_>) {
@@ -68,10 +79,24 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, assign_expr: &'tcx hir::Expr<'_
             return;
         };
 
-        if is_ok_to_suggest(cx, lhs, &call) {
+        if is_ok_to_suggest(cx, lhs, &call)
+            && path_to_local(lhs).map_or(true, |local| {
+                !local_maybe_uninit(cx, &mut self.body_moves, local, assign_expr)
+            })
+        {
             suggest(cx, assign_expr, lhs, &call);
         }
     }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        if self
+            .body_moves
+            .last()
+            .map_or(false, |&(owner, _)| owner == cx.tcx.hir().body_owner_def_id(body.id()))
+        {
+            self.body_moves.pop();
+        }
+    }
 }
 
 // Try to resolve the call to `Clone::clone` or `ToOwned::to_owned`.
@@ -136,19 +161,6 @@ fn extract_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<
 // Return true if we find that the called method has a custom implementation and isn't derived or
 // provided by default by the corresponding trait.
 fn is_ok_to_suggest<'tcx>(cx: &LateContext<'tcx>, lhs: &Expr<'tcx>, call: &CallCandidate<'tcx>) -> bool {
-    // If the left-hand side is a local variable, it might be uninitialized at this point.
-    // In that case we do not want to suggest the lint.
-    if let Some(local) = path_to_local(lhs) {
-        // TODO: This check currently bails if the local variable has no initializer.
-        // That is overly conservative - the lint should fire even if there was no initializer,
-        // but the variable has been initialized before `lhs` was evaluated.
-        if let Some(Node::Local(local)) = cx.tcx.hir().parent_id_iter(local).next().map(|p| cx.tcx.hir_node(p))
-            && local.init.is_none()
-        {
-            return false;
-        }
-    }
-
     let Some(impl_block) = cx.tcx.impl_of_method(call.method_def_id) else {
         return false;
     };
@@ -184,6 +196,56 @@ fn is_ok_to_suggest<'tcx>(cx: &LateContext<'tcx>, lhs: &Expr<'tcx>, call: &CallC
     implemented_fns.contains_key(&provided_fn.def_id)
 }
 
+/// Checks if the local variable `local` might be uninitialized when `assign` is evaluated, because
+/// it was declared without an initializer and isn't assigned before, or because its value might
+/// have been moved out. `clone_from` can't be called on it in that case.
+fn local_maybe_uninit<'tcx>(
+    cx: &LateContext<'tcx>,
+    body_moves: &mut Vec<(LocalDefId, HirIdMap<Vec<HirId>>)>,
+    local: HirId,
+    assign: &Expr<'tcx>,
+) -> bool {
+    if let Node::Local(let_stmt) = cx.tcx.parent_hir_node(local)
+        && let_stmt.init.is_none()
+        && !is_assigned_before(cx, let_stmt.hir_id, local, assign)
+    {
+        return true;
+    }
+
+    // The moves are only computed once per body
+    let body_owner = cx.tcx.hir().enclosing_body_owner(assign.hir_id);
+    if body_moves.last().map_or(true, |&(owner, _)| owner != body_owner) {
+        let body = cx.tcx.hir().body(cx.tcx.hir().body_owned_by(body_owner));
+        body_moves.push((body_owner, variable_moves(cx, body_owner, body)));
+    }
+    let Some(moves) = body_moves.last().and_then(|(_, moves)| moves.get(&local)) else {
+        return false;
+    };
+
+    // A move before the assignment, or anywhere in a loop around it, might reach it
+    let enclosing_loop = get_enclosing_loop_or_multi_call_closure(cx, assign);
+    moves.iter().any(|&id| {
+        let span = cx.tcx.hir().span(id);
+        span.lo() < assign.span.lo() || enclosing_loop.is_some_and(|e| e.span.contains(span))
+    })
+}
+
+/// Checks if a statement of the block declaring `local` with `let_id`, but without an
+/// initializer, assigns it before `assign`.
+fn is_assigned_before(cx: &LateContext<'_>, let_id: HirId, local: HirId, assign: &Expr<'_>) -> bool {
+    get_enclosing_block(cx, let_id).is_some_and(|block| {
+        block.stmts.iter().any(|stmt| {
+            if let StmtKind::Semi(e) | StmtKind::Expr(e) = stmt.kind
+                && let ExprKind::Assign(lhs, ..) = e.kind
+            {
+                stmt.span.hi() <= assign.span.lo() && path_to_local_id(lhs, local)
+            } else {
+                false
+            }
+        })
+    })
+}
+
 fn suggest<'tcx>(
     cx: &LateContext<'tcx>,
     assign_expr: &hir::Expr<'tcx>,
@@ -3,45 +3,71 @@
 use cargo_metadata::Metadata;
 use clippy_utils::diagnostics::span_lint;
 use rustc_lint::LateContext;
-use rustc_span::DUMMY_SP;
+use rustc_span::{Span, DUMMY_SP};
+use serde::Deserialize;
+use toml::Spanned;
 
-use super::CARGO_COMMON_METADATA;
+use super::{load_manifest, toml_span, CARGO_COMMON_METADATA};
+
+#[derive(Deserialize, Debug)]
+struct PackageTable {
+    name: Spanned<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Manifest {
+    package: PackageTable,
+}
 
 pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, ignore_publish: bool) {
     for package in &metadata.packages {
         // only run the lint if publish is `None` (`publish = true` or skipped entirely)
         // or if the vector isn't empty (`publish = ["something"]`)
         if package.publish.as_ref().filter(|publish| publish.is_empty()).is_none() || ignore_publish {
+            let span = package_name_span(cx, package);
+
             if is_empty_str(&package.description) {
-                missing_warning(cx, package, "package.description");
+                missing_warning(cx, package, span, "package.description");
             }
 
             if is_empty_str(&package.license) && is_empty_str(&package.license_file) {
-                missing_warning(cx, package, "either package.license or package.license_file");
+                missing_warning(cx, package, span, "either package.license or package.license_file");
             }
 
             if is_empty_str(&package.repository) {
-                missing_warning(cx, package, "package.repository");
+                missing_warning(cx, package, span, "package.repository");
             }
 
             if is_empty_str(&package.readme) {
-                missing_warning(cx, package, "package.readme");
+                missing_warning(cx, package, span, "package.readme");
             }
 
             if is_empty_vec(&package.keywords) {
-                missing_warning(cx, package, "package.keywords");
+                missing_warning(cx, package, span, "package.keywords");
             }
 
             if is_empty_vec(&package.categories) {
-                missing_warning(cx, package, "package.categories");
+                missing_warning(cx, package, span, "package.categories");
             }
         }
     }
 }
 
-fn missing_warning(cx: &LateContext<'_>, package: &cargo_metadata::Package, field: &str) {
+/// Returns the span of the package name in its manifest, or `DUMMY_SP` if it cannot be found.
+fn package_name_span(cx: &LateContext<'_>, package: &cargo_metadata::Package) -> Span {
+    if let Some(file) = load_manifest(cx, package)
+        && let Some(src) = file.src.as_deref()
+        && let Ok(manifest) = toml::from_str::<Manifest>(src)
+    {
+        toml_span(manifest.package.name.span(), &file)
+    } else {
+        DUMMY_SP
+    }
+}
+
+fn missing_warning(cx: &LateContext<'_>, package: &cargo_metadata::Package, span: Span, field: &str) {
     let message = format!("package `{}` is missing `{field}` metadata", package.name);
-    span_lint(cx, CARGO_COMMON_METADATA, DUMMY_SP, &message);
+    span_lint(cx, CARGO_COMMON_METADATA, span, &message);
 }
 
 fn is_empty_str<T: AsRef<std::ffi::OsStr>>(value: &Option<T>) -> bool {
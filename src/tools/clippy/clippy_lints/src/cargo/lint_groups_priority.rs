@@ -1,12 +1,11 @@
-use super::LINT_GROUPS_PRIORITY;
+use super::{toml_span, LINT_GROUPS_PRIORITY};
 use clippy_utils::diagnostics::span_lint_and_then;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_lint::{unerased_lint_store, LateContext};
-use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
+use rustc_span::SourceFile;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::ops::Range;
 use std::path::Path;
 use toml::Spanned;
 
@@ -68,15 +67,6 @@ struct LintsAndGroups {
     groups: Vec<(Spanned<String>, Spanned<LintConfig>)>,
 }
 
-fn toml_span(range: Range<usize>, file: &SourceFile) -> Span {
-    Span::new(
-        file.start_pos + BytePos::from_usize(range.start),
-        file.start_pos + BytePos::from_usize(range.end),
-        SyntaxContext::root(),
-        None,
-    )
-}
-
 fn check_table(cx: &LateContext<'_>, table: LintTable, groups: &FxHashSet<&str>, file: &SourceFile) {
     let mut by_priority = BTreeMap::<_, LintsAndGroups>::new();
     for (name, config) in table {
@@ -4,14 +4,17 @@
 mod multiple_crate_versions;
 mod wildcard_dependencies;
 
-use cargo_metadata::MetadataCommand;
+use cargo_metadata::{MetadataCommand, Package};
 use clippy_utils::diagnostics::span_lint;
 use clippy_utils::is_lint_allowed;
 use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync::Lrc;
 use rustc_hir::hir_id::CRATE_HIR_ID;
 use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_session::impl_lint_pass;
-use rustc_span::DUMMY_SP;
+use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext, DUMMY_SP};
+use std::ops::Range;
+use std::path::Path;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -264,3 +267,23 @@ fn check_crate(&mut self, cx: &LateContext<'_>) {
         }
     }
 }
+
+/// Loads the manifest of `package` into the source map so that diagnostics can point into it. The
+/// path is made relative to the current directory where possible to keep the output readable.
+fn load_manifest(cx: &LateContext<'_>, package: &Package) -> Option<Lrc<SourceFile>> {
+    let manifest_path = package.manifest_path.as_std_path();
+    let path = std::env::current_dir()
+        .ok()
+        .and_then(|dir| manifest_path.strip_prefix(dir).ok())
+        .unwrap_or(manifest_path);
+    cx.tcx.sess.source_map().load_file(Path::new(path)).ok()
+}
+
+fn toml_span(range: Range<usize>, file: &SourceFile) -> Span {
+    Span::new(
+        file.start_pos + BytePos::from_usize(range.start),
+        file.start_pos + BytePos::from_usize(range.end),
+        SyntaxContext::root(),
+        None,
+    )
+}
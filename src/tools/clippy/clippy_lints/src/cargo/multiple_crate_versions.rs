@@ -4,11 +4,21 @@
 use clippy_utils::diagnostics::span_lint;
 use itertools::Itertools;
 use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::MultiSpan;
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_lint::LateContext;
-use rustc_span::DUMMY_SP;
+use rustc_span::{Span, DUMMY_SP};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use toml::Spanned;
 
-use super::MULTIPLE_CRATE_VERSIONS;
+use super::{load_manifest, toml_span, MULTIPLE_CRATE_VERSIONS};
+
+#[derive(Deserialize, Debug)]
+struct Manifest {
+    #[serde(default)]
+    dependencies: BTreeMap<String, Spanned<toml::Value>>,
+}
 
 pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate_crates: &FxHashSet<String>) {
     let local_name = cx.tcx.crate_name(LOCAL_CRATE);
@@ -48,10 +58,16 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate
                 versions.sort();
                 let versions = versions.iter().join(", ");
 
+                let spans = dependency_spans(cx, metadata, &resolve.nodes, local_id, &group);
+                let span = if spans.is_empty() {
+                    MultiSpan::from(DUMMY_SP)
+                } else {
+                    MultiSpan::from(spans)
+                };
                 span_lint(
                     cx,
                     MULTIPLE_CRATE_VERSIONS,
-                    DUMMY_SP,
+                    span,
                     &format!("multiple versions for dependency `{name}`: {versions}"),
                 );
             }
@@ -59,6 +75,75 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate
     }
 }
 
+/// Returns the spans of the entries in the `[dependencies]` of the local package that pull in one
+/// of the packages of `group`, either directly or through their own dependencies.
+fn dependency_spans(
+    cx: &LateContext<'_>,
+    metadata: &Metadata,
+    nodes: &[Node],
+    local_id: &PackageId,
+    group: &[&Package],
+) -> Vec<Span> {
+    let (Some(local), Some(local_node)) = (
+        metadata.packages.iter().find(|p| p.id == *local_id),
+        nodes.iter().find(|node| node.id == *local_id),
+    ) else {
+        return Vec::new();
+    };
+    let Some(file) = load_manifest(cx, local) else {
+        return Vec::new();
+    };
+    let Some(manifest) = file.src.as_deref().and_then(|src| toml::from_str::<Manifest>(src).ok()) else {
+        return Vec::new();
+    };
+
+    let mut spans: Vec<Span> = local
+        .dependencies
+        .iter()
+        // Target specific dependencies are not looked up
+        .filter(|dep| matches!(dep.kind, DependencyKind::Normal) && dep.target.is_none())
+        .filter(|dep| {
+            local_node.deps.iter().any(|node_dep| {
+                metadata
+                    .packages
+                    .iter()
+                    .any(|p| p.id == node_dep.pkg && p.name == dep.name)
+                    && group.iter().any(|p| reaches(nodes, &node_dep.pkg, &p.id))
+            })
+        })
+        .filter_map(|dep| manifest.dependencies.get(dep.rename.as_ref().unwrap_or(&dep.name)))
+        .map(|value| toml_span(value.span(), &file))
+        .collect();
+    spans.sort();
+    spans
+}
+
+/// Checks if `to` is `from` or one of its normal dependencies, directly or transitively.
+fn reaches(nodes: &[Node], from: &PackageId, to: &PackageId) -> bool {
+    let mut seen = FxHashSet::default();
+    let mut stack = vec![from];
+    while let Some(id) = stack.pop() {
+        if id == to {
+            return true;
+        }
+        if seen.insert(id)
+            && let Some(node) = nodes.iter().find(|node| node.id == *id)
+        {
+            stack.extend(
+                node.deps
+                    .iter()
+                    .filter(|dep| {
+                        dep.dep_kinds
+                            .iter()
+                            .any(|info| matches!(info.kind, DependencyKind::Normal))
+                    })
+                    .map(|dep| &dep.pkg),
+            );
+        }
+    }
+    false
+}
+
 fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bool {
     fn depends_on(node: &Node, dep_id: &PackageId) -> bool {
         node.deps.iter().any(|dep| {
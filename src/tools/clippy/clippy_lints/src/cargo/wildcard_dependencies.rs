@@ -1,22 +1,57 @@
-use cargo_metadata::Metadata;
+use cargo_metadata::{DependencyKind, Metadata};
 use clippy_utils::diagnostics::span_lint;
 use rustc_lint::LateContext;
 use rustc_span::DUMMY_SP;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use toml::Spanned;
 
-use super::WILDCARD_DEPENDENCIES;
+use super::{load_manifest, toml_span, WILDCARD_DEPENDENCIES};
+
+type DependencyTable = BTreeMap<String, Spanned<toml::Value>>;
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "kebab-case")]
+struct Manifest {
+    #[serde(default)]
+    dependencies: DependencyTable,
+    #[serde(default)]
+    dev_dependencies: DependencyTable,
+    #[serde(default)]
+    build_dependencies: DependencyTable,
+}
 
 pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
-    for dep in &metadata.packages[0].dependencies {
+    let package = &metadata.packages[0];
+    let file = load_manifest(cx, package);
+    let manifest = file
+        .as_ref()
+        .and_then(|file| file.src.as_deref())
+        .and_then(|src| toml::from_str::<Manifest>(src).ok())
+        .unwrap_or_default();
+
+    for dep in &package.dependencies {
         // VersionReq::any() does not work
         if let Ok(wildcard_ver) = semver::VersionReq::parse("*")
             && let Some(ref source) = dep.source
             && !source.starts_with("git")
             && dep.req == wildcard_ver
         {
+            // Target specific dependencies are not looked up, they are reported without a span
+            let table = match dep.kind {
+                DependencyKind::Normal if dep.target.is_none() => Some(&manifest.dependencies),
+                DependencyKind::Development if dep.target.is_none() => Some(&manifest.dev_dependencies),
+                DependencyKind::Build if dep.target.is_none() => Some(&manifest.build_dependencies),
+                _ => None,
+            };
+            let span = table
+                .and_then(|table| table.get(dep.rename.as_ref().unwrap_or(&dep.name)))
+                .zip(file.as_ref())
+                .map_or(DUMMY_SP, |(value, file)| toml_span(value.span(), file));
             span_lint(
                 cx,
                 WILDCARD_DEPENDENCIES,
-                DUMMY_SP,
+                span,
                 &format!("wildcard dependency for `{}`", dep.name),
             );
         }
@@ -0,0 +1,104 @@
+use clippy_utils::diagnostics::span_lint_and_sugg_unless_external_macro;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{get_parent_expr, is_lint_allowed};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, FloatTy, Ty};
+
+use super::CAST_FLOAT_ROUND_TRIP;
+
+/// Methods whose `f32` version returns the same value as the rounded `f64` one
+const EXACT_METHODS: [&str; 9] = [
+    "abs", "ceil", "floor", "fract", "recip", "round", "signum", "sqrt", "trunc",
+];
+
+/// Methods whose `f32` version may differ from the rounded `f64` one in the last bits
+const INEXACT_METHODS: [&str; 22] = [
+    "acos",
+    "acosh",
+    "asin",
+    "asinh",
+    "atan",
+    "atanh",
+    "cbrt",
+    "cos",
+    "cosh",
+    "exp",
+    "exp2",
+    "exp_m1",
+    "ln",
+    "ln_1p",
+    "log10",
+    "log2",
+    "sin",
+    "sinh",
+    "tan",
+    "tanh",
+    "to_degrees",
+    "to_radians",
+];
+
+/// Checks for `(x as f64).method() as f32`. Returns `true` if it linted, so that the other cast
+/// lints don't warn about the narrowing cast as well.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+) -> bool {
+    if let ty::Float(FloatTy::F64) = cast_from.kind()
+        && let ty::Float(FloatTy::F32) = cast_to.kind()
+        && !is_lint_allowed(cx, CAST_FLOAT_ROUND_TRIP, expr.hir_id)
+        && let Some((method, receiver)) = widened_method_call(cx, cast_expr)
+    {
+        let applicability = if EXACT_METHODS.contains(&method) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            CAST_FLOAT_ROUND_TRIP,
+            expr.span,
+            &format!("casting an `f32` to `f64` to call `{method}` and back"),
+            "call the `f32` method directly",
+            format!(
+                "{}.{method}()",
+                Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
+            ),
+            applicability,
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// Checks if `expr` is the widening cast of a round trip linted by `check`.
+pub(super) fn is_widening_of_round_trip(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).is_some_and(|call| {
+        widened_method_call(cx, call).is_some()
+            && get_parent_expr(cx, call).is_some_and(|cast| {
+                matches!(cast.kind, ExprKind::Cast(..))
+                    && matches!(cx.typeck_results().expr_ty(cast).kind(), ty::Float(FloatTy::F32))
+                    && !is_lint_allowed(cx, CAST_FLOAT_ROUND_TRIP, cast.hir_id)
+            })
+    })
+}
+
+/// Returns the method name and `x` if `expr` is `(x as f64).method()` with `x` being an `f32`.
+fn widened_method_call<'a>(cx: &LateContext<'_>, expr: &Expr<'a>) -> Option<(&'a str, &'a Expr<'a>)> {
+    if let ExprKind::MethodCall(path, receiver, [], _) = expr.kind
+        && let ExprKind::Cast(inner, _) = receiver.kind
+        && let ty::Float(FloatTy::F64) = cx.typeck_results().expr_ty(receiver).kind()
+        && let ty::Float(FloatTy::F32) = cx.typeck_results().expr_ty(inner).kind()
+        && let method = path.ident.name.as_str()
+        && (EXACT_METHODS.contains(&method) || INEXACT_METHODS.contains(&method))
+    {
+        Some((method, inner))
+    } else {
+        None
+    }
+}
@@ -8,7 +8,7 @@
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, FloatTy, Ty};
 
-use super::{utils, CAST_LOSSLESS};
+use super::{cast_float_round_trip, utils, CAST_LOSSLESS};
 
 pub(super) fn check(
     cx: &LateContext<'_>,
@@ -63,6 +63,11 @@ fn should_lint(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to
         return false;
     }
 
+    // The whole round trip is linted by `cast_float_round_trip`
+    if cast_float_round_trip::is_widening_of_round_trip(cx, expr) {
+        return false;
+    }
+
     match (cast_from.is_integral(), cast_to.is_integral()) {
         (true, true) => {
             let cast_signed_to_unsigned = cast_from.is_signed() && !cast_to.is_signed();
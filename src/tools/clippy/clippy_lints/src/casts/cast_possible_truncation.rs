@@ -1,9 +1,10 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
-use clippy_utils::expr_or_init;
 use clippy_utils::source::snippet;
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{get_discriminant_value, is_isize_or_usize};
+use clippy_utils::{enclosing_cfg_pointer_width, expr_or_init};
+use itertools::Itertools;
 use rustc_errors::{Applicability, Diag, SuggestionStyle};
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
@@ -11,6 +12,7 @@
 use rustc_middle::ty::{self, FloatTy, Ty};
 use rustc_span::Span;
 use rustc_target::abi::IntegerType;
+use std::slice;
 
 use super::{utils, CAST_ENUM_TRUNCATION, CAST_POSSIBLE_TRUNCATION};
 
@@ -78,6 +80,26 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
     }
 }
 
+/// Returns the suffix of the message for a cast truncating the value on the targets whose
+/// pointer width satisfies `truncates`, or `None` if none of the supported targets do.
+fn pointer_width_suffix(supported_pointer_widths: &[u64], truncates: impl Fn(u64) -> bool) -> Option<String> {
+    let widths: Vec<u64> = supported_pointer_widths
+        .iter()
+        .copied()
+        .filter(|&width| truncates(width))
+        .collect();
+    if widths.is_empty() {
+        None
+    } else if widths.len() == supported_pointer_widths.len() {
+        Some(String::new())
+    } else {
+        Some(format!(
+            " on targets with {}-bit wide pointers",
+            widths.iter().join("/")
+        ))
+    }
+}
+
 pub(super) fn check(
     cx: &LateContext<'_>,
     expr: &Expr<'_>,
@@ -85,6 +107,7 @@ pub(super) fn check(
     cast_from: Ty<'_>,
     cast_to: Ty<'_>,
     cast_to_span: Span,
+    supported_pointer_widths: &[u64],
 ) {
     let msg = match (cast_from.kind(), cast_to.is_integral()) {
         (ty::Int(_) | ty::Uint(_), true) => {
@@ -96,22 +119,18 @@ pub(super) fn check(
             );
             let to_nbits = utils::int_ty_to_nbits(cast_to, cx.tcx);
 
-            let (should_lint, suffix) = match (is_isize_or_usize(cast_from), is_isize_or_usize(cast_to)) {
-                (true, true) | (false, false) => (to_nbits < from_nbits, ""),
-                (true, false) => (
-                    to_nbits <= 32,
-                    if to_nbits == 32 {
-                        " on targets with 64-bit wide pointers"
-                    } else {
-                        ""
-                    },
-                ),
-                (false, true) => (from_nbits == 64, " on targets with 32-bit wide pointers"),
+            // Code gated on a pointer width is only checked against that width
+            let pinned_width = enclosing_cfg_pointer_width(cx.tcx, expr.hir_id);
+            let widths = pinned_width.as_ref().map_or(supported_pointer_widths, slice::from_ref);
+            let suffix = match (is_isize_or_usize(cast_from), is_isize_or_usize(cast_to)) {
+                (true, true) | (false, false) => (to_nbits < from_nbits).then(String::new),
+                (true, false) => pointer_width_suffix(widths, |width| to_nbits < width),
+                (false, true) => pointer_width_suffix(widths, |width| width < from_nbits),
             };
 
-            if !should_lint {
+            let Some(suffix) = suffix else {
                 return;
-            }
+            };
 
             format!("casting `{cast_from}` to `{cast_to}` may truncate the value{suffix}",)
         },
@@ -112,13 +112,13 @@ fn get_const_unsigned_int_eval<'cx>(
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Sign {
+pub(super) enum Sign {
     ZeroOrPositive,
     Negative,
     Uncertain,
 }
 
-fn expr_sign<'cx>(cx: &LateContext<'cx>, expr: &Expr<'_>, ty: impl Into<Option<Ty<'cx>>>) -> Sign {
+pub(super) fn expr_sign<'cx>(cx: &LateContext<'cx>, expr: &Expr<'_>, ty: impl Into<Option<Ty<'cx>>>) -> Sign {
     // Try evaluate this expr first to see if it's positive
     if let Some(val) = get_const_signed_int_eval(cx, expr, ty) {
         return if val >= 0 { Sign::ZeroOrPositive } else { Sign::Negative };
@@ -0,0 +1,43 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::in_constant;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, UintTy};
+
+use super::cast_sign_loss::{expr_sign, Sign};
+use super::CAST_SIGNED_REM_TO_USIZE;
+
+pub(super) fn check<'cx>(
+    cx: &LateContext<'cx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'cx>,
+    cast_to: Ty<'_>,
+    msrv: &Msrv,
+) {
+    if let ty::Int(_) = cast_from.kind()
+        && let ty::Uint(UintTy::Usize) = cast_to.kind()
+        && let ExprKind::Binary(op, lhs, rhs) = cast_expr.kind
+        && op.node == BinOpKind::Rem
+        // The remainder has the sign of the dividend
+        && expr_sign(cx, lhs, cast_from) != Sign::ZeroOrPositive
+        && msrv.meets(msrvs::REM_EUCLID)
+        && (!in_constant(cx, expr.hir_id) || msrv.meets(msrvs::REM_EUCLID_CONST))
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let lhs = Sugg::hir_with_applicability(cx, lhs, "..", &mut app).maybe_par();
+        let rhs = Sugg::hir_with_applicability(cx, rhs, "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            CAST_SIGNED_REM_TO_USIZE,
+            cast_expr.span,
+            &format!("casting the remainder of a possibly negative `{cast_from}` to `usize`"),
+            "use `rem_euclid` to get a non-negative remainder",
+            format!("{lhs}.rem_euclid({rhs})"),
+            app,
+        );
+    }
+}
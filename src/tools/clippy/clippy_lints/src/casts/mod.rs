@@ -3,6 +3,7 @@
 mod borrow_as_ptr;
 mod cast_abs_to_unsigned;
 mod cast_enum_constructor;
+mod cast_float_round_trip;
 mod cast_lossless;
 mod cast_nan_to_int;
 mod cast_possible_truncation;
@@ -10,6 +11,7 @@
 mod cast_precision_loss;
 mod cast_ptr_alignment;
 mod cast_sign_loss;
+mod cast_signed_rem_to_usize;
 mod cast_slice_different_sizes;
 mod cast_slice_from_raw_parts;
 mod char_lit_as_u8;
@@ -85,6 +87,10 @@
     /// default. It suggests user either explicitly ignore the lint,
     /// or use `try_from()` and handle the truncation, default, or panic explicitly.
     ///
+    /// Casts between `usize` or `isize` and fixed-width integers are only linted when they
+    /// truncate on one of the targets set by the `supported-pointer-widths` configuration. In code
+    /// gated by `#[cfg(target_pointer_width = "..")]`, only the width set there is considered.
+    ///
     /// ### Why is this bad?
     /// In some problem domains, it is good practice to avoid
     /// truncation. This lint can be activated to help assess where additional
@@ -531,6 +537,34 @@
     "casting the result of `abs()` to an unsigned integer can panic"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the remainder of a signed integer division being cast to `usize`, e.g. to
+    /// index a slice or to pick a hash bucket.
+    ///
+    /// ### Why is this bad?
+    /// The remainder has the sign of the dividend, so for a negative value the cast wraps around
+    /// to a huge index. Indexing then panics and bucket computations silently go wrong.
+    /// `rem_euclid` always returns a non-negative remainder.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let buckets = [0; 16];
+    /// # let hash: i64 = -3;
+    /// let bucket = buckets[(hash % 16) as usize];
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let buckets = [0; 16];
+    /// # let hash: i64 = -3;
+    /// let bucket = buckets[hash.rem_euclid(16) as usize];
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub CAST_SIGNED_REM_TO_USIZE,
+    suspicious,
+    "casting the remainder of a signed integer to `usize`"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for the usage of `as _` conversion using inferred type.
@@ -667,6 +701,35 @@
     "casting a known floating-point NaN into an integer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an `f32` being cast to `f64` to call a math method, with the result being
+    /// cast straight back to `f32`, like `(x as f64).sqrt() as f32`.
+    ///
+    /// ### Why is this bad?
+    /// The `f32` methods can be called directly. The round trip is noisier and computing in
+    /// `f64` is usually slower.
+    ///
+    /// ### Known problems
+    /// For methods other than the correctly rounded ones (`sqrt`, `abs`, `floor`, ...), the
+    /// `f32` version may differ from the rounded `f64` result in the last bits.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let x = 2.0_f32;
+    /// let y = (x as f64).sqrt() as f32;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let x = 2.0_f32;
+    /// let y = x.sqrt();
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub CAST_FLOAT_ROUND_TRIP,
+    pedantic,
+    "casting an `f32` to `f64` to call a math method and casting the result back"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Catch casts from `0` to some pointer type
@@ -716,12 +779,16 @@
 
 pub struct Casts {
     msrv: Msrv,
+    supported_pointer_widths: Vec<u64>,
 }
 
 impl Casts {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, supported_pointer_widths: Vec<u64>) -> Self {
+        Self {
+            msrv,
+            supported_pointer_widths,
+        }
     }
 }
 
@@ -743,6 +810,8 @@ pub fn new(msrv: Msrv) -> Self {
     CAST_ENUM_TRUNCATION,
     CAST_ENUM_CONSTRUCTOR,
     CAST_ABS_TO_UNSIGNED,
+    CAST_SIGNED_REM_TO_USIZE,
+    CAST_FLOAT_ROUND_TRIP,
     AS_UNDERSCORE,
     BORROW_AS_PTR,
     CAST_SLICE_FROM_RAW_PARTS,
@@ -774,6 +843,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             if unnecessary_cast::check(cx, expr, cast_expr, cast_from, cast_to) {
                 return;
             }
+            if cast_float_round_trip::check(cx, expr, cast_expr, cast_from, cast_to) {
+                return;
+            }
             cast_slice_from_raw_parts::check(cx, expr, cast_expr, cast_to, &self.msrv);
             ptr_cast_constness::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
             as_ptr_cast_mut::check(cx, expr, cast_expr, cast_to);
@@ -783,12 +855,21 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             zero_ptr::check(cx, expr, cast_expr, cast_to_hir);
 
             if cast_to.is_numeric() && !in_external_macro(cx.sess(), expr.span) {
-                cast_possible_truncation::check(cx, expr, cast_expr, cast_from, cast_to, cast_to_hir.span);
+                cast_possible_truncation::check(
+                    cx,
+                    expr,
+                    cast_expr,
+                    cast_from,
+                    cast_to,
+                    cast_to_hir.span,
+                    &self.supported_pointer_widths,
+                );
                 if cast_from.is_numeric() {
                     cast_possible_wrap::check(cx, expr, cast_from, cast_to);
                     cast_precision_loss::check(cx, expr, cast_from, cast_to);
                     cast_sign_loss::check(cx, expr, cast_expr, cast_from, cast_to);
                     cast_abs_to_unsigned::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
+                    cast_signed_rem_to_usize::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
                     cast_nan_to_int::check(cx, expr, cast_expr, cast_from, cast_to);
                 }
                 cast_lossless::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
@@ -215,3 +215,15 @@ macro_rules! declare_deprecated_lint {
     pub WRONG_PUB_SELF_CONVENTION,
     "set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items"
 }
+
+declare_deprecated_lint! {
+    /// ### What it does
+    /// Nothing. This lint has been deprecated.
+    ///
+    /// ### Deprecation reason
+    /// This lint has been split into `imprecise_flops`, for operations losing
+    /// precision, and `suboptimal_flops`, for operations that can be made faster.
+    #[clippy::version = "1.78.0"]
+    pub FLOATING_POINT_IMPROVEMENTS,
+    "this lint has been split into `imprecise_flops` and `suboptimal_flops`"
+}
@@ -1,12 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::source::snippet;
 use rustc_ast::node_id::NodeSet;
-use rustc_ast::visit::{walk_block, walk_item, Visitor};
+use rustc_ast::visit::{walk_block, walk_fn, walk_item, FnKind, Visitor};
 use rustc_ast::{Block, Crate, Inline, Item, ItemKind, ModKind, NodeId};
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::impl_lint_pass;
 use rustc_span::Span;
+use std::mem;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -14,6 +15,9 @@
     ///
     /// Note: Even though this lint is warn-by-default, it will only trigger if a maximum nesting level is defined in the clippy.toml file.
     ///
+    /// With `excessive-nesting-per-fn` enabled, the nesting of blocks is measured in each function
+    /// body on its own, and the lint points at the most deeply nested block of the function.
+    ///
     /// ### Why is this bad?
     /// It can severely hinder readability.
     ///
@@ -66,6 +70,7 @@
 #[derive(Clone)]
 pub struct ExcessiveNesting {
     pub excessive_nesting_threshold: u64,
+    pub excessive_nesting_per_fn: bool,
     pub nodes: NodeSet,
 }
 
@@ -78,7 +83,11 @@ pub fn check_node_id(&self, cx: &EarlyContext<'_>, span: Span, node_id: NodeId)
                 span,
                 "this block is too nested",
                 None,
-                "try refactoring your code to minimize nesting",
+                if self.excessive_nesting_per_fn {
+                    "try returning early or extracting parts of the function to minimize nesting"
+                } else {
+                    "try refactoring your code to minimize nesting"
+                },
             );
         }
     }
@@ -90,14 +99,27 @@ fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
             return;
         }
 
-        let mut visitor = NestingVisitor {
-            conf: self,
-            cx,
-            nest_level: 0,
-        };
+        if self.excessive_nesting_per_fn {
+            let mut visitor = FnNestingVisitor {
+                conf: self,
+                cx,
+                nest_level: 0,
+                deepest: None,
+            };
+
+            for item in &krate.items {
+                visitor.visit_item(item);
+            }
+        } else {
+            let mut visitor = NestingVisitor {
+                conf: self,
+                cx,
+                nest_level: 0,
+            };
 
-        for item in &krate.items {
-            visitor.visit_item(item);
+            for item in &krate.items {
+                visitor.visit_item(item);
+            }
         }
     }
 
@@ -128,16 +150,21 @@ fn check_indent(&mut self, span: Span, id: NodeId) -> bool {
     }
 }
 
+/// Checks that the block is written out in the source, i.e. not created by a macro.
+fn is_source_block(cx: &EarlyContext<'_>, block: &Block) -> bool {
+    if block.span.from_expansion() {
+        return false;
+    }
+
+    // TODO: This should be rewritten using `LateLintPass` so we can use `is_from_proc_macro` instead,
+    // but for now, this is fine.
+    let snippet = snippet(cx, block.span, "{}").trim().to_owned();
+    snippet.starts_with('{') && snippet.ends_with('}')
+}
+
 impl<'conf, 'cx> Visitor<'_> for NestingVisitor<'conf, 'cx> {
     fn visit_block(&mut self, block: &Block) {
-        if block.span.from_expansion() {
-            return;
-        }
-
-        // TODO: This should be rewritten using `LateLintPass` so we can use `is_from_proc_macro` instead,
-        // but for now, this is fine.
-        let snippet = snippet(self.cx, block.span, "{}").trim().to_owned();
-        if !snippet.starts_with('{') || !snippet.ends_with('}') {
+        if !is_source_block(self.cx, block) {
             return;
         }
 
@@ -178,3 +205,55 @@ fn visit_item(&mut self, item: &Item) {
         }
     }
 }
+
+/// Measures the nesting of each function body on its own, remembering its most deeply nested
+/// block. Closures count towards the nesting of the enclosing function.
+struct FnNestingVisitor<'conf, 'cx> {
+    conf: &'conf mut ExcessiveNesting,
+    cx: &'cx EarlyContext<'cx>,
+    nest_level: u64,
+    deepest: Option<(u64, NodeId)>,
+}
+
+impl<'conf, 'cx> Visitor<'_> for FnNestingVisitor<'conf, 'cx> {
+    fn visit_fn(&mut self, kind: FnKind<'_>, span: Span, _: NodeId) {
+        if let FnKind::Closure(..) = kind {
+            walk_fn(self, kind);
+            return;
+        }
+        if span.from_expansion() {
+            return;
+        }
+
+        let outer_level = mem::take(&mut self.nest_level);
+        let outer_deepest = self.deepest.take();
+
+        walk_fn(self, kind);
+
+        if let Some((level, id)) = self.deepest
+            && level > self.conf.excessive_nesting_threshold
+        {
+            self.conf.nodes.insert(id);
+        }
+
+        self.nest_level = outer_level;
+        self.deepest = outer_deepest;
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        if !is_source_block(self.cx, block) {
+            return;
+        }
+
+        self.nest_level += 1;
+
+        if self.deepest.map_or(true, |(level, _)| self.nest_level > level)
+            && !in_external_macro(self.cx.sess(), block.span)
+        {
+            self.deepest = Some((self.nest_level, block.id));
+        }
+        walk_block(self, block);
+
+        self.nest_level -= 1;
+    }
+}
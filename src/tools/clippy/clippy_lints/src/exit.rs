@@ -1,16 +1,25 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::is_entrypoint_fn;
-use rustc_hir::{Expr, ExprKind, Item, ItemKind, Node};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_entrypoint_fn, match_def_path, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{LocalDefId, LOCAL_CRATE};
+use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Detects calls to the `exit()` function which terminates the program.
+    /// Detects calls to the `exit()` and `abort()` functions which terminate the program,
+    /// outside of `main`. The nearest enclosing public function is pointed out, as that is
+    /// where callers see the program terminate.
+    ///
+    /// Functions can be allowed to call them with the `allowed-exit-callers` configuration,
+    /// either by name (`fatal_error`) or by path (`my_crate::cli::run`).
     ///
     /// ### Why is this bad?
-    /// Exit terminates the program at the location it is called. For unrecoverable
+    /// They terminate the program at the location they are called, without running
+    /// destructors, which also makes the calling code hard to test. For unrecoverable
     /// errors `panics` should be used to provide a stacktrace and potentially other
     /// information. A normal termination or one with an error code should happen in
     /// the main function.
@@ -34,24 +43,74 @@
     #[clippy::version = "1.41.0"]
     pub EXIT,
     restriction,
-    "detects `std::process::exit` calls"
+    "detects `std::process::exit` and `std::process::abort` calls"
+}
+
+pub struct Exit {
+    allowed_callers: FxHashSet<String>,
+}
+
+impl Exit {
+    pub fn new(allowed_callers: FxHashSet<String>) -> Self {
+        Self { allowed_callers }
+    }
+
+    fn is_allowed_caller(&self, cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+        self.allowed_callers
+            .contains(cx.tcx.item_name(def_id.to_def_id()).as_str())
+            || self.allowed_callers.contains(&format!(
+                "{}::{}",
+                cx.tcx.crate_name(LOCAL_CRATE),
+                cx.tcx.def_path_str(def_id)
+            ))
+    }
 }
 
-declare_lint_pass!(Exit => [EXIT]);
+impl_lint_pass!(Exit => [EXIT]);
 
 impl<'tcx> LateLintPass<'tcx> for Exit {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if let ExprKind::Call(path_expr, _args) = e.kind
             && let ExprKind::Path(ref path) = path_expr.kind
             && let Some(def_id) = cx.qpath_res(path, path_expr.hir_id).opt_def_id()
-            && cx.tcx.is_diagnostic_item(sym::process_exit, def_id)
+            && let Some(name) = if cx.tcx.is_diagnostic_item(sym::process_exit, def_id) {
+                Some("exit")
+            } else if match_def_path(cx, def_id, &paths::PROCESS_ABORT) {
+                Some("abort")
+            } else {
+                None
+            }
             && let parent = cx.tcx.hir().get_parent_item(e.hir_id).def_id
-            && let Some(Node::Item(Item{kind: ItemKind::Fn(..), ..})) = cx.tcx.opt_hir_node_by_def_id(parent)
+            && is_fn(cx, parent)
             // If the next item up is a function we check if it is an entry point
             // and only then emit a linter warning
             && !is_entrypoint_fn(cx, parent.to_def_id())
+            && !self.is_allowed_caller(cx, parent)
         {
-            span_lint(cx, EXIT, e.span, "usage of `process::exit`");
+            span_lint_and_then(cx, EXIT, e.span, &format!("usage of `process::{name}`"), |diag| {
+                // Point out the public function that callers will see terminating the program
+                if let Some(public_fn) = cx
+                    .tcx
+                    .hir()
+                    .parent_owner_iter(e.hir_id)
+                    .map(|(owner, _)| owner.def_id)
+                    .find(|&id| is_fn(cx, id) && cx.effective_visibilities.is_exported(id))
+                {
+                    diag.span_note(
+                        cx.tcx
+                            .def_ident_span(public_fn)
+                            .unwrap_or_else(|| cx.tcx.def_span(public_fn)),
+                        format!(
+                            "the nearest enclosing public function is `{}`",
+                            cx.tcx.item_name(public_fn.to_def_id())
+                        ),
+                    );
+                }
+            });
         }
     }
 }
+
+fn is_fn(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+}
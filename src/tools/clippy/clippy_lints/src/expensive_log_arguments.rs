@@ -0,0 +1,170 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::{macro_backtrace, root_macro_call_first_node};
+use clippy_utils::source::walk_span_to_context;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{def_path_def_ids, fn_def_id, is_trait_method};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expensive expressions passed as arguments to logging macros, i.e. `format!`
+    /// calls, `.to_string()`, `.to_owned()` and `.clone()` calls, and calls to the functions
+    /// listed in the `expensive-log-argument-fns` configuration.
+    ///
+    /// The macros of the `log` and `tracing` crates are checked, additional logging macros can
+    /// be added with the `logging-macros` configuration. Macro calls inside of an
+    /// `if log_enabled!(..)` or `if tracing::enabled!(..)` block are not linted.
+    ///
+    /// ### Why is this bad?
+    /// The logging macros already format their arguments, and only need a reference to
+    /// them, so the allocations are wasted. Expensive function calls are made whenever
+    /// the log level is enabled, even if the record is filtered out later on.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// log::debug!("request: {}", format!("{} {}", method, path));
+    /// log::debug!("user: {}", user.name.clone());
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// log::debug!("request: {} {}", method, path);
+    /// log::debug!("user: {}", user.name);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub EXPENSIVE_LOG_ARGUMENTS,
+    pedantic,
+    "expensive expressions passed to logging macros"
+}
+
+const DEFAULT_LOGGING_MACROS: &[&[&str]] = &[
+    &["log", "log"],
+    &["log", "trace"],
+    &["log", "debug"],
+    &["log", "info"],
+    &["log", "warn"],
+    &["log", "error"],
+    &["tracing", "event"],
+    &["tracing", "trace"],
+    &["tracing", "debug"],
+    &["tracing", "info"],
+    &["tracing", "warn"],
+    &["tracing", "error"],
+];
+
+pub struct ExpensiveLogArguments {
+    logging_macros: Vec<String>,
+    expensive_fns: Vec<String>,
+    macro_def_ids: FxHashSet<DefId>,
+    fn_def_ids: FxHashSet<DefId>,
+}
+
+impl ExpensiveLogArguments {
+    pub fn new(logging_macros: Vec<String>, expensive_fns: Vec<String>) -> Self {
+        Self {
+            logging_macros,
+            expensive_fns,
+            macro_def_ids: FxHashSet::default(),
+            fn_def_ids: FxHashSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(ExpensiveLogArguments => [EXPENSIVE_LOG_ARGUMENTS]);
+
+impl<'tcx> LateLintPass<'tcx> for ExpensiveLogArguments {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in DEFAULT_LOGGING_MACROS {
+            self.macro_def_ids.extend(def_path_def_ids(cx, path));
+        }
+        for path in &self.logging_macros {
+            let segments: Vec<&str> = path.split("::").collect();
+            self.macro_def_ids.extend(def_path_def_ids(cx, &segments));
+        }
+        for path in &self.expensive_fns {
+            let segments: Vec<&str> = path.split("::").collect();
+            self.fn_def_ids.extend(def_path_def_ids(cx, &segments));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(macro_call) = root_macro_call_first_node(cx, expr) else {
+            return;
+        };
+        if !self.macro_def_ids.contains(&macro_call.def_id) || is_behind_level_check(cx, expr) {
+            return;
+        }
+
+        let call_site_ctxt = macro_call.span.ctxt();
+        for_each_expr(expr, |e| {
+            let help = if e.span.ctxt() == call_site_ctxt {
+                match e.kind {
+                    ExprKind::MethodCall(path, ..)
+                        if matches!(path.ident.name, sym::to_string | sym::to_owned | sym::clone)
+                            && (is_trait_method(cx, e, sym::ToString)
+                                || is_trait_method(cx, e, sym::ToOwned)
+                                || is_trait_method(cx, e, sym::Clone)) =>
+                    {
+                        Some("pass the value itself, the logging macro only needs a reference to it")
+                    },
+                    ExprKind::Call(..) | ExprKind::MethodCall(..)
+                        if fn_def_id(cx, e).is_some_and(|id| self.fn_def_ids.contains(&id)) =>
+                    {
+                        Some("only make the call when the log level is enabled, e.g. in `if log_enabled!(..)`")
+                    },
+                    _ => None,
+                }
+            } else if let Some(inner) = macro_backtrace(e.span).next()
+                && inner.span.ctxt() == call_site_ctxt
+                && cx.tcx.is_diagnostic_item(sym::format_macro, inner.def_id)
+            {
+                Some("use the format string of the logging macro instead")
+            } else {
+                None
+            };
+
+            if let Some(help) = help {
+                span_lint_and_help(
+                    cx,
+                    EXPENSIVE_LOG_ARGUMENTS,
+                    walk_span_to_context(e.span, call_site_ctxt).unwrap_or(e.span),
+                    "expensive expression passed to a logging macro",
+                    None,
+                    help,
+                );
+                ControlFlow::<(), _>::Continue(Descend::No)
+            } else {
+                ControlFlow::Continue(Descend::Yes)
+            }
+        });
+    }
+}
+
+/// Checks if the logging macro call is in an `if log_enabled!(..)` or `if enabled!(..)` block.
+fn is_behind_level_check(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.tcx.hir().parent_iter(expr.hir_id).any(|(_, node)| {
+        if let Node::Expr(Expr {
+            kind: ExprKind::If(cond, ..),
+            ..
+        }) = node
+        {
+            for_each_expr(*cond, |e| {
+                if macro_backtrace(e.span)
+                    .any(|call| matches!(cx.tcx.item_name(call.def_id).as_str(), "log_enabled" | "enabled"))
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        } else {
+            false
+        }
+    })
+}
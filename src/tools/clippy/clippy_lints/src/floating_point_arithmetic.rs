@@ -1,14 +1,24 @@
+use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::consts::Constant::{Int, F32, F64};
-use clippy_utils::consts::{constant, constant_simple, Constant};
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::consts::{constant, constant_simple, constant_with_float_methods, Constant};
+use clippy_utils::diagnostics::{
+    applicability_for_span, span_lint_and_help, span_lint_and_sugg_unless_external_macro, span_lint_and_then,
+};
+use clippy_utils::source::snippet;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{
-    eq_expr_value, get_parent_expr, higher, in_constant, is_no_std_crate, numeric_literal, peel_blocks, sugg,
+    eq_expr_value, eq_expr_value_allow_pure_calls, get_enclosing_block, get_parent_expr, higher, in_constant,
+    is_in_test, is_no_std_crate, numeric_literal, path_to_local, peel_blocks, repeated_subexprs, sugg,
 };
+use core::ops::ControlFlow;
+use itertools::Itertools;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, PathSegment, UnOp};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, MatchSource, PatKind, PathSegment, Stmt, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
-use rustc_session::declare_lint_pass;
+use rustc_middle::ty::{self, FloatTy};
+use rustc_semver::RustcVersion;
+use rustc_session::impl_lint_pass;
 use rustc_span::source_map::Spanned;
 
 use rustc_ast::ast;
@@ -30,7 +40,9 @@
     /// let a = 3f32;
     /// let _ = a.powf(1.0 / 3.0);
     /// let _ = (1.0 + a).ln();
+    /// let _ = (a + 3.0).ln();
     /// let _ = a.exp() - 1.0;
+    /// let _ = a.exp() - 3.0;
     /// ```
     ///
     /// Use instead:
@@ -38,7 +50,9 @@
     /// let a = 3f32;
     /// let _ = a.cbrt();
     /// let _ = a.ln_1p();
+    /// let _ = (a + 2.0).ln_1p();
     /// let _ = a.exp_m1();
+    /// let _ = a.exp_m1() - 2.0;
     /// ```
     #[clippy::version = "1.43.0"]
     pub IMPRECISE_FLOPS,
@@ -67,7 +81,15 @@
     /// let _ = a.log(10.0);
     /// let _ = a.log(E);
     /// let _ = a.powf(2.0);
+    /// let _ = a.powf(3.0);
+    /// let _ = a.powf(-1.0);
+    /// let _ = a * 57.29578;
     /// let _ = a * 2.0 + 4.0;
+    /// let s = a.sin();
+    /// let c = a.cos();
+    /// let _ = a.exp() * 2f32.exp();
+    /// let mut b = 1.0;
+    /// b += a * 2.0;
     /// let _ = if a < 0.0 {
     ///     -a
     /// } else {
@@ -92,8 +114,15 @@
     /// let _ = a.log2();
     /// let _ = a.log10();
     /// let _ = a.ln();
-    /// let _ = a.powi(2);
+    /// let _ = a * a;
+    /// let _ = a.powi(3);
+    /// let _ = a.recip();
+    /// let _ = a.to_degrees();
     /// let _ = a.mul_add(2.0, 4.0);
+    /// let (s, c) = a.sin_cos();
+    /// let _ = (a + 2.0).exp();
+    /// let mut b = 1.0;
+    /// b = a.mul_add(2.0, b);
     /// let _ = a.abs();
     /// let _ = -a.abs();
     /// ```
@@ -103,9 +132,117 @@
     "usage of sub-optimal floating point operations"
 }
 
-declare_lint_pass!(FloatingPointArithmetic => [
+declare_clippy_lint! {
+    /// ### What it does
+    /// Looks for floating-point computations which only involve constants, like
+    /// `2.0_f64.powf(10.0)`, `100.0_f64.log(10.0)` or `100.0_f32.ln()`.
+    ///
+    /// ### Why is this bad?
+    /// The computation is done at runtime, even though its result is known upfront. Writing the
+    /// resulting literal, or the matching constant of `std::f32::consts` / `std::f64::consts`,
+    /// avoids it.
+    ///
+    /// ### Known problems
+    /// Functions like `ln` or `powf` are not guaranteed to return the exact same result on every
+    /// platform, so the suggested literal may differ from the runtime result in the last digit.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let _ = 2.0_f64.powf(10.0);
+    /// let _ = 2.0_f64.sqrt();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let _ = 1024.0_f64;
+    /// let _ = std::f64::consts::SQRT_2;
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub CONSTANT_FLOAT_COMPUTATION,
+    nursery,
+    "floating point computations on constants which could be replaced by their result"
+}
+
+pub struct FloatingPointArithmetic {
+    check_reciprocal_division: bool,
+    performance_suggestions: bool,
+    performance_suggestions_in_tests: bool,
+    suggest_libm: bool,
+    msrv: Msrv,
+}
+
+impl FloatingPointArithmetic {
+    pub fn new(
+        check_reciprocal_division: bool,
+        performance_suggestions: bool,
+        performance_suggestions_in_tests: bool,
+        suggest_libm: bool,
+        msrv: Msrv,
+    ) -> Self {
+        Self {
+            check_reciprocal_division,
+            performance_suggestions,
+            performance_suggestions_in_tests,
+            suggest_libm,
+            msrv,
+        }
+    }
+
+    // Whether the suggestions that only improve performance are emitted for the node `id`
+    fn performance_suggestions(&self, cx: &LateContext<'_>, id: HirId) -> bool {
+        self.performance_suggestions && (self.performance_suggestions_in_tests || !is_in_test(cx.tcx, id))
+    }
+
+    // Dispatches the checks of binary operators on the operator
+    fn check_binary(
+        &self,
+        cx: &LateContext<'_>,
+        expr: &Expr<'_>,
+        op: BinOpKind,
+        no_std: bool,
+        performance_suggestions: bool,
+    ) {
+        match op {
+            BinOpKind::Add if !no_std && performance_suggestions => check_mul_add(cx, expr),
+            BinOpKind::Sub if !no_std => {
+                check_expm1(cx, expr);
+                if performance_suggestions {
+                    check_mul_add(cx, expr);
+                }
+                check_manual_fract(cx, expr);
+            },
+            BinOpKind::Mul => {
+                if !no_std {
+                    if performance_suggestions {
+                        check_repeated_mul(cx, expr);
+                        check_exp_product(cx, expr);
+                    }
+                    check_signum_abs(cx, expr);
+                }
+                check_radians(cx, expr);
+            },
+            BinOpKind::Div => {
+                if !no_std {
+                    if performance_suggestions {
+                        check_exp_product(cx, expr);
+                    }
+                    check_log_division(cx, expr);
+                    check_ln_constant_division(cx, expr);
+                }
+                check_radians(cx, expr);
+                if self.check_reciprocal_division && performance_suggestions {
+                    check_reciprocal_division(cx, expr);
+                }
+            },
+            BinOpKind::Rem if !no_std => check_manual_fract(cx, expr),
+            _ => {},
+        }
+    }
+}
+
+impl_lint_pass!(FloatingPointArithmetic => [
     IMPRECISE_FLOPS,
-    SUBOPTIMAL_FLOPS
+    SUBOPTIMAL_FLOPS,
+    CONSTANT_FLOAT_COMPUTATION
 ]);
 
 // Returns the specialized log method for a given base if base is constant
@@ -153,24 +290,27 @@ fn prepare_receiver_sugg<'a>(cx: &LateContext<'_>, mut expr: &'a Expr<'a>) -> Su
         };
     }
 
-    suggestion.maybe_par()
+    suggestion.maybe_par_for_method_receiver()
 }
 
 fn check_log_base(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args: &[Expr<'_>]) {
     if let Some(method) = get_specialized_log_method(cx, &args[0]) {
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             SUBOPTIMAL_FLOPS,
             expr.span,
             "logarithm for bases 2, 10 and e can be computed more accurately",
             "consider using",
-            format!("{}.{method}()", Sugg::hir(cx, receiver, "..").maybe_par()),
+            format!(
+                "{}.{method}()",
+                Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
+            ),
             Applicability::MachineApplicable,
         );
     }
 }
 
-// TODO: Lint expressions of the form `(x + y).ln()` where y > 1 and
+// Lint expressions of the form `(x + y).ln()` where y >= 1 and
 // suggest usage of `(x + (y - 1)).ln_1p()` instead
 fn check_ln1p(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>) {
     if let ExprKind::Binary(
@@ -181,27 +321,66 @@ fn check_ln1p(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>) {
         rhs,
     ) = receiver.kind
     {
-        let recv = match (
+        let (message, suggestion) = match (
             constant(cx, cx.typeck_results(), lhs),
             constant(cx, cx.typeck_results(), rhs),
         ) {
-            (Some(value), _) if F32(1.0) == value || F64(1.0) == value => rhs,
-            (_, Some(value)) if F32(1.0) == value || F64(1.0) == value => lhs,
+            (Some(value), _) if F32(1.0) == value || F64(1.0) == value => (
+                "ln(1 + x) can be computed more accurately",
+                format!("{}.ln_1p()", prepare_receiver_sugg(cx, rhs)),
+            ),
+            (_, Some(value)) if F32(1.0) == value || F64(1.0) == value => (
+                "ln(1 + x) can be computed more accurately",
+                format!("{}.ln_1p()", prepare_receiver_sugg(cx, lhs)),
+            ),
+            (_, Some(value)) if is_greater_than_one(&value) => (
+                "ln(y + x) can be computed more accurately",
+                format!(
+                    "({} + {}).ln_1p()",
+                    Sugg::hir(cx, lhs, ".."),
+                    decremented_sugg(cx, rhs, &value)
+                ),
+            ),
+            (Some(value), _) if is_greater_than_one(&value) => (
+                "ln(y + x) can be computed more accurately",
+                format!(
+                    "({} + {}).ln_1p()",
+                    decremented_sugg(cx, lhs, &value),
+                    Sugg::hir(cx, rhs, "..")
+                ),
+            ),
             _ => return,
         };
 
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             IMPRECISE_FLOPS,
             expr.span,
-            "ln(1 + x) can be computed more accurately",
+            message,
             "consider using",
-            format!("{}.ln_1p()", prepare_receiver_sugg(cx, recv)),
+            suggestion,
             Applicability::MachineApplicable,
         );
     }
 }
 
+fn is_greater_than_one(value: &Constant<'_>) -> bool {
+    match *value {
+        F32(num) => num > 1.0,
+        F64(num) => num > 1.0,
+        _ => false,
+    }
+}
+
+// Returns the suggestion for `expr - 1` where `expr` evaluates to `value`. Only whole
+// literals are decremented directly, to avoid printing rounded values.
+fn decremented_sugg(cx: &LateContext<'_>, expr: &Expr<'_>, value: &Constant<'_>) -> String {
+    match get_integer_from_float_constant(value) {
+        Some(num) if matches!(expr.kind, ExprKind::Lit(_)) => format!("{}.0", num - 1),
+        _ => format!("({} - 1.0)", Sugg::hir(cx, expr, "..")),
+    }
+}
+
 // Returns an integer if the float constant is a whole number and it can be
 // converted to an integer without loss of precision. For now we only check
 // ranges [-16777215, 16777216) for type f32 as whole number floats outside
@@ -227,7 +406,13 @@ fn get_integer_from_float_constant(value: &Constant<'_>) -> Option<i32> {
     }
 }
 
-fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args: &[Expr<'_>]) {
+fn check_powf(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    receiver: &Expr<'_>,
+    args: &[Expr<'_>],
+    performance_suggestions: bool,
+) {
     // Check receiver
     if let Some(value) = constant(cx, cx.typeck_results(), receiver) {
         if let Some(method) = if F32(f32_consts::E) == value || F64(f64_consts::E) == value {
@@ -237,7 +422,7 @@ fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
         } else {
             None
         } {
-            span_lint_and_sugg(
+            span_lint_and_sugg_unless_external_macro(
                 cx,
                 SUBOPTIMAL_FLOPS,
                 expr.span,
@@ -255,13 +440,55 @@ fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
             (
                 SUBOPTIMAL_FLOPS,
                 "square-root of a number can be computed more efficiently and accurately",
-                format!("{}.sqrt()", Sugg::hir(cx, receiver, "..").maybe_par()),
+                format!(
+                    "{}.sqrt()",
+                    Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
+                ),
             )
         } else if F32(1.0 / 3.0) == value || F64(1.0 / 3.0) == value {
             (
                 IMPRECISE_FLOPS,
                 "cube-root of a number can be computed more accurately",
-                format!("{}.cbrt()", Sugg::hir(cx, receiver, "..").maybe_par()),
+                format!(
+                    "{}.cbrt()",
+                    Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
+                ),
+            )
+        } else if !performance_suggestions {
+            // The remaining suggestions only improve performance
+            return;
+        } else if let Some(-1) = get_integer_from_float_constant(&value) {
+            (
+                SUBOPTIMAL_FLOPS,
+                "reciprocal of a number can be written more clearly",
+                format!(
+                    "{}.recip()",
+                    Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
+                ),
+            )
+        } else if let Some(2) = get_integer_from_float_constant(&value)
+            && let ExprKind::Path(..) = receiver.kind
+        {
+            let recv = Sugg::hir(cx, receiver, "..");
+            // `x * x` binds weaker than the method call it replaces
+            let needs_paren = get_parent_expr(cx, expr).is_some_and(|parent| match parent.kind {
+                ExprKind::MethodCall(_, parent_recv, ..) => parent_recv.hir_id == expr.hir_id,
+                ExprKind::Index(base, ..) => base.hir_id == expr.hir_id,
+                ExprKind::Binary(..)
+                | ExprKind::Unary(..)
+                | ExprKind::AddrOf(..)
+                | ExprKind::Cast(..)
+                | ExprKind::Field(..) => true,
+                _ => false,
+            });
+            (
+                SUBOPTIMAL_FLOPS,
+                "square can be computed more efficiently",
+                if needs_paren {
+                    format!("({recv} * {recv})")
+                } else {
+                    format!("{recv} * {recv}")
+                },
             )
         } else if let Some(exponent) = get_integer_from_float_constant(&value) {
             (
@@ -269,7 +496,7 @@ fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
                 "exponentiation with integer powers can be computed more efficiently",
                 format!(
                     "{}.powi({})",
-                    Sugg::hir(cx, receiver, "..").maybe_par(),
+                    Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver(),
                     numeric_literal::format(&exponent.to_string(), None, false)
                 ),
             )
@@ -277,7 +504,7 @@ fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
             return;
         };
 
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             lint,
             expr.span,
@@ -323,7 +550,7 @@ fn check_powi(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
                         }
                     };
 
-                    span_lint_and_sugg(
+                    span_lint_and_sugg_unless_external_macro(
                         cx,
                         SUBOPTIMAL_FLOPS,
                         parent.span,
@@ -331,7 +558,7 @@ fn check_powi(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
                         "consider using",
                         format!(
                             "{}.mul_add({}, {})",
-                            Sugg::hir(cx, receiver, "..").maybe_par(),
+                            Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver(),
                             maybe_neg_sugg(receiver, expr.hir_id),
                             maybe_neg_sugg(other_addend, other_addend.hir_id),
                         ),
@@ -343,78 +570,54 @@ fn check_powi(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>, args:
     }
 }
 
-fn detect_hypot(cx: &LateContext<'_>, receiver: &Expr<'_>) -> Option<String> {
-    if let ExprKind::Binary(
-        Spanned {
-            node: BinOpKind::Add, ..
-        },
-        add_lhs,
-        add_rhs,
-    ) = receiver.kind
-    {
-        // check if expression of the form x * x + y * y
-        if let ExprKind::Binary(
+/// Returns `x` if the expression is of the form `x * x` or `x.powi(2)`
+fn square_base<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    match expr.kind {
+        ExprKind::Binary(
             Spanned {
                 node: BinOpKind::Mul, ..
             },
-            lmul_lhs,
-            lmul_rhs,
-        ) = add_lhs.kind
-            && let ExprKind::Binary(
-                Spanned {
-                    node: BinOpKind::Mul, ..
-                },
-                rmul_lhs,
-                rmul_rhs,
-            ) = add_rhs.kind
-            && eq_expr_value(cx, lmul_lhs, lmul_rhs)
-            && eq_expr_value(cx, rmul_lhs, rmul_rhs)
+            lhs,
+            rhs,
+        ) if eq_expr_value(cx, lhs, rhs) => Some(lhs),
+        ExprKind::MethodCall(PathSegment { ident, .. }, recv, [arg], _)
+            if ident.as_str() == "powi" && constant(cx, cx.typeck_results(), arg) == Some(Int(2)) =>
         {
-            return Some(format!(
-                "{}.hypot({})",
-                Sugg::hir(cx, lmul_lhs, "..").maybe_par(),
-                Sugg::hir(cx, rmul_lhs, "..")
-            ));
-        }
+            Some(recv)
+        },
+        _ => None,
+    }
+}
 
-        // check if expression of the form x.powi(2) + y.powi(2)
-        if let ExprKind::MethodCall(
-            PathSegment {
-                ident: lmethod_name, ..
+fn detect_hypot(cx: &LateContext<'_>, receiver: &Expr<'_>) -> Option<String> {
+    let (lhs_base, rhs_base) = match receiver.kind {
+        // x * x + y * y, x.powi(2) + y.powi(2) or a mix of both
+        ExprKind::Binary(
+            Spanned {
+                node: BinOpKind::Add, ..
             },
-            largs_0,
-            [largs_1, ..],
-            _,
-        ) = &add_lhs.kind
-            && let ExprKind::MethodCall(
-                PathSegment {
-                    ident: rmethod_name, ..
-                },
-                rargs_0,
-                [rargs_1, ..],
-                _,
-            ) = &add_rhs.kind
-            && lmethod_name.as_str() == "powi"
-            && rmethod_name.as_str() == "powi"
-            && let Some(lvalue) = constant(cx, cx.typeck_results(), largs_1)
-            && let Some(rvalue) = constant(cx, cx.typeck_results(), rargs_1)
-            && Int(2) == lvalue
-            && Int(2) == rvalue
+            add_lhs,
+            add_rhs,
+        ) => (square_base(cx, add_lhs)?, square_base(cx, add_rhs)?),
+        // x.mul_add(x, y * y)
+        ExprKind::MethodCall(PathSegment { ident, .. }, recv, [factor, addend], _)
+            if ident.as_str() == "mul_add" && eq_expr_value(cx, recv, factor) =>
         {
-            return Some(format!(
-                "{}.hypot({})",
-                Sugg::hir(cx, largs_0, "..").maybe_par(),
-                Sugg::hir(cx, rargs_0, "..")
-            ));
-        }
-    }
+            (recv, square_base(cx, addend)?)
+        },
+        _ => return None,
+    };
 
-    None
+    Some(format!(
+        "{}.hypot({})",
+        Sugg::hir(cx, lhs_base, "..").maybe_par_for_method_receiver(),
+        Sugg::hir(cx, rhs_base, "..")
+    ))
 }
 
 fn check_hypot(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>) {
     if let Some(message) = detect_hypot(cx, receiver) {
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             IMPRECISE_FLOPS,
             expr.span,
@@ -426,7 +629,7 @@ fn check_hypot(cx: &LateContext<'_>, expr: &Expr<'_>, receiver: &Expr<'_>) {
     }
 }
 
-// TODO: Lint expressions of the form `x.exp() - y` where y > 1
+// Lint expressions of the form `x.exp() - y` where y >= 1
 // and suggest usage of `x.exp_m1() - (y - 1)` instead
 fn check_expm1(cx: &LateContext<'_>, expr: &Expr<'_>) {
     if let ExprKind::Binary(
@@ -438,18 +641,121 @@ fn check_expm1(cx: &LateContext<'_>, expr: &Expr<'_>) {
     ) = expr.kind
         && cx.typeck_results().expr_ty(lhs).is_floating_point()
         && let Some(value) = constant(cx, cx.typeck_results(), rhs)
-        && (F32(1.0) == value || F64(1.0) == value)
         && let ExprKind::MethodCall(path, self_arg, ..) = &lhs.kind
         && cx.typeck_results().expr_ty(self_arg).is_floating_point()
         && path.ident.name.as_str() == "exp"
     {
-        span_lint_and_sugg(
+        let recv = Sugg::hir(cx, self_arg, "..").maybe_par_for_method_receiver();
+        let (message, suggestion) = if F32(1.0) == value || F64(1.0) == value {
+            (
+                "(e.pow(x) - 1) can be computed more accurately",
+                format!("{recv}.exp_m1()"),
+            )
+        } else if is_greater_than_one(&value) {
+            (
+                "(e.pow(x) - y) can be computed more accurately",
+                format!("{recv}.exp_m1() - {}", decremented_sugg(cx, rhs, &value)),
+            )
+        } else {
+            return;
+        };
+        span_lint_and_sugg_unless_external_macro(
             cx,
             IMPRECISE_FLOPS,
             expr.span,
-            "(e.pow(x) - 1) can be computed more accurately",
+            message,
+            "consider using",
+            suggestion,
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+// Lint products of three or more factors of the same expression, like `x * x * x` or
+// `(x * x) * (x * x)`, and suggest usage of `x.powi(n)` instead
+fn check_repeated_mul(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    // the maximal depth of the multiplication tree
+    const MAX_DEPTH: usize = 8;
+
+    fn collect_factors<'a>(
+        cx: &LateContext<'_>,
+        expr: &'a Expr<'a>,
+        depth: usize,
+        factors: &mut Vec<&'a Expr<'a>>,
+    ) -> bool {
+        if let Some((lhs, rhs)) = is_float_mul_expr(cx, expr) {
+            depth < MAX_DEPTH
+                && collect_factors(cx, lhs, depth + 1, factors)
+                && collect_factors(cx, rhs, depth + 1, factors)
+        } else {
+            factors.push(expr);
+            true
+        }
+    }
+
+    if is_float_mul_expr(cx, expr).is_none()
+        || expr.span.from_expansion()
+        || get_parent_expr(cx, expr).is_some_and(|parent| is_float_mul_expr(cx, parent).is_some())
+    {
+        return;
+    }
+    let mut factors = Vec::new();
+    if collect_factors(cx, expr, 0, &mut factors)
+        && let [first, rest @ ..] = &factors[..]
+        && rest.len() >= 2
+        && rest
+            .iter()
+            .all(|factor| eq_expr_value_allow_pure_calls(cx, first, factor))
+    {
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            "repeated multiplication can be expressed as an integer power",
+            "consider using",
+            format!(
+                "{}.powi({})",
+                Sugg::hir(cx, first, "..").maybe_par_for_method_receiver(),
+                factors.len()
+            ),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+// Lint expressions of the form `x.exp() * y.exp()` and `x.exp() / y.exp()`, and suggest usage of
+// `(x + y).exp()` and `(x - y).exp()` instead, likewise for `exp2`
+fn check_exp_product(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if let ExprKind::Binary(
+        Spanned {
+            node: op @ (BinOpKind::Mul | BinOpKind::Div),
+            ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+        && let ExprKind::MethodCall(lhs_path, lhs_recv, [], _) = lhs.kind
+        && let ExprKind::MethodCall(rhs_path, rhs_recv, [], _) = rhs.kind
+        && let method = lhs_path.ident.name.as_str()
+        && matches!(method, "exp" | "exp2")
+        && method == rhs_path.ident.name.as_str()
+        && cx.typeck_results().expr_ty(lhs_recv).is_floating_point()
+        && cx.typeck_results().expr_ty(rhs_recv).is_floating_point()
+    {
+        let lhs_sugg = Sugg::hir(cx, lhs_recv, "..");
+        let rhs_sugg = Sugg::hir(cx, rhs_recv, "..");
+        let exponent = if op == BinOpKind::Mul {
+            lhs_sugg + rhs_sugg
+        } else {
+            lhs_sugg - rhs_sugg
+        };
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            "exponentials can be combined into a single exponentiation",
             "consider using",
-            format!("{}.exp_m1()", Sugg::hir(cx, self_arg, "..").maybe_par()),
+            format!("{}.{method}()", exponent.maybe_par_for_method_receiver()),
             Applicability::MachineApplicable,
         );
     }
@@ -508,7 +814,7 @@ fn check_mul_add(cx: &LateContext<'_>, expr: &Expr<'_>) {
             return;
         };
 
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             SUBOPTIMAL_FLOPS,
             expr.span,
@@ -517,9 +823,151 @@ fn check_mul_add(cx: &LateContext<'_>, expr: &Expr<'_>) {
             format!("{}.mul_add({arg1}, {arg2})", prepare_receiver_sugg(cx, recv)),
             Applicability::MachineApplicable,
         );
+    } else if let ExprKind::AssignOp(
+        Spanned {
+            node: op @ (BinOpKind::Add | BinOpKind::Sub),
+            ..
+        },
+        lhs,
+        rhs,
+    ) = &expr.kind
+        && cx.typeck_results().expr_ty(lhs).is_floating_point()
+        && let Some((inner_lhs, inner_rhs)) = is_float_mul_expr(cx, rhs)
+    {
+        // `x += a * b` becomes `x = a.mul_add(b, x)`, which evaluates `x` twice
+        let applicability = if path_to_local(lhs).is_some() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let target = Sugg::hir(cx, lhs, "..");
+        let factor = Sugg::hir(cx, inner_rhs, "..");
+        let factor = if let BinOpKind::Sub = op { -factor } else { factor };
+
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            "multiply and add expressions can be calculated more efficiently and accurately",
+            "consider using",
+            format!(
+                "{target} = {}.mul_add({factor}, {target})",
+                prepare_receiver_sugg(cx, inner_lhs)
+            ),
+            applicability,
+        );
     }
 }
 
+// In `no_std` crates, float methods like `ln_1p` aren't available. Lint `(1.0 + x).ln()`,
+// `x.exp() - 1.0` and `a * b + c` there and suggest the corresponding `libm` functions instead
+fn check_libm(cx: &LateContext<'_>, expr: &Expr<'_>, performance_suggestions: bool) {
+    let ty::Float(float_ty) = cx.typeck_results().expr_ty(expr).kind() else {
+        return;
+    };
+    let is_one = |expr: &Expr<'_>| {
+        constant(cx, cx.typeck_results(), expr).is_some_and(|value| F32(1.0) == value || F64(1.0) == value)
+    };
+
+    let (lint, message, function, args) = if let ExprKind::MethodCall(path, receiver, [], _) = expr.kind
+        && path.ident.name.as_str() == "ln"
+        && cx.typeck_results().expr_ty(receiver).is_floating_point()
+        && let ExprKind::Binary(
+            Spanned {
+                node: BinOpKind::Add, ..
+            },
+            lhs,
+            rhs,
+        ) = receiver.kind
+        && let Some(operand) = if is_one(lhs) {
+            Some(rhs)
+        } else if is_one(rhs) {
+            Some(lhs)
+        } else {
+            None
+        }
+    {
+        (
+            IMPRECISE_FLOPS,
+            "ln(1 + x) can be computed more accurately",
+            "log1p",
+            vec![Sugg::hir(cx, operand, "..")],
+        )
+    } else if let ExprKind::Binary(
+        Spanned {
+            node: BinOpKind::Sub, ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+        && let ExprKind::MethodCall(path, receiver, [], _) = lhs.kind
+        && path.ident.name.as_str() == "exp"
+        && cx.typeck_results().expr_ty(receiver).is_floating_point()
+        && is_one(rhs)
+    {
+        (
+            IMPRECISE_FLOPS,
+            "(e.pow(x) - 1) can be computed more accurately",
+            "expm1",
+            vec![Sugg::hir(cx, receiver, "..")],
+        )
+    } else if performance_suggestions
+        && let ExprKind::Binary(
+            Spanned {
+                node: op @ (BinOpKind::Add | BinOpKind::Sub),
+                ..
+            },
+            lhs,
+            rhs,
+        ) = expr.kind
+    {
+        let maybe_neg_sugg = |expr| {
+            let sugg = Sugg::hir(cx, expr, "..");
+            if let BinOpKind::Sub = op { -sugg } else { sugg }
+        };
+
+        let args = if let Some((inner_lhs, inner_rhs)) = is_float_mul_expr(cx, lhs)
+            && cx.typeck_results().expr_ty(rhs).is_floating_point()
+        {
+            vec![
+                Sugg::hir(cx, inner_lhs, ".."),
+                Sugg::hir(cx, inner_rhs, ".."),
+                maybe_neg_sugg(rhs),
+            ]
+        } else if let Some((inner_lhs, inner_rhs)) = is_float_mul_expr(cx, rhs)
+            && cx.typeck_results().expr_ty(lhs).is_floating_point()
+        {
+            vec![
+                Sugg::hir(cx, inner_lhs, ".."),
+                maybe_neg_sugg(inner_rhs),
+                Sugg::hir(cx, lhs, ".."),
+            ]
+        } else {
+            return;
+        };
+        (
+            SUBOPTIMAL_FLOPS,
+            "multiply and add expressions can be calculated more efficiently and accurately",
+            "fma",
+            args,
+        )
+    } else {
+        return;
+    };
+
+    // `libm` suffixes the `f32` versions of its functions with `f`
+    let suffix = if let FloatTy::F32 = float_ty { "f" } else { "" };
+    span_lint_and_sugg_unless_external_macro(
+        cx,
+        lint,
+        expr.span,
+        message,
+        "consider using",
+        format!("libm::{function}{suffix}({})", args.iter().join(", ")),
+        Applicability::MachineApplicable,
+    );
+}
+
 /// Returns true iff expr is an expression which tests whether or not
 /// test is positive or an expression which tests whether or not test
 /// is nonnegative.
@@ -591,11 +1039,11 @@ fn check_custom_abs(cx: &LateContext<'_>, expr: &Expr<'_>) {
     {
         let positive_abs_sugg = (
             "manual implementation of `abs` method",
-            format!("{}.abs()", Sugg::hir(cx, body, "..").maybe_par()),
+            format!("{}.abs()", Sugg::hir(cx, body, "..").maybe_par_for_method_receiver()),
         );
         let negative_abs_sugg = (
             "manual implementation of negation of `abs` method",
-            format!("-{}.abs()", Sugg::hir(cx, body, "..").maybe_par()),
+            format!("-{}.abs()", Sugg::hir(cx, body, "..").maybe_par_for_method_receiver()),
         );
         let sugg = if is_testing_positive(cx, cond, body) {
             if if_expr_positive {
@@ -612,7 +1060,7 @@ fn check_custom_abs(cx: &LateContext<'_>, expr: &Expr<'_>) {
         } else {
             return;
         };
-        span_lint_and_sugg(
+        span_lint_and_sugg_unless_external_macro(
             cx,
             SUBOPTIMAL_FLOPS,
             expr.span,
@@ -624,6 +1072,185 @@ fn check_custom_abs(cx: &LateContext<'_>, expr: &Expr<'_>) {
     }
 }
 
+// Lint expressions of the form `x * x.signum()` and `x.signum() * x`
+fn check_signum_abs(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if let ExprKind::Binary(
+        Spanned {
+            node: BinOpKind::Mul, ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+        && cx.typeck_results().expr_ty(expr).is_floating_point()
+    {
+        let body = [(lhs, rhs), (rhs, lhs)].into_iter().find_map(|(value, sign)| {
+            if let ExprKind::MethodCall(path, receiver, [], _) = sign.kind
+                && path.ident.name.as_str() == "signum"
+                && eq_expr_value(cx, value, receiver)
+            {
+                Some(value)
+            } else {
+                None
+            }
+        });
+        if let Some(body) = body {
+            span_lint_and_sugg_unless_external_macro(
+                cx,
+                SUBOPTIMAL_FLOPS,
+                expr.span,
+                "manual implementation of `abs` method",
+                "try",
+                format!("{}.abs()", Sugg::hir(cx, body, "..").maybe_par_for_method_receiver()),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+// Lint expressions of the form `x - x.trunc()`, `x % 1.0` and, for non-negative `x`,
+// `x - x.floor()`
+fn check_manual_fract(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return;
+    };
+    if !cx.typeck_results().expr_ty(lhs).is_floating_point() {
+        return;
+    }
+
+    let is_fract = match op.node {
+        BinOpKind::Sub => {
+            if let ExprKind::MethodCall(path, receiver, [], _) = rhs.kind
+                && eq_expr_value(cx, lhs, receiver)
+            {
+                match path.ident.name.as_str() {
+                    "trunc" => true,
+                    // `floor` rounds towards negative infinity, which only matches `trunc` for
+                    // positive values
+                    "floor" => is_non_negative(cx, lhs),
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        },
+        BinOpKind::Rem => {
+            constant(cx, cx.typeck_results(), rhs).is_some_and(|value| F32(1.0) == value || F64(1.0) == value)
+        },
+        _ => false,
+    };
+
+    if is_fract {
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            "manual implementation of `fract` method",
+            "try",
+            format!("{}.fract()", Sugg::hir(cx, lhs, "..").maybe_par_for_method_receiver()),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+fn is_non_negative(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, _, [], _) = expr.kind {
+        path.ident.name.as_str() == "abs"
+    } else {
+        match constant(cx, cx.typeck_results(), expr) {
+            Some(F32(value)) => value >= 0.0,
+            Some(F64(value)) => value >= 0.0,
+            _ => false,
+        }
+    }
+}
+
+// Lint expressions of the form `if a < b { a } else { b }` and
+// `match a < b { true => a, false => b }`, and suggest usage of `a.min(b)` or `a.max(b)` instead
+fn check_manual_min_max(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    let (cond, then, r#else) = if let Some(higher::If {
+        cond,
+        then,
+        r#else: Some(r#else),
+    }) = higher::If::hir(expr)
+    {
+        (cond, then, r#else)
+    } else if let ExprKind::Match(cond, [first, second], MatchSource::Normal) = expr.kind
+        && first.guard.is_none()
+        && second.guard.is_none()
+        && let PatKind::Lit(first_lit) = first.pat.kind
+        && let ExprKind::Lit(lit) = first_lit.kind
+        && let ast::LitKind::Bool(first_value) = lit.node
+        && matches!(second.pat.kind, PatKind::Wild | PatKind::Lit(_))
+    {
+        if first_value {
+            (cond, first.body, second.body)
+        } else {
+            (cond, second.body, first.body)
+        }
+    } else {
+        return;
+    };
+
+    if let ExprKind::Binary(Spanned { node: op, .. }, left, right) = cond.kind
+        && cx.typeck_results().expr_ty(left).is_floating_point()
+        && let then = peel_blocks(then)
+        && let r#else = peel_blocks(r#else)
+    {
+        let returns_left = if eq_expr_value(cx, left, then) && eq_expr_value(cx, right, r#else) {
+            true
+        } else if eq_expr_value(cx, right, then) && eq_expr_value(cx, left, r#else) {
+            false
+        } else {
+            return;
+        };
+        let method = match (op, returns_left) {
+            (BinOpKind::Lt | BinOpKind::Le, true) | (BinOpKind::Gt | BinOpKind::Ge, false) => "min",
+            (BinOpKind::Lt | BinOpKind::Le, false) | (BinOpKind::Gt | BinOpKind::Ge, true) => "max",
+            _ => return,
+        };
+
+        // If the operand returned when the comparison fails is NaN, this expression returns NaN,
+        // while `min` and `max` return the other operand. This can't happen if it is a constant.
+        let nan_matters = match constant(cx, cx.typeck_results(), r#else) {
+            Some(F32(value)) => value.is_nan(),
+            Some(F64(value)) => value.is_nan(),
+            _ => true,
+        };
+        let applicability = if nan_matters {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let Some(applicability) = applicability_for_span(cx, expr.span, applicability) else {
+            return;
+        };
+        span_lint_and_then(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            &format!("manual implementation of `{method}` method"),
+            |diag| {
+                diag.span_suggestion(
+                    expr.span,
+                    "try",
+                    format!(
+                        "{}.{method}({})",
+                        Sugg::hir(cx, left, "..").maybe_par_for_method_receiver(),
+                        Sugg::hir(cx, right, "..")
+                    ),
+                    applicability,
+                );
+                if nan_matters {
+                    diag.note(format!(
+                        "if one of the operands is NaN, `{method}` returns the other one, while this returns `{}`",
+                        snippet(cx, r#else.span, "..")
+                    ));
+                }
+            },
+        );
+    }
+}
+
 fn are_same_base_logs(cx: &LateContext<'_>, expr_a: &Expr<'_>, expr_b: &Expr<'_>) -> bool {
     if let ExprKind::MethodCall(
         PathSegment {
@@ -664,119 +1291,536 @@ fn check_log_division(cx: &LateContext<'_>, expr: &Expr<'_>) {
         && let ExprKind::MethodCall(_, largs_self, ..) = &lhs.kind
         && let ExprKind::MethodCall(_, rargs_self, ..) = &rhs.kind
     {
-        span_lint_and_sugg(
+        // dividing by the logarithm of 2, 10 or e is the same as using the specialized method
+        let (message, suggestion) = if let Some(method) = get_specialized_log_method(cx, rargs_self) {
+            (
+                "logarithm for bases 2, 10 and e can be computed more accurately",
+                format!(
+                    "{}.{method}()",
+                    Sugg::hir(cx, largs_self, "..").maybe_par_for_method_receiver()
+                ),
+            )
+        } else {
+            (
+                "log base can be expressed more clearly",
+                format!(
+                    "{}.log({})",
+                    Sugg::hir(cx, largs_self, "..").maybe_par_for_method_receiver(),
+                    Sugg::hir(cx, rargs_self, ".."),
+                ),
+            )
+        };
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            message,
+            "consider using",
+            suggestion,
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+// Lint expressions of the form `x.ln() / LN_2` and `x.ln() / LN_10`, including approximations of
+// these constants like `x.ln() / 0.6931472`
+fn check_ln_constant_division(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if let ExprKind::Binary(
+        Spanned {
+            node: BinOpKind::Div, ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+        && let ExprKind::MethodCall(path, receiver, [], _) = lhs.kind
+        && path.ident.name.as_str() == "ln"
+        && cx.typeck_results().expr_ty(receiver).is_floating_point()
+        && let Some(value) = constant_float(cx, rhs)
+    {
+        let (method, target) = if is_near(value, f64_consts::LN_2, 1e-4) {
+            ("log2", f64_consts::LN_2)
+        } else if is_near(value, f64_consts::LN_10, 1e-4) {
+            ("log10", f64_consts::LN_10)
+        } else {
+            return;
+        };
+
+        // Constants that are only approximately right change the result slightly
+        let applicability = if is_near(value, target, 1e-6) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+
+        span_lint_and_sugg_unless_external_macro(
             cx,
             SUBOPTIMAL_FLOPS,
             expr.span,
-            "log base can be expressed more clearly",
+            "logarithm for bases 2, 10 and e can be computed more accurately",
             "consider using",
             format!(
-                "{}.log({})",
-                Sugg::hir(cx, largs_self, "..").maybe_par(),
-                Sugg::hir(cx, rargs_self, ".."),
+                "{}.{method}()",
+                Sugg::hir(cx, receiver, "..").maybe_par_for_method_receiver()
             ),
-            Applicability::MachineApplicable,
+            applicability,
         );
     }
 }
 
-fn check_radians(cx: &LateContext<'_>, expr: &Expr<'_>) {
+fn constant_float(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<f64> {
+    match constant(cx, cx.typeck_results(), expr)? {
+        F32(value) => Some(f64::from(value)),
+        F64(value) => Some(value),
+        _ => None,
+    }
+}
+
+// Returns the variable operand of a multiplication by a float constant, and the value of the
+// constant
+fn split_constant_factor<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, f64)> {
     if let ExprKind::Binary(
+        Spanned {
+            node: BinOpKind::Mul, ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+    {
+        if let Some(value) = constant_float(cx, rhs) {
+            return Some((lhs, value));
+        }
+        if let Some(value) = constant_float(cx, lhs) {
+            return Some((rhs, value));
+        }
+    }
+    None
+}
+
+// Lint expressions of the form `x * 180 / PI`, `x * (180 / PI)` and `x * PI / 180`, as well as
+// multiplications by approximations of these factors like `x * 57.29578`
+fn check_radians(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    let (recv, factor) = if let ExprKind::Binary(
         Spanned {
             node: BinOpKind::Div, ..
         },
         div_lhs,
         div_rhs,
-    ) = &expr.kind
-        && let ExprKind::Binary(
-            Spanned {
-                node: BinOpKind::Mul, ..
-            },
-            mul_lhs,
-            mul_rhs,
-        ) = &div_lhs.kind
-        && let Some(rvalue) = constant(cx, cx.typeck_results(), div_rhs)
-        && let Some(lvalue) = constant(cx, cx.typeck_results(), mul_rhs)
+    ) = expr.kind
     {
-        // TODO: also check for constant values near PI/180 or 180/PI
-        if (F32(f32_consts::PI) == rvalue || F64(f64_consts::PI) == rvalue)
-            && (F32(180_f32) == lvalue || F64(180_f64) == lvalue)
+        if let Some((recv, numerator)) = split_constant_factor(cx, div_lhs)
+            && let Some(denominator) = constant_float(cx, div_rhs)
         {
-            let mut proposal = format!("{}.to_degrees()", Sugg::hir(cx, mul_lhs, "..").maybe_par());
-            if let ExprKind::Lit(literal) = mul_lhs.kind
-                && let ast::LitKind::Float(ref value, float_type) = literal.node
-                && float_type == ast::LitFloatType::Unsuffixed
+            (recv, numerator / denominator)
+        } else {
+            return;
+        }
+    } else if let Some(split) = split_constant_factor(cx, expr) {
+        split
+    } else {
+        return;
+    };
+    if !cx.typeck_results().expr_ty(recv).is_floating_point() {
+        return;
+    }
+
+    let (method, message, target) = if is_near(factor, 180.0 / f64_consts::PI, 1e-4) {
+        (
+            "to_degrees",
+            "conversion to degrees can be done more accurately",
+            180.0 / f64_consts::PI,
+        )
+    } else if is_near(factor, f64_consts::PI / 180.0, 1e-4) {
+        (
+            "to_radians",
+            "conversion to radians can be done more accurately",
+            f64_consts::PI / 180.0,
+        )
+    } else {
+        return;
+    };
+
+    let proposal = if let ExprKind::Lit(literal) = recv.kind
+        && let ast::LitKind::Float(ref value, float_type) = literal.node
+        && float_type == ast::LitFloatType::Unsuffixed
+    {
+        if value.as_str().ends_with('.') {
+            format!("{}0_f64.{method}()", Sugg::hir(cx, recv, ".."))
+        } else {
+            format!("{}_f64.{method}()", Sugg::hir(cx, recv, ".."))
+        }
+    } else {
+        format!(
+            "{}.{method}()",
+            Sugg::hir(cx, recv, "..").maybe_par_for_method_receiver()
+        )
+    };
+
+    // Factors that are only approximately right change the result slightly
+    let applicability = if is_near(factor, target, 1e-6) {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+
+    span_lint_and_sugg_unless_external_macro(
+        cx,
+        SUBOPTIMAL_FLOPS,
+        expr.span,
+        message,
+        "consider using",
+        proposal,
+        applicability,
+    );
+}
+
+fn is_near(value: f64, target: f64, relative_tolerance: f64) -> bool {
+    ((value - target) / target).abs() < relative_tolerance
+}
+
+// Lint expressions of the form `1.0 / x` whose result is multiplied further
+fn check_reciprocal_division(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if let ExprKind::Binary(
+        Spanned {
+            node: BinOpKind::Div, ..
+        },
+        lhs,
+        rhs,
+    ) = expr.kind
+        && cx.typeck_results().expr_ty(rhs).is_floating_point()
+        && let Some(value) = constant(cx, cx.typeck_results(), lhs)
+        && (F32(1.0) == value || F64(1.0) == value)
+        && let Some(parent) = get_parent_expr(cx, expr)
+        && matches!(
+            parent.kind,
+            ExprKind::Binary(
+                Spanned {
+                    node: BinOpKind::Mul,
+                    ..
+                },
+                ..
+            )
+        )
+    {
+        span_lint_and_sugg_unless_external_macro(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr.span,
+            "reciprocal of a number can be written more clearly",
+            "consider using",
+            format!("{}.recip()", Sugg::hir(cx, rhs, "..").maybe_par_for_method_receiver()),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+// The named constants of `std::f32::consts` and `std::f64::consts` suggested for folded values,
+// along with the version they were stabilized in if it is more recent than 1.0
+const NAMED_F32_CONSTS: &[(f32, &str, Option<RustcVersion>)] = &[
+    (f32_consts::E, "E", None),
+    (f32_consts::FRAC_1_SQRT_2, "FRAC_1_SQRT_2", None),
+    (f32_consts::LN_10, "LN_10", None),
+    (f32_consts::LN_2, "LN_2", None),
+    (f32_consts::LOG10_2, "LOG10_2", Some(msrvs::LOG10_2)),
+    (f32_consts::LOG10_E, "LOG10_E", None),
+    (f32_consts::LOG2_10, "LOG2_10", Some(msrvs::LOG2_10)),
+    (f32_consts::LOG2_E, "LOG2_E", None),
+    (f32_consts::PI, "PI", None),
+    (f32_consts::SQRT_2, "SQRT_2", None),
+];
+const NAMED_F64_CONSTS: &[(f64, &str, Option<RustcVersion>)] = &[
+    (f64_consts::E, "E", None),
+    (f64_consts::FRAC_1_SQRT_2, "FRAC_1_SQRT_2", None),
+    (f64_consts::LN_10, "LN_10", None),
+    (f64_consts::LN_2, "LN_2", None),
+    (f64_consts::LOG10_2, "LOG10_2", Some(msrvs::LOG10_2)),
+    (f64_consts::LOG10_E, "LOG10_E", None),
+    (f64_consts::LOG2_10, "LOG2_10", Some(msrvs::LOG2_10)),
+    (f64_consts::LOG2_E, "LOG2_E", None),
+    (f64_consts::PI, "PI", None),
+    (f64_consts::SQRT_2, "SQRT_2", None),
+];
+
+// Lint float expressions whose value only depends on constants once float methods are evaluated,
+// e.g. `2.0_f64.powf(10.0)`
+fn check_constant_computation(cx: &LateContext<'_>, expr: &Expr<'_>, msrv: &Msrv) {
+    if expr.span.from_expansion()
+        || !is_constant_computation_shape(expr)
+        || !cx.typeck_results().expr_ty(expr).is_floating_point()
+    {
+        return;
+    }
+    let Some(value) = constant_with_float_methods(cx, cx.typeck_results(), expr) else {
+        return;
+    };
+    // only lint the outermost computation
+    let parent = get_parent_expr(cx, expr);
+    if parent.is_some_and(|parent| {
+        is_constant_computation_shape(parent)
+            && cx.typeck_results().expr_ty(parent).is_floating_point()
+            && constant_with_float_methods(cx, cx.typeck_results(), parent).is_some()
+    }) {
+        return;
+    }
+
+    let (literal, negative) = match value {
+        F32(value) if value.is_finite() => (
+            NAMED_F32_CONSTS
+                .iter()
+                .find(|(constant, _, since)| {
+                    constant.to_bits() == value.to_bits() && since.map_or(true, |since| msrv.meets(since))
+                })
+                .map_or_else(
+                    || format!("{value:?}_f32"),
+                    |(_, name, _)| format!("std::f32::consts::{name}"),
+                ),
+            value.is_sign_negative(),
+        ),
+        F64(value) if value.is_finite() => (
+            NAMED_F64_CONSTS
+                .iter()
+                .find(|(constant, _, since)| {
+                    constant.to_bits() == value.to_bits() && since.map_or(true, |since| msrv.meets(since))
+                })
+                .map_or_else(
+                    || format!("{value:?}_f64"),
+                    |(_, name, _)| format!("std::f64::consts::{name}"),
+                ),
+            value.is_sign_negative(),
+        ),
+        _ => return,
+    };
+    let needs_paren = negative
+        && parent.is_some_and(
+            |parent| matches!(parent.kind, ExprKind::MethodCall(_, recv, ..) if recv.hir_id == expr.hir_id),
+        );
+
+    span_lint_and_sugg_unless_external_macro(
+        cx,
+        CONSTANT_FLOAT_COMPUTATION,
+        expr.span,
+        "floating point computation on constants",
+        "consider using the result",
+        if needs_paren { format!("({literal})") } else { literal },
+        Applicability::MaybeIncorrect,
+    );
+}
+
+// Cheap syntactic check whether `expr` could be folded by `constant_with_float_methods`, so const
+// evaluation is only attempted for operators and method calls on literals and paths to items.
+// Only expressions containing a method call are of interest, the others are folded by `constant`.
+fn is_constant_computation_shape(expr: &Expr<'_>) -> bool {
+    fn is_shape(expr: &Expr<'_>, has_method_call: &mut bool) -> bool {
+        match expr.kind {
+            ExprKind::Lit(_) => true,
+            ExprKind::Path(_) => path_to_local(expr).is_none(),
+            ExprKind::Unary(UnOp::Neg, operand) => is_shape(operand, has_method_call),
+            ExprKind::Binary(_, lhs, rhs) => is_shape(lhs, has_method_call) && is_shape(rhs, has_method_call),
+            ExprKind::MethodCall(_, receiver, args, _) => {
+                *has_method_call = true;
+                is_shape(receiver, has_method_call) && args.iter().all(|arg| is_shape(arg, has_method_call))
+            },
+            _ => false,
+        }
+    }
+
+    let mut has_method_call = false;
+    is_shape(expr, &mut has_method_call) && has_method_call
+}
+
+// Lint blocks containing both `let s = x.sin();` and `let c = x.cos();`, which can be computed at
+// once with `x.sin_cos()`
+fn check_sin_cos<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+    // the `let` statements initialized with `x.sin()` or `x.cos()`
+    let calls: Vec<_> = block
+        .stmts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, stmt)| {
+            if let StmtKind::Local(local) = stmt.kind
+                && local.els.is_none()
+                && let Some(init) = local.init
+                && !init.span.from_expansion()
+                && let ExprKind::MethodCall(path, receiver, [], _) = init.kind
+                && matches!(path.ident.name.as_str(), "sin" | "cos")
+                && cx.typeck_results().expr_ty(receiver).is_floating_point()
             {
-                if value.as_str().ends_with('.') {
-                    proposal = format!("{}0_f64.to_degrees()", Sugg::hir(cx, mul_lhs, ".."));
-                } else {
-                    proposal = format!("{}_f64.to_degrees()", Sugg::hir(cx, mul_lhs, ".."));
-                }
+                Some((index, local, path.ident.name.as_str() == "sin", receiver))
+            } else {
+                None
             }
-            span_lint_and_sugg(
-                cx,
-                SUBOPTIMAL_FLOPS,
-                expr.span,
-                "conversion to degrees can be done more accurately",
-                "consider using",
-                proposal,
-                Applicability::MachineApplicable,
-            );
-        } else if (F32(180_f32) == rvalue || F64(180_f64) == rvalue)
-            && (F32(f32_consts::PI) == lvalue || F64(f64_consts::PI) == lvalue)
+        })
+        .collect();
+
+    let mut paired = vec![false; calls.len()];
+    for (pos, &(first_index, first, first_is_sin, value)) in calls.iter().enumerate() {
+        if paired[pos] {
+            continue;
+        }
+        let Some(offset) =
+            calls[pos + 1..]
+                .iter()
+                .zip(&paired[pos + 1..])
+                .position(|(&(_, _, is_sin, receiver), &done)| {
+                    !done && is_sin != first_is_sin && eq_expr_value(cx, value, receiver)
+                })
+        else {
+            continue;
+        };
+        let (second_index, second, _, _) = calls[pos + 1 + offset];
+        if block.stmts[first_index + 1..second_index]
+            .iter()
+            .any(|stmt| is_mutated_in(cx, stmt, value))
         {
-            let mut proposal = format!("{}.to_radians()", Sugg::hir(cx, mul_lhs, "..").maybe_par());
-            if let ExprKind::Lit(literal) = mul_lhs.kind
-                && let ast::LitKind::Float(ref value, float_type) = literal.node
-                && float_type == ast::LitFloatType::Unsuffixed
-            {
-                if value.as_str().ends_with('.') {
-                    proposal = format!("{}0_f64.to_radians()", Sugg::hir(cx, mul_lhs, ".."));
-                } else {
-                    proposal = format!("{}_f64.to_radians()", Sugg::hir(cx, mul_lhs, ".."));
-                }
-            }
-            span_lint_and_sugg(
+            continue;
+        }
+        paired[pos + 1 + offset] = true;
+
+        let (sin, cos) = if first_is_sin { (first, second) } else { (second, first) };
+        span_lint_and_help(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            block.stmts[first_index].span,
+            "sine and cosine of the same value are computed separately",
+            Some(block.stmts[second_index].span),
+            format!(
+                "consider computing both at once: `let ({}, {}) = {}.sin_cos();`",
+                snippet(cx, sin.pat.span, ".."),
+                snippet(cx, cos.pat.span, ".."),
+                Sugg::hir(cx, value, "..").maybe_par_for_method_receiver(),
+            ),
+        );
+    }
+
+    let exprs = block.stmts.iter().filter_map(|stmt| match stmt.kind {
+        StmtKind::Local(local) => local.init,
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+        StmtKind::Item(_) => None,
+    });
+    for expr in exprs.chain(block.expr) {
+        check_sin_cos_in_expr(cx, block, expr);
+    }
+}
+
+// Lint expressions computing both the sine and the cosine of the same value, like
+// `x.sin() * x.cos()`
+fn check_sin_cos_in_expr<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if expr.span.from_expansion() || !mutated_variables(expr, cx).is_some_and(|mutated| mutated.is_empty()) {
+        return;
+    }
+    let call_on = |value: &Expr<'_>, name: &str| {
+        get_parent_expr(cx, value).filter(|call| {
+            matches!(
+                call.kind,
+                ExprKind::MethodCall(path, receiver, [], _)
+                    if receiver.hir_id == value.hir_id && path.ident.name.as_str() == name
+            )
+            // calls in nested blocks are linted when checking those blocks
+            && get_enclosing_block(cx, call.hir_id).is_some_and(|enclosing| enclosing.hir_id == block.hir_id)
+        })
+    };
+
+    for group in repeated_subexprs(cx, expr) {
+        if cx.typeck_results().expr_ty(group[0]).is_floating_point()
+            && let Some(sin) = group.iter().find_map(|&value| call_on(value, "sin"))
+            && let Some(cos) = group.iter().find_map(|&value| call_on(value, "cos"))
+        {
+            span_lint_and_help(
                 cx,
                 SUBOPTIMAL_FLOPS,
-                expr.span,
-                "conversion to radians can be done more accurately",
-                "consider using",
-                proposal,
-                Applicability::MachineApplicable,
+                sin.span,
+                "sine and cosine of the same value are computed separately",
+                Some(cos.span),
+                format!(
+                    "consider computing both at once with `{}.sin_cos()`",
+                    Sugg::hir(cx, group[0], "..").maybe_par_for_method_receiver()
+                ),
             );
         }
     }
 }
 
+// Checks whether one of the locals used in `value` may be mutated by `stmt`
+fn is_mutated_in<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>, value: &Expr<'_>) -> bool {
+    let expr = match stmt.kind {
+        StmtKind::Local(local) => local.init,
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+        StmtKind::Item(_) => None,
+    };
+    expr.is_some_and(|expr| {
+        mutated_variables(expr, cx).map_or(true, |mutated| {
+            for_each_expr(value, |e| {
+                if path_to_local(e).is_some_and(|id| mutated.contains(&id)) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        })
+    })
+}
+
 impl<'tcx> LateLintPass<'tcx> for FloatingPointArithmetic {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // Only method calls, operators and conditionals can be linted, which lets most expressions
+        // skip the parent lookups of `in_constant`
+        if !matches!(
+            expr.kind,
+            ExprKind::MethodCall(..)
+                | ExprKind::Binary(..)
+                | ExprKind::Unary(..)
+                | ExprKind::If(..)
+                | ExprKind::Match(..)
+        ) {
+            return;
+        }
+
         // All of these operations are currently not const and are in std.
         if in_constant(cx, expr.hir_id) {
             return;
         }
 
-        if let ExprKind::MethodCall(path, receiver, args, _) = &expr.kind {
-            let recv_ty = cx.typeck_results().expr_ty(receiver);
-
-            if recv_ty.is_floating_point() && !is_no_std_crate(cx) {
-                match path.ident.name.as_str() {
-                    "ln" => check_ln1p(cx, expr, receiver),
-                    "log" => check_log_base(cx, expr, receiver, args),
-                    "powf" => check_powf(cx, expr, receiver, args),
-                    "powi" => check_powi(cx, expr, receiver, args),
-                    "sqrt" => check_hypot(cx, expr, receiver),
-                    _ => {},
+        let no_std = is_no_std_crate(cx);
+        let performance_suggestions = self.performance_suggestions(cx, expr.hir_id);
+        match expr.kind {
+            ExprKind::MethodCall(path, receiver, args, _) => {
+                if no_std {
+                    if self.suggest_libm {
+                        check_libm(cx, expr, performance_suggestions);
+                    }
+                } else if cx.typeck_results().expr_ty(receiver).is_floating_point() {
+                    match path.ident.name.as_str() {
+                        "ln" => check_ln1p(cx, expr, receiver),
+                        "log" => check_log_base(cx, expr, receiver, args),
+                        "powf" => check_powf(cx, expr, receiver, args, performance_suggestions),
+                        "powi" if performance_suggestions => check_powi(cx, expr, receiver, args),
+                        "sqrt" => check_hypot(cx, expr, receiver),
+                        _ => {},
+                    }
                 }
-            }
-        } else {
-            if !is_no_std_crate(cx) {
-                check_expm1(cx, expr);
-                check_mul_add(cx, expr);
+            },
+            ExprKind::Binary(op, ..) => {
+                if no_std && self.suggest_libm {
+                    check_libm(cx, expr, performance_suggestions);
+                }
+                self.check_binary(cx, expr, op.node, no_std, performance_suggestions);
+            },
+            ExprKind::If(..) | ExprKind::Match(..) if !no_std => {
                 check_custom_abs(cx, expr);
-                check_log_division(cx, expr);
-            }
-            check_radians(cx, expr);
+                check_manual_min_max(cx, expr);
+            },
+            _ => {},
+        }
+        if !no_std {
+            check_constant_computation(cx, expr, &self.msrv);
         }
     }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if self.performance_suggestions(cx, block.hir_id) && !in_constant(cx, block.hir_id) && !is_no_std_crate(cx) {
+            check_sin_cos(cx, block);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
 }
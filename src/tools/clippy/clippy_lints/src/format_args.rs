@@ -1,13 +1,13 @@
 use arrayvec::ArrayVec;
 use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::is_diag_trait_item;
 use clippy_utils::macros::{
     find_format_arg_expr, find_format_args, format_arg_removal_span, format_placeholder_format_span, is_assert_macro,
     is_format_macro, is_panic, root_macro_call, root_macro_call_first_node, FormatParamUsage, MacroCall,
 };
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{implements_trait, is_type_lang_item};
+use clippy_utils::{eq_expr_value, is_diag_trait_item};
 use itertools::Itertools;
 use rustc_ast::{
     FormatArgPosition, FormatArgPositionKind, FormatArgsPiece, FormatArgumentKind, FormatCount, FormatOptions,
@@ -160,11 +160,37 @@
     "use of a format specifier that has no effect"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Detects the same argument being passed more than once to `format!()`, `println!()` or
+    /// similar macros, e.g. `format!("{} {}", x.name, x.name)`.
+    ///
+    /// ### Why is this bad?
+    /// An argument can be referred to multiple times in the format string by its index or
+    /// name, instead of being repeated in the argument list.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let x = (1, 2);
+    /// println!("{}{}", x.0, x.0);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let x = (1, 2);
+    /// println!("{0}{0}", x.0);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub DUPLICATED_FORMAT_ARGS,
+    pedantic,
+    "passing the same argument more than once to a formatting macro"
+}
+
 impl_lint_pass!(FormatArgs => [
     FORMAT_IN_FORMAT_ARGS,
     TO_STRING_IN_FORMAT_ARGS,
     UNINLINED_FORMAT_ARGS,
     UNUSED_FORMAT_SPECS,
+    DUPLICATED_FORMAT_ARGS,
 ]);
 
 pub struct FormatArgs {
@@ -197,6 +223,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
             };
 
             linter.check_templates();
+            linter.check_duplicated_args();
 
             if self.msrv.meets(msrvs::FORMAT_ARGS_CAPTURE) {
                 linter.check_uninlined_args();
@@ -306,6 +333,44 @@ fn check_unused_format_specifier(
         }
     }
 
+    fn check_duplicated_args(&self) {
+        if self.format_args.span.from_expansion() {
+            return;
+        }
+        let args = self.format_args.arguments.explicit_args();
+        let arg_exprs: Vec<_> = args
+            .iter()
+            .map(|arg| find_format_arg_expr(self.expr, arg).ok())
+            .collect();
+
+        for (i, arg_expr) in arg_exprs.iter().enumerate() {
+            let Some(arg_expr) = arg_expr else {
+                continue;
+            };
+            // Only compare with the first occurrence, `eq_expr_value` rejects expressions with side effects
+            if let Some((first_index, first_expr)) = arg_exprs[..i]
+                .iter()
+                .enumerate()
+                .find_map(|(j, e)| (*e).filter(|e| eq_expr_value(self.cx, e, arg_expr)).map(|e| (j, e)))
+            {
+                let reference = match args[first_index].kind {
+                    FormatArgumentKind::Named(ident) => ident.name.to_string(),
+                    _ => first_index.to_string(),
+                };
+                span_lint_and_then(
+                    self.cx,
+                    DUPLICATED_FORMAT_ARGS,
+                    arg_expr.span,
+                    "this argument is passed more than once",
+                    |diag| {
+                        diag.span_note(first_expr.span, "it is first passed here");
+                        diag.help(format!("refer to the first argument with `{{{reference}}}` instead"));
+                    },
+                );
+            }
+        }
+    }
+
     fn check_uninlined_args(&self) {
         if self.format_args.span.from_expansion() {
             return;
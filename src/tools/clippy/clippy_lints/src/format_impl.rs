@@ -1,9 +1,9 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::macros::{find_format_arg_expr, find_format_args, is_format_macro, root_macro_call_first_node};
-use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_to_local, peel_ref_operators};
+use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_def_id, path_to_local, peel_ref_operators};
 use rustc_ast::{FormatArgsPiece, FormatTrait};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath};
+use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::kw;
@@ -148,24 +148,29 @@ struct FormatImplExpr<'a, 'tcx> {
 impl<'a, 'tcx> FormatImplExpr<'a, 'tcx> {
     fn check_to_string_in_display(&self) {
         if self.format_trait_impl.name == sym::Display
-            && let ExprKind::MethodCall(path, self_arg, ..) = self.expr.kind
-            // Get the hir_id of the object we are calling the method on
-            // Is the method to_string() ?
-            && path.ident.name == sym::to_string
+            // Either `self.to_string()` or `ToString::to_string(self)`
+            && let Some((expr_def_id, self_arg)) = match self.expr.kind {
+                ExprKind::MethodCall(path, self_arg, [], _) if path.ident.name == sym::to_string => self
+                    .cx
+                    .typeck_results()
+                    .type_dependent_def_id(self.expr.hir_id)
+                    .map(|id| (id, self_arg)),
+                ExprKind::Call(func, [self_arg]) => path_def_id(self.cx, func).map(|id| (id, self_arg)),
+                _ => None,
+            }
             // Is the method a part of the ToString trait? (i.e. not to_string() implemented
             // separately)
-            && let Some(expr_def_id) = self.cx.typeck_results().type_dependent_def_id(self.expr.hir_id)
             && is_diag_trait_item(self.cx, expr_def_id, sym::ToString)
-            // Is the method is called on self
-            && let ExprKind::Path(QPath::Resolved(_, path)) = self_arg.kind
-            && let [segment] = path.segments
-            && segment.ident.name == kw::SelfLower
+            // Is the method called on self, possibly behind references or dereferences?
+            && self.is_self(self_arg)
         {
-            span_lint(
+            span_lint_and_help(
                 self.cx,
                 RECURSIVE_FORMAT_IMPL,
                 self.expr.span,
                 "using `self.to_string` in `fmt::Display` implementation will cause infinite recursion",
+                None,
+                "format the fields of `self` directly instead",
             );
         }
     }
@@ -205,10 +210,7 @@ fn check_format_arg_self(&self, arg: &Expr<'_>) {
         // Handle multiple dereferencing of references e.g. &&self
         // Handle dereference of &self -> self that is equivalent (i.e. via *self in fmt() impl)
         // Since the argument to fmt is itself a reference: &self
-        let reference = peel_ref_operators(self.cx, arg);
-        let map = self.cx.tcx.hir();
-        // Is the reference self?
-        if path_to_local(reference).map(|x| map.name(x)) == Some(kw::SelfLower) {
+        if self.is_self(arg) {
             let FormatTraitNames { name, .. } = self.format_trait_impl;
             span_lint(
                 self.cx,
@@ -219,6 +221,11 @@ fn check_format_arg_self(&self, arg: &Expr<'_>) {
         }
     }
 
+    fn is_self(&self, expr: &Expr<'_>) -> bool {
+        let reference = peel_ref_operators(self.cx, expr);
+        path_to_local(reference).map(|x| self.cx.tcx.hir().name(x)) == Some(kw::SelfLower)
+    }
+
     fn check_print_in_format_impl(&self) {
         if let Some(macro_call) = root_macro_call_first_node(self.cx, self.expr)
             && let Some(name) = self.cx.tcx.get_diagnostic_name(macro_call.def_id)
@@ -6,7 +6,10 @@
 mod too_many_arguments;
 mod too_many_lines;
 
+use clippy_utils::def_path_def_ids;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
@@ -291,6 +294,45 @@
     "function returning `Result` with large `Err` type"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public functions of library crates that return a `Result` whose error type
+    /// is type-erased, i.e. `Box<dyn Error>` or one of the types listed in the
+    /// `erased-error-types` configuration (e.g. `anyhow::Error`).
+    ///
+    /// ### Why is this bad?
+    /// Callers of the function cannot match on the possible errors, they can only
+    /// display them or try to downcast them to a type that isn't part of the signature.
+    ///
+    /// The lint can be allowed for a module with `#[allow(clippy::result_erased_err)]`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn parse(s: &str) -> Result<u8, Box<dyn std::error::Error>> {
+    ///     Ok(s.parse()?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum ParseError {
+    ///     Empty,
+    ///     Invalid(std::num::ParseIntError),
+    /// }
+    ///
+    /// pub fn parse(s: &str) -> Result<u8, ParseError> {
+    ///     if s.is_empty() {
+    ///         return Err(ParseError::Empty);
+    ///     }
+    ///     s.parse().map_err(ParseError::Invalid)
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub RESULT_ERASED_ERR,
+    pedantic,
+    "public function returning `Result` with a type-erased `Err` type"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for getter methods that return a field that doesn't correspond
@@ -359,13 +401,14 @@
     "`impl Trait` is used in the function's parameters"
 }
 
-#[derive(Copy, Clone)]
 #[allow(clippy::struct_field_names)]
 pub struct Functions {
     too_many_arguments_threshold: u64,
     too_many_lines_threshold: u64,
     large_error_threshold: u64,
     avoid_breaking_exported_api: bool,
+    erased_error_types: Vec<String>,
+    erased_error_def_ids: FxHashSet<DefId>,
 }
 
 impl Functions {
@@ -374,12 +417,15 @@ pub fn new(
         too_many_lines_threshold: u64,
         large_error_threshold: u64,
         avoid_breaking_exported_api: bool,
+        erased_error_types: Vec<String>,
     ) -> Self {
         Self {
             too_many_arguments_threshold,
             too_many_lines_threshold,
             large_error_threshold,
             avoid_breaking_exported_api,
+            erased_error_types,
+            erased_error_def_ids: FxHashSet::default(),
         }
     }
 }
@@ -393,11 +439,19 @@ pub fn new(
     MUST_USE_CANDIDATE,
     RESULT_UNIT_ERR,
     RESULT_LARGE_ERR,
+    RESULT_ERASED_ERR,
     MISNAMED_GETTERS,
     IMPL_TRAIT_IN_PARAMS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Functions {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.erased_error_types {
+            let segments: Vec<&str> = path.split("::").collect();
+            self.erased_error_def_ids.extend(def_path_def_ids(cx, &segments));
+        }
+    }
+
     fn check_fn(
         &mut self,
         cx: &LateContext<'tcx>,
@@ -417,12 +471,12 @@ fn check_fn(
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         must_use::check_item(cx, item);
-        result::check_item(cx, item, self.large_error_threshold);
+        result::check_item(cx, item, self.large_error_threshold, &self.erased_error_def_ids);
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         must_use::check_impl_item(cx, item);
-        result::check_impl_item(cx, item, self.large_error_threshold);
+        result::check_impl_item(cx, item, self.large_error_threshold, &self.erased_error_def_ids);
         impl_trait_in_params::check_impl_item(cx, item);
     }
 
@@ -430,7 +484,7 @@ fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitIte
         too_many_arguments::check_trait_item(cx, item, self.too_many_arguments_threshold);
         not_unsafe_ptr_arg_deref::check_trait_item(cx, item);
         must_use::check_trait_item(cx, item);
-        result::check_trait_item(cx, item, self.large_error_threshold);
+        result::check_trait_item(cx, item, self.large_error_threshold, &self.erased_error_def_ids);
         impl_trait_in_params::check_trait_item(cx, item, self.avoid_breaking_exported_api);
     }
 }
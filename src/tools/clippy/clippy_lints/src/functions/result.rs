@@ -1,15 +1,18 @@
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Diag;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Adt, Ty};
+use rustc_session::config::CrateType;
 use rustc_span::{sym, Span};
 
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
 use clippy_utils::trait_ref_of_method;
 use clippy_utils::ty::{approx_ty_size, is_type_diagnostic_item, AdtVariantInfo};
 
-use super::{RESULT_LARGE_ERR, RESULT_UNIT_ERR};
+use super::{RESULT_ERASED_ERR, RESULT_LARGE_ERR, RESULT_UNIT_ERR};
 
 /// The type of the `Err`-variant in a `std::result::Result` returned by the
 /// given `FnDecl`
@@ -34,19 +37,30 @@ fn result_err_ty<'tcx>(
     }
 }
 
-pub(super) fn check_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::Item<'tcx>, large_err_threshold: u64) {
+pub(super) fn check_item<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &hir::Item<'tcx>,
+    large_err_threshold: u64,
+    erased_err_types: &FxHashSet<DefId>,
+) {
     if let hir::ItemKind::Fn(ref sig, _generics, _) = item.kind
         && let Some((hir_ty, err_ty)) = result_err_ty(cx, sig.decl, item.owner_id.def_id, item.span)
     {
         if cx.effective_visibilities.is_exported(item.owner_id.def_id) {
             let fn_header_span = item.span.with_hi(sig.decl.output.span().hi());
             check_result_unit_err(cx, err_ty, fn_header_span);
+            check_result_erased_err(cx, err_ty, hir_ty.span, erased_err_types);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
     }
 }
 
-pub(super) fn check_impl_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::ImplItem<'tcx>, large_err_threshold: u64) {
+pub(super) fn check_impl_item<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &hir::ImplItem<'tcx>,
+    large_err_threshold: u64,
+    erased_err_types: &FxHashSet<DefId>,
+) {
     // Don't lint if method is a trait's implementation, we can't do anything about those
     if let hir::ImplItemKind::Fn(ref sig, _) = item.kind
         && let Some((hir_ty, err_ty)) = result_err_ty(cx, sig.decl, item.owner_id.def_id, item.span)
@@ -55,17 +69,24 @@ pub(super) fn check_impl_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::ImplItem
         if cx.effective_visibilities.is_exported(item.owner_id.def_id) {
             let fn_header_span = item.span.with_hi(sig.decl.output.span().hi());
             check_result_unit_err(cx, err_ty, fn_header_span);
+            check_result_erased_err(cx, err_ty, hir_ty.span, erased_err_types);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
     }
 }
 
-pub(super) fn check_trait_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::TraitItem<'tcx>, large_err_threshold: u64) {
+pub(super) fn check_trait_item<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &hir::TraitItem<'tcx>,
+    large_err_threshold: u64,
+    erased_err_types: &FxHashSet<DefId>,
+) {
     if let hir::TraitItemKind::Fn(ref sig, _) = item.kind {
         let fn_header_span = item.span.with_hi(sig.decl.output.span().hi());
         if let Some((hir_ty, err_ty)) = result_err_ty(cx, sig.decl, item.owner_id.def_id, item.span) {
             if cx.effective_visibilities.is_exported(item.owner_id.def_id) {
                 check_result_unit_err(cx, err_ty, fn_header_span);
+                check_result_erased_err(cx, err_ty, hir_ty.span, erased_err_types);
             }
             check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
         }
@@ -85,6 +106,43 @@ fn check_result_unit_err(cx: &LateContext<'_>, err_ty: Ty<'_>, fn_header_span: S
     }
 }
 
+fn check_result_erased_err(
+    cx: &LateContext<'_>,
+    err_ty: Ty<'_>,
+    hir_ty_span: Span,
+    erased_err_types: &FxHashSet<DefId>,
+) {
+    let is_erased = match err_ty.kind() {
+        // `Box<dyn Error + ..>`
+        Adt(..) if err_ty.is_box() => matches!(
+            err_ty.boxed_ty().kind(),
+            ty::Dynamic(preds, ..) if preds
+                .principal_def_id()
+                .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::Error, def_id))
+        ),
+        Adt(adt, _) => erased_err_types.contains(&adt.did()),
+        _ => false,
+    };
+
+    // Binaries have no API whose callers could match on the errors
+    if is_erased
+        && cx
+            .tcx
+            .crate_types()
+            .iter()
+            .any(|t| matches!(t, CrateType::Rlib | CrateType::Dylib))
+    {
+        span_lint_and_help(
+            cx,
+            RESULT_ERASED_ERR,
+            hir_ty_span,
+            "this returns a `Result` with a type-erased `Err` type",
+            None,
+            "use a concrete error type, e.g. an enum of the possible errors, so that callers can match on it",
+        );
+    }
+}
+
 fn check_result_large_err<'tcx>(cx: &LateContext<'tcx>, err_ty: Ty<'tcx>, hir_ty_span: Span, large_err_threshold: u64) {
     if let Adt(adt, subst) = err_ty.kind()
         && let Some(local_def_id) = err_ty
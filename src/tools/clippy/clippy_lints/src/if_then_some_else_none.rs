@@ -1,5 +1,5 @@
 use clippy_config::msrvs::{self, Msrv};
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::eager_or_lazy::switch_to_eager_eval;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::sugg::Sugg;
@@ -93,34 +93,44 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
             && is_res_lang_ctor(cx, path_res(cx, then_call), OptionSome)
             && is_res_lang_ctor(cx, path_res(cx, peel_blocks(els)), OptionNone)
             && !contains_return(then_block.stmts)
+            && !contains_return(then_arg)
         {
-            let mut app = Applicability::Unspecified;
+            let mut app = Applicability::MachineApplicable;
             let cond_snip = Sugg::hir_with_context(cx, cond, expr.span.ctxt(), "[condition]", &mut app)
                 .maybe_par()
                 .to_string();
             let arg_snip = snippet_with_context(cx, then_arg.span, ctxt, "[body]", &mut app).0;
-            let mut method_body = if then_block.stmts.is_empty() {
-                arg_snip.into_owned()
-            } else {
-                format!("{{ /* snippet */ {arg_snip} }}")
-            };
-            let method_name = if switch_to_eager_eval(cx, expr) && self.msrv.meets(msrvs::BOOL_THEN_SOME) {
+            // Only a side effect free value can be evaluated eagerly
+            let method_name = if then_block.stmts.is_empty()
+                && switch_to_eager_eval(cx, then_arg)
+                && self.msrv.meets(msrvs::BOOL_THEN_SOME)
+            {
                 "then_some"
             } else {
-                method_body.insert_str(0, "|| ");
                 "then"
             };
+            let msg = format!("this could be simplified with `bool::{method_name}`");
 
-            let help =
-                format!("consider using `bool::{method_name}` like: `{cond_snip}.{method_name}({method_body})`",);
-            span_lint_and_help(
-                cx,
-                IF_THEN_SOME_ELSE_NONE,
-                expr.span,
-                &format!("this could be simplified with `bool::{method_name}`"),
-                None,
-                &help,
-            );
+            if then_block.stmts.is_empty() {
+                let method_body = if method_name == "then" {
+                    format!("|| {arg_snip}")
+                } else {
+                    arg_snip.into_owned()
+                };
+                span_lint_and_sugg(
+                    cx,
+                    IF_THEN_SOME_ELSE_NONE,
+                    expr.span,
+                    &msg,
+                    "try",
+                    format!("{cond_snip}.{method_name}({method_body})"),
+                    app,
+                );
+            } else {
+                let help =
+                    format!("consider using `bool::then` like: `{cond_snip}.then(|| {{ /* snippet */ {arg_snip} }})`");
+                span_lint_and_help(cx, IF_THEN_SOME_ELSE_NONE, expr.span, &msg, None, &help);
+            }
         }
     }
 
@@ -1,8 +1,8 @@
 use clippy_config::msrvs::{self, Msrv};
-use clippy_utils::diagnostics::{self, span_lint_and_sugg};
+use clippy_utils::diagnostics::{self, span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty;
+use clippy_utils::{paths, ty};
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -40,7 +40,7 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Lints subtraction between an `Instant` and a `Duration`.
+    /// Lints subtraction between an `Instant` or a `SystemTime` and a `Duration`.
     ///
     /// ### Why is this bad?
     /// Unchecked subtraction could cause underflow on certain platforms, leading to
@@ -60,7 +60,70 @@
     #[clippy::version = "1.67.0"]
     pub UNCHECKED_DURATION_SUBTRACTION,
     pedantic,
-    "finds unchecked subtraction of a 'Duration' from an 'Instant'"
+    "finds unchecked subtraction of a 'Duration' from an 'Instant' or a 'SystemTime'"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Lints subtraction between two `Instant`s, and subtraction of a `Duration` from the
+    /// time elapsed since an `Instant`.
+    ///
+    /// ### Why is this bad?
+    /// Subtracting a later `Instant` from an earlier one panicked before Rust 1.60 and now
+    /// silently returns a zero `Duration`, while subtracting a `Duration` from a shorter elapsed
+    /// time panics. `saturating_duration_since` and `saturating_sub` make the behavior explicit.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::{Duration, Instant};
+    /// # let earlier = Instant::now();
+    /// # let later = Instant::now();
+    /// # let timeout = Duration::from_secs(5);
+    /// let gap = later - earlier;
+    /// let overdue = earlier.elapsed() - timeout;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # use std::time::{Duration, Instant};
+    /// # let earlier = Instant::now();
+    /// # let later = Instant::now();
+    /// # let timeout = Duration::from_secs(5);
+    /// let gap = later.saturating_duration_since(earlier);
+    /// let overdue = earlier.elapsed().saturating_sub(timeout);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub UNCHECKED_INSTANT_DIFFERENCE,
+    pedantic,
+    "subtraction between `Instant`s or from an elapsed time that can underflow"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Lints unwrapping the result of `SystemTime::duration_since` and `SystemTime::elapsed`.
+    ///
+    /// ### Why is this bad?
+    /// `SystemTime` follows the system clock, which is not monotonic: it can be adjusted
+    /// backwards at any time, in which case these methods return an error and the program
+    /// panics.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// let start = SystemTime::now();
+    /// let elapsed = SystemTime::now().duration_since(start).unwrap();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// use std::time::Instant;
+    /// let start = Instant::now();
+    /// let elapsed = start.elapsed();
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub UNCHECKED_SYSTEM_TIME_DIFFERENCE,
+    pedantic,
+    "unwrapping the difference between two `SystemTime`s"
 }
 
 pub struct InstantSubtraction {
@@ -74,7 +137,12 @@ pub fn new(msrv: Msrv) -> Self {
     }
 }
 
-impl_lint_pass!(InstantSubtraction => [MANUAL_INSTANT_ELAPSED, UNCHECKED_DURATION_SUBTRACTION]);
+impl_lint_pass!(InstantSubtraction => [
+    MANUAL_INSTANT_ELAPSED,
+    UNCHECKED_DURATION_SUBTRACTION,
+    UNCHECKED_INSTANT_DIFFERENCE,
+    UNCHECKED_SYSTEM_TIME_DIFFERENCE,
+]);
 
 impl LateLintPass<'_> for InstantSubtraction {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ Expr<'_>) {
@@ -93,11 +161,41 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ Expr<'_>) {
                 print_manual_instant_elapsed_sugg(cx, expr, sugg);
             } else if !expr.span.from_expansion()
                 && self.msrv.meets(msrvs::TRY_FROM)
+                && let Some(time_ty) = time_ty_name(cx, lhs)
+                && is_a_duration(cx, rhs)
+            {
+                print_unchecked_duration_subtraction_sugg(cx, lhs, rhs, expr, time_ty);
+            } else if !expr.span.from_expansion()
+                && self.msrv.meets(msrvs::INSTANT_SATURATING_DURATION_SINCE)
                 && is_an_instant(cx, lhs)
+                && is_an_instant(cx, rhs)
+            {
+                print_unchecked_instant_difference_sugg(cx, lhs, rhs, expr, "saturating_duration_since");
+            } else if !expr.span.from_expansion()
+                && self.msrv.meets(msrvs::DURATION_SATURATING_SUB)
+                && is_instant_elapsed_call(cx, lhs)
                 && is_a_duration(cx, rhs)
             {
-                print_unchecked_duration_subtraction_sugg(cx, lhs, rhs, expr);
+                print_unchecked_instant_difference_sugg(cx, lhs, rhs, expr, "saturating_sub");
             }
+        } else if let ExprKind::MethodCall(unwrap, recv, _, _) = expr.kind
+            && matches!(unwrap.ident.as_str(), "unwrap" | "expect")
+            && let ExprKind::MethodCall(method, time, _, _) = recv.kind
+            && matches!(method.ident.as_str(), "duration_since" | "elapsed")
+            && !expr.span.from_expansion()
+            && is_a_system_time(cx, time)
+        {
+            span_lint_and_help(
+                cx,
+                UNCHECKED_SYSTEM_TIME_DIFFERENCE,
+                expr.span,
+                &format!(
+                    "`SystemTime::{}` fails when the system clock goes backwards",
+                    method.ident
+                ),
+                None,
+                "handle the error explicitly, or use `Instant` to measure elapsed time",
+            );
         }
     }
 
@@ -115,11 +213,37 @@ fn is_instant_now_call(cx: &LateContext<'_>, expr_block: &'_ Expr<'_>) -> bool {
     }
 }
 
+fn is_instant_elapsed_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(method, recv, [], _) = expr.kind
+        && method.ident.name.as_str() == "elapsed"
+    {
+        is_an_instant(cx, recv)
+    } else {
+        false
+    }
+}
+
 fn is_an_instant(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     let expr_ty = cx.typeck_results().expr_ty(expr);
     ty::is_type_diagnostic_item(cx, expr_ty, sym::Instant)
 }
 
+fn is_a_system_time(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let expr_ty = cx.typeck_results().expr_ty(expr);
+    ty::match_type(cx, expr_ty, &paths::SYSTEM_TIME)
+}
+
+/// Returns the name of the type of `expr`, if it is a point in time.
+fn time_ty_name(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if is_an_instant(cx, expr) {
+        Some("an 'Instant'")
+    } else if is_a_system_time(cx, expr) {
+        Some("a 'SystemTime'")
+    } else {
+        None
+    }
+}
+
 fn is_a_duration(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     let expr_ty = cx.typeck_results().expr_ty(expr);
     ty::is_type_diagnostic_item(cx, expr_ty, sym::Duration)
@@ -142,6 +266,7 @@ fn print_unchecked_duration_subtraction_sugg(
     left_expr: &Expr<'_>,
     right_expr: &Expr<'_>,
     expr: &Expr<'_>,
+    time_ty: &str,
 ) {
     let mut applicability = Applicability::MachineApplicable;
 
@@ -153,9 +278,40 @@ fn print_unchecked_duration_subtraction_sugg(
         cx,
         UNCHECKED_DURATION_SUBTRACTION,
         expr.span,
-        "unchecked subtraction of a 'Duration' from an 'Instant'",
+        &format!("unchecked subtraction of a 'Duration' from {time_ty}"),
         "try",
         format!("{left_expr}.checked_sub({right_expr}).unwrap()"),
         applicability,
     );
 }
+
+fn print_unchecked_instant_difference_sugg(
+    cx: &LateContext<'_>,
+    left_expr: &Expr<'_>,
+    right_expr: &Expr<'_>,
+    expr: &Expr<'_>,
+    method: &str,
+) {
+    let mut applicability = Applicability::MachineApplicable;
+
+    let ctxt = expr.span.ctxt();
+    let left_sugg = Sugg::hir_with_context(cx, left_expr, ctxt, "..", &mut applicability);
+    let right_expr = snippet_with_context(cx, right_expr.span, ctxt, "..", &mut applicability).0;
+
+    let msg = if method == "saturating_sub" {
+        // Unlike the subtraction, `saturating_sub` doesn't panic
+        applicability = Applicability::MaybeIncorrect;
+        "unchecked subtraction of a 'Duration' from the time elapsed since an 'Instant'"
+    } else {
+        "unchecked subtraction between two 'Instant's"
+    };
+    span_lint_and_sugg(
+        cx,
+        UNCHECKED_INSTANT_DIFFERENCE,
+        expr.span,
+        msg,
+        "try",
+        format!("{}.{method}({right_expr})", left_sugg.maybe_par()),
+        applicability,
+    );
+}
@@ -1,12 +1,16 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::higher::ForLoop;
-use clippy_utils::match_any_def_paths;
 use clippy_utils::paths::{
     HASHMAP_DRAIN, HASHMAP_ITER, HASHMAP_ITER_MUT, HASHMAP_KEYS, HASHMAP_VALUES, HASHMAP_VALUES_MUT, HASHSET_DRAIN,
     HASHSET_ITER_TY,
 };
-use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_enclosing_block, is_trait_method, match_any_def_paths, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, LangItem, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::Ty;
 use rustc_session::declare_lint_pass;
 use rustc_span::sym;
 
@@ -40,32 +44,49 @@
     "iterating over unordered hash-based types (`HashMap` and `HashSet`)"
 }
 
-declare_lint_pass!(IterOverHashType => [ITER_OVER_HASH_TYPE]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterations over hash-based types (i.e. `HashMap` and `HashSet`) whose order
+    /// ends up in the result: iterators collected into or extending an ordered collection
+    /// (`Vec`, `VecDeque`, `LinkedList` or `String`), and iterators compared with
+    /// `Iterator::eq`, `Iterator::cmp` and the like.
+    ///
+    /// ### Why is this bad?
+    /// The iteration order of hash-based types is unspecified and usually differs between runs,
+    /// so the result is nondeterministic. This causes flaky tests and output that changes
+    /// for no apparent reason.
+    ///
+    /// ### Known problems
+    /// The order may not matter for how the result is used later on. Collections which are
+    /// sorted right after being collected into a local are not linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<String, u32> = HashMap::new();
+    /// let names: Vec<&String> = map.keys().collect();
+    /// println!("{}", names.len());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::BTreeMap;
+    /// let map: BTreeMap<String, u32> = BTreeMap::new();
+    /// let names: Vec<&String> = map.keys().collect();
+    /// println!("{}", names.len());
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub HASH_ORDER_DEPENDENT_ITERATION,
+    restriction,
+    "results which depend on the iteration order of hash-based types"
+}
 
-impl LateLintPass<'_> for IterOverHashType {
-    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ rustc_hir::Expr<'_>) {
+declare_lint_pass!(IterOverHashType => [ITER_OVER_HASH_TYPE, HASH_ORDER_DEPENDENT_ITERATION]);
+
+impl<'tcx> LateLintPass<'tcx> for IterOverHashType {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some(for_loop) = ForLoop::hir(expr)
             && !for_loop.body.span.from_expansion()
-            && let ty = cx.typeck_results().expr_ty(for_loop.arg).peel_refs()
-            && let Some(adt) = ty.ty_adt_def()
-            && let did = adt.did()
-            && (match_any_def_paths(
-                cx,
-                did,
-                &[
-                    &HASHMAP_KEYS,
-                    &HASHMAP_VALUES,
-                    &HASHMAP_VALUES_MUT,
-                    &HASHMAP_ITER,
-                    &HASHMAP_ITER_MUT,
-                    &HASHMAP_DRAIN,
-                    &HASHSET_ITER_TY,
-                    &HASHSET_DRAIN,
-                ],
-            )
-            .is_some()
-                || is_type_diagnostic_item(cx, ty, sym::HashMap)
-                || is_type_diagnostic_item(cx, ty, sym::HashSet))
+            && is_hash_type_or_iter(cx, cx.typeck_results().expr_ty(for_loop.arg).peel_refs())
         {
             span_lint(
                 cx,
@@ -74,5 +95,121 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ rustc_hir::Expr<'_>) {
                 "iteration over unordered hash-based type",
             );
         };
+
+        if let ExprKind::MethodCall(path, recv, args, _) = expr.kind
+            && !expr.span.from_expansion()
+        {
+            let msg = match (path.ident.as_str(), args) {
+                ("collect", [])
+                    if is_trait_method(cx, expr, sym::Iterator)
+                        && is_ordered_collection(cx, cx.typeck_results().expr_ty(expr))
+                        && is_hash_ordered(cx, recv)
+                        && !is_sorted_afterwards(cx, expr) =>
+                {
+                    "collecting the elements of a hash-based type into an ordered collection"
+                },
+                ("extend", [arg])
+                    if is_ordered_collection(cx, cx.typeck_results().expr_ty(recv).peel_refs())
+                        && is_hash_ordered(cx, arg) =>
+                {
+                    "extending an ordered collection with the elements of a hash-based type"
+                },
+                ("eq" | "ne" | "lt" | "le" | "gt" | "ge" | "cmp" | "partial_cmp", [arg])
+                    if is_trait_method(cx, expr, sym::Iterator)
+                        && (is_hash_ordered(cx, recv) || is_hash_ordered(cx, arg)) =>
+                {
+                    "comparing the elements of a hash-based type in iteration order"
+                },
+                _ => return,
+            };
+            span_lint_and_help(
+                cx,
+                HASH_ORDER_DEPENDENT_ITERATION,
+                expr.span,
+                msg,
+                None,
+                "the iteration order of hash-based types is unspecified, \
+                consider using a `BTreeMap` or `BTreeSet`, or sorting the elements",
+            );
+        }
+    }
+}
+
+/// Checks if the type is a `HashMap`, a `HashSet` or one of their iterators.
+fn is_hash_type_or_iter(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if let Some(adt) = ty.ty_adt_def()
+        && match_any_def_paths(
+            cx,
+            adt.did(),
+            &[
+                &HASHMAP_KEYS,
+                &HASHMAP_VALUES,
+                &HASHMAP_VALUES_MUT,
+                &HASHMAP_ITER,
+                &HASHMAP_ITER_MUT,
+                &HASHMAP_DRAIN,
+                &HASHSET_ITER_TY,
+                &HASHSET_DRAIN,
+            ],
+        )
+        .is_some()
+    {
+        return true;
+    }
+    is_type_diagnostic_item(cx, ty, sym::HashMap) || is_type_diagnostic_item(cx, ty, sym::HashSet)
+}
+
+/// Checks if the elements of the iterator (or `IntoIterator`) `expr` come from a hash-based type
+/// in iteration order, following iterator adapters like `map` and `filter`.
+fn is_hash_ordered<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> bool {
+    loop {
+        let ty = cx.typeck_results().expr_ty(expr);
+        if is_hash_type_or_iter(cx, ty.peel_refs()) {
+            return true;
+        }
+        match expr.kind {
+            ExprKind::AddrOf(_, _, inner) => expr = inner,
+            ExprKind::MethodCall(_, recv, ..)
+                if is_trait_method(cx, expr, sym::IntoIterator)
+                    || (is_trait_method(cx, expr, sym::Iterator)
+                        && cx
+                            .tcx
+                            .get_diagnostic_item(sym::Iterator)
+                            .is_some_and(|iter_id| implements_trait(cx, ty, iter_id, &[]))) =>
+            {
+                expr = recv;
+            },
+            _ => return false,
+        }
+    }
+}
+
+fn is_ordered_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    is_type_diagnostic_item(cx, ty, sym::Vec)
+        || is_type_diagnostic_item(cx, ty, sym::VecDeque)
+        || is_type_diagnostic_item(cx, ty, sym::LinkedList)
+        || is_type_lang_item(cx, ty, LangItem::String)
+}
+
+/// Checks if `expr` initializes a local which is sorted later on, e.g.
+/// `let mut v: Vec<_> = map.keys().collect(); v.sort();`
+fn is_sorted_afterwards(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Node::Local(local) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let PatKind::Binding(_, id, ..) = local.pat.kind
+        && let Some(block) = get_enclosing_block(cx, local.hir_id)
+    {
+        for_each_expr(block, |e| {
+            if let ExprKind::MethodCall(path, recv, ..) = e.kind
+                && path.ident.as_str().starts_with("sort")
+                && path_to_local_id(recv, id)
+            {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_some()
+    } else {
+        false
     }
 }
@@ -3,13 +3,20 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::{approx_ty_size, is_copy, AdtVariantInfo};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind};
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, walk_pat, Visitor};
+use rustc_hir::pat_util::EnumerateAndAdjustIterator;
+use rustc_hir::{BodyId, Expr, ExprKind, HirId, Item, ItemKind, Pat, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{Adt, Ty};
+use rustc_middle::ty::{Adt, Ty, TypeckResults};
 use rustc_session::impl_lint_pass;
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
+use rustc_target::abi::FieldIdx;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -59,9 +66,11 @@
     "large size difference between variants on an enum"
 }
 
-#[derive(Copy, Clone)]
 pub struct LargeEnumVariant {
     maximum_size_difference_allowed: u64,
+    /// The places where the variants of the crate are used, collected the first time a
+    /// suggestion needs them
+    variant_uses: Option<FxHashMap<DefId, Vec<VariantUse>>>,
 }
 
 impl LargeEnumVariant {
@@ -69,8 +78,21 @@ impl LargeEnumVariant {
     pub fn new(maximum_size_difference_allowed: u64) -> Self {
         Self {
             maximum_size_difference_allowed,
+            variant_uses: None,
         }
     }
+
+    fn variant_uses(&mut self, cx: &LateContext<'_>) -> &FxHashMap<DefId, Vec<VariantUse>> {
+        self.variant_uses.get_or_insert_with(|| {
+            let mut collector = VariantUseCollector {
+                cx,
+                typeck_results: None,
+                uses: FxHashMap::default(),
+            };
+            cx.tcx.hir().walk_toplevel_module(&mut collector);
+            collector.uses
+        })
+    }
 }
 
 impl_lint_pass!(LargeEnumVariant => [LARGE_ENUM_VARIANT]);
@@ -92,6 +114,30 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
 
             let mut difference = variants_size[0].size - variants_size[1].size;
             if difference > self.maximum_size_difference_allowed {
+                let is_copy = is_copy(cx, ty) || maybe_copy(cx, ty);
+                let boxed: Vec<usize> = if is_copy {
+                    Vec::new()
+                } else {
+                    variants_size[0]
+                        .fields_size
+                        .iter()
+                        .rev()
+                        .map_while(|&(ind, size)| {
+                            if difference > self.maximum_size_difference_allowed {
+                                difference = difference.saturating_sub(size);
+                                Some(ind)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                };
+                let variant_uses = if boxed.is_empty() {
+                    None
+                } else {
+                    Some(self.variant_uses(cx))
+                };
+
                 let help_text = "consider boxing the large fields to reduce the total size of the enum";
                 span_lint_and_then(
                     cx,
@@ -119,46 +165,37 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
                             },
                         );
 
-                        let fields = def.variants[variants_size[0].ind].data.fields();
-                        let mut applicability = Applicability::MaybeIncorrect;
-                        if is_copy(cx, ty) || maybe_copy(cx, ty) {
+                        let variant = &def.variants[variants_size[0].ind];
+                        if is_copy {
                             diag.span_note(
                                 item.ident.span,
                                 "boxing a variant would require the type no longer be `Copy`",
                             );
-                        } else {
-                            let sugg: Vec<(Span, String)> = variants_size[0]
-                                .fields_size
+                        } else if let Some(variant_uses) = variant_uses {
+                            // Other crates may construct or match on an exported variant
+                            let mut applicability = if cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+                                Applicability::MaybeIncorrect
+                            } else {
+                                Applicability::MachineApplicable
+                            };
+                            let fields = variant.data.fields();
+                            let mut sugg: Vec<(Span, String)> = boxed
                                 .iter()
-                                .rev()
-                                .map_while(|&(ind, size)| {
-                                    if difference > self.maximum_size_difference_allowed {
-                                        difference = difference.saturating_sub(size);
-                                        Some((
-                                            fields[ind].ty.span,
-                                            format!(
-                                                "Box<{}>",
-                                                snippet_with_applicability(
-                                                    cx,
-                                                    fields[ind].ty.span,
-                                                    "..",
-                                                    &mut applicability
-                                                )
-                                                .into_owned()
-                                            ),
-                                        ))
-                                    } else {
-                                        None
-                                    }
+                                .map(|&ind| {
+                                    let ty_span = fields[ind].ty.span;
+                                    let snip = snippet_with_applicability(cx, ty_span, "..", &mut applicability);
+                                    (ty_span, format!("Box<{snip}>"))
                                 })
                                 .collect();
 
-                            if !sugg.is_empty() {
-                                diag.multipart_suggestion(help_text, sugg, Applicability::MaybeIncorrect);
-                                return;
+                            // Also box the fields where the variant is constructed
+                            if let Some(uses) = variant_uses.get(&variant.def_id.to_def_id()) {
+                                box_variant_uses(cx, uses, &boxed, &mut sugg, &mut applicability);
                             }
+                            diag.multipart_suggestion(help_text, sugg, applicability);
+                            return;
                         }
-                        diag.span_help(def.variants[variants_size[0].ind].span, help_text);
+                        diag.span_help(variant.span, help_text);
                     },
                 );
             }
@@ -175,3 +212,191 @@ fn maybe_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     }
     false
 }
+
+/// Adds the changes needed where a variant is constructed once the fields at the `boxed` indices
+/// are boxed, and lowers the applicability for uses which can't be fixed automatically.
+fn box_variant_uses(
+    cx: &LateContext<'_>,
+    uses: &[VariantUse],
+    boxed: &[usize],
+    sugg: &mut Vec<(Span, String)>,
+    applicability: &mut Applicability,
+) {
+    for variant_use in uses {
+        match variant_use {
+            VariantUse::Construct(values) => {
+                for value in values.iter().filter(|value| boxed.contains(&value.index)) {
+                    if let Some(value_span) = value.value_span {
+                        let snip = snippet_with_applicability(cx, value_span, "..", applicability);
+                        let boxed_value = match value.shorthand {
+                            Some(name) => format!("{name}: Box::new({snip})"),
+                            None => format!("Box::new({snip})"),
+                        };
+                        sugg.push((value.span, boxed_value));
+                    } else {
+                        *applicability = Applicability::MaybeIncorrect;
+                    }
+                }
+            },
+            VariantUse::CtorFn => *applicability = Applicability::MaybeIncorrect,
+            // Patterns binding a boxed field now bind a `Box`, which may not be used the same way
+            VariantUse::Destructure(bound) => {
+                if bound.iter().any(|ind| boxed.contains(ind)) {
+                    *applicability = Applicability::MaybeIncorrect;
+                }
+            },
+        }
+    }
+}
+
+/// A place where a variant is constructed or destructured.
+enum VariantUse {
+    /// `Variant(a, b)` or `Variant { a, b: c }`
+    Construct(Vec<FieldValue>),
+    /// The constructor is used as a function, e.g. `.map(Enum::Variant)`
+    CtorFn,
+    /// A pattern binding the fields at these indices
+    Destructure(Vec<usize>),
+}
+
+/// The value given to a field when constructing a variant.
+struct FieldValue {
+    index: usize,
+    /// The span to replace when boxing the value
+    span: Span,
+    /// The name of the field, if it is written in the shorthand form
+    shorthand: Option<Symbol>,
+    /// The span of the value, or `None` if it comes from a macro expansion
+    value_span: Option<Span>,
+}
+
+impl FieldValue {
+    fn new(index: usize, expr: &Expr<'_>, value: &Expr<'_>, span: Span, shorthand: Option<Symbol>) -> Self {
+        Self {
+            index,
+            span,
+            shorthand,
+            value_span: (!expr.span.from_expansion() && !value.span.from_expansion()).then_some(value.span),
+        }
+    }
+}
+
+/// Collects the places where the variants of the enums in the crate are used, so the
+/// suggestions can box the fields there too.
+struct VariantUseCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: Option<&'tcx TypeckResults<'tcx>>,
+    uses: FxHashMap<DefId, Vec<VariantUse>>,
+}
+
+impl<'a, 'tcx> VariantUseCollector<'a, 'tcx> {
+    /// Returns the local variant that `qpath` resolves to, and whether it resolves to its
+    /// constructor rather than to the variant itself.
+    fn variant(&self, qpath: &QPath<'_>, hir_id: HirId) -> Option<(DefId, bool)> {
+        let res = match self.typeck_results {
+            Some(typeck_results) => typeck_results.qpath_res(qpath, hir_id),
+            None => match qpath {
+                QPath::Resolved(_, path) => path.res,
+                _ => Res::Err,
+            },
+        };
+        match res {
+            Res::Def(DefKind::Variant, id) if id.is_local() => Some((id, false)),
+            Res::Def(DefKind::Ctor(CtorOf::Variant, _), id) if id.is_local() => Some((self.cx.tcx.parent(id), true)),
+            _ => None,
+        }
+    }
+
+    fn add(&mut self, variant: DefId, variant_use: VariantUse) {
+        self.uses.entry(variant).or_default().push(variant_use);
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for VariantUseCollector<'a, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_nested_body(&mut self, body_id: BodyId) {
+        let old_typeck_results = self.typeck_results.replace(self.cx.tcx.typeck_body(body_id));
+        self.visit_body(self.cx.tcx.hir().body(body_id));
+        self.typeck_results = old_typeck_results;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, args) = expr.kind
+            && let ExprKind::Path(ref qpath) = func.kind
+            && let Some((variant, true)) = self.variant(qpath, func.hir_id)
+        {
+            let values = args
+                .iter()
+                .enumerate()
+                .map(|(index, arg)| FieldValue::new(index, expr, arg, arg.span, None))
+                .collect();
+            self.add(variant, VariantUse::Construct(values));
+            // Don't visit the path of the constructor, which is fine in this position
+            for arg in args {
+                self.visit_expr(arg);
+            }
+            return;
+        }
+
+        if let ExprKind::Struct(qpath, expr_fields, _) = expr.kind
+            && let Some((variant, false)) = self.variant(qpath, expr.hir_id)
+            && let Some(typeck_results) = self.typeck_results
+        {
+            let values = expr_fields
+                .iter()
+                .filter_map(|field| {
+                    let index = typeck_results.opt_field_index(field.hir_id)?.as_usize();
+                    Some(if field.is_shorthand {
+                        FieldValue::new(index, expr, field.expr, field.span, Some(field.ident.name))
+                    } else {
+                        FieldValue::new(index, expr, field.expr, field.expr.span, None)
+                    })
+                })
+                .collect();
+            self.add(variant, VariantUse::Construct(values));
+        } else if let ExprKind::Path(ref qpath) = expr.kind
+            && let Some((variant, true)) = self.variant(qpath, expr.hir_id)
+        {
+            self.add(variant, VariantUse::CtorFn);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'tcx>) {
+        match pat.kind {
+            PatKind::TupleStruct(ref qpath, pats, dotdot) => {
+                if let Some((variant, true)) = self.variant(qpath, pat.hir_id) {
+                    let tcx = self.cx.tcx;
+                    let field_count = tcx.adt_def(tcx.parent(variant)).variant_with_id(variant).fields.len();
+                    let bound = pats
+                        .iter()
+                        .enumerate_and_adjust(field_count, dotdot)
+                        .filter(|(_, pat)| !matches!(pat.kind, PatKind::Wild))
+                        .map(|(ind, _)| ind)
+                        .collect();
+                    self.add(variant, VariantUse::Destructure(bound));
+                }
+            },
+            PatKind::Struct(ref qpath, pat_fields, _) => {
+                if let Some((variant, false)) = self.variant(qpath, pat.hir_id)
+                    && let Some(typeck_results) = self.typeck_results
+                {
+                    let bound = pat_fields
+                        .iter()
+                        .filter(|field| !matches!(field.pat.kind, PatKind::Wild))
+                        .filter_map(|field| typeck_results.opt_field_index(field.hir_id))
+                        .map(FieldIdx::as_usize)
+                        .collect();
+                    self.add(variant, VariantUse::Destructure(bound));
+                }
+            },
+            _ => {},
+        }
+        walk_pat(self, pat);
+    }
+}
@@ -67,4 +67,8 @@
         "clippy::wrong_pub_self_convention",
         "set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items",
     );
+    store.register_removed(
+        "clippy::floating_point_improvements",
+        "this lint has been split into `imprecise_flops` and `suboptimal_flops`",
+    );
 }
@@ -59,8 +59,14 @@
 
 use std::collections::BTreeMap;
 
-use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::{Lint, LintId};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::sync::Lrc;
+use rustc_errors::emitter::{stderr_destination, HumanEmitter};
+use rustc_errors::{ColorConfig, DiagCtxt};
+use rustc_lint::{CheckLintNameResult, Lint, LintId};
+use rustc_middle::ty::{RegisteredTools, TyCtxt};
+use rustc_session::Session;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
 
 #[cfg(feature = "internal")]
 pub mod deprecated_lints;
@@ -134,6 +140,7 @@
 mod excessive_nesting;
 mod exhaustive_items;
 mod exit;
+mod expensive_log_arguments;
 mod explicit_write;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
@@ -189,6 +196,7 @@
 mod let_if_seq;
 mod let_underscore;
 mod let_with_type_underscore;
+mod library_env_access;
 mod lifetimes;
 mod lines_filter_map_ok;
 mod literal_representation;
@@ -201,10 +209,12 @@
 mod manual_clamp;
 mod manual_float_methods;
 mod manual_hash_one;
+mod manual_ignore_case_cmp;
 mod manual_is_ascii_check;
 mod manual_let_else;
 mod manual_main_separator_str;
 mod manual_non_exhaustive;
+mod manual_path_join;
 mod manual_range_patterns;
 mod manual_rem_euclid;
 mod manual_retain;
@@ -224,6 +234,7 @@
 mod missing_assert_message;
 mod missing_asserts_for_indexing;
 mod missing_const_for_fn;
+mod missing_debug_impl;
 mod missing_doc;
 mod missing_enforced_import_rename;
 mod missing_fields_in_debug;
@@ -340,8 +351,10 @@
 mod transmute;
 mod tuple_array_conversions;
 mod types;
+mod unbuffered_io;
 mod unconditional_recursion;
 mod undocumented_unsafe_blocks;
+mod unfulfilled_clippy_expectations;
 mod unicode;
 mod uninhabited_references;
 mod uninit_vec;
@@ -376,7 +389,7 @@
 mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
-use clippy_config::{get_configuration_metadata, Conf};
+use clippy_config::{get_configuration_metadata, lookup_conf_file, Conf};
 
 /// Register all pre expansion lints
 ///
@@ -449,6 +462,22 @@ fn is_all(self) -> bool {
         matches!(self, Correctness | Suspicious | Style | Complexity | Perf)
     }
 
+    fn name(self) -> &'static str {
+        match self {
+            Cargo => "clippy::cargo",
+            Complexity => "clippy::complexity",
+            Correctness => "clippy::correctness",
+            Nursery => "clippy::nursery",
+            Pedantic => "clippy::pedantic",
+            Perf => "clippy::perf",
+            Restriction => "clippy::restriction",
+            Style => "clippy::style",
+            Suspicious => "clippy::suspicious",
+            #[cfg(feature = "internal")]
+            Internal => "clippy::internal",
+        }
+    }
+
     fn group(self, groups: &mut RegistrationGroups) -> &mut Vec<LintId> {
         match self {
             Cargo => &mut groups.cargo,
@@ -494,6 +523,49 @@ pub fn explain(name: &str) -> i32 {
     }
 }
 
+/// Prints every configuration option with its value, as read from the `clippy.toml` file used for
+/// the current directory, and the lints it affects. The output is itself a valid `clippy.toml`
+pub fn print_config() -> i32 {
+    rustc_span::create_default_session_globals_then(|| {
+        let source_map = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        let fallback_bundle =
+            rustc_errors::fallback_fluent_bundle(rustc_driver::DEFAULT_LOCALE_RESOURCES.to_vec(), false);
+        let emitter =
+            HumanEmitter::new(stderr_destination(ColorConfig::Auto), fallback_bundle).sm(Some(source_map.clone()));
+        let dcx = DiagCtxt::new(Box::new(emitter));
+
+        let path = lookup_conf_file();
+        let conf = Conf::read(&dcx, &source_map, &path);
+        if dcx.has_errors().is_some() {
+            return 1;
+        }
+
+        match path {
+            Ok((Some(path), _)) => println!("# configuration file: `{}`\n", path.display()),
+            _ => println!("# no configuration file found, using the default values\n"),
+        }
+        let values: FxHashMap<_, _> = conf
+            .values()
+            .into_iter()
+            .map(|(name, value)| (name.replace('_', "-"), value))
+            .collect();
+        for option in get_configuration_metadata() {
+            // Deprecated options are read into the option replacing them
+            if option.deprecation_reason.is_some() {
+                continue;
+            }
+            if !option.lints.is_empty() {
+                println!("# lints: {}", option.lints.join(", "));
+            }
+            match &values[&option.name] {
+                Some(value) => println!("{} = {value}", option.name),
+                None => println!("# {} is not set", option.name),
+            }
+        }
+        0
+    })
+}
+
 fn register_categories(store: &mut rustc_lint::LintStore) {
     let mut groups = RegistrationGroups::default();
 
@@ -509,6 +581,13 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
 
     store.register_lints(&lints);
     groups.register(store);
+
+    clippy_utils::diagnostics::set_lint_groups(
+        declared_lints::LINTS
+            .iter()
+            .map(|info| (info.lint.name, info.category.name()))
+            .collect(),
+    );
 }
 
 /// Register all lints and lint groups with the rustc lint store
@@ -521,16 +600,21 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         absolute_paths_max_segments,
         accept_comment_above_attributes,
         accept_comment_above_statement,
+        allow: _,
         allow_dbg_in_tests,
         allow_expect_in_tests,
+        allow_indexing_slicing_in_tests,
         allow_mixed_uninlined_format_args,
         allow_one_hash_in_raw_strings,
         allow_print_in_tests,
         allow_private_module_inception,
         allow_unwrap_in_tests,
         ref allowed_dotfiles,
+        ref allowed_env_modules,
+        ref allowed_exit_callers,
         ref allowed_idents_below_min_chars,
         ref allowed_scripts,
+        ref allowed_shadow_names,
         ref allowed_wildcard_imports,
         ref arithmetic_side_effects_allowed_binary,
         ref arithmetic_side_effects_allowed_unary,
@@ -539,7 +623,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         avoid_breaking_exported_api,
         ref await_holding_invalid_types,
         cargo_ignore_publish,
+        check_reciprocal_division,
         cognitive_complexity_threshold,
+        deny: _,
         ref disallowed_macros,
         ref disallowed_methods,
         ref disallowed_names,
@@ -550,11 +636,20 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         ref enforced_import_renames,
         enum_variant_name_threshold,
         enum_variant_size_threshold,
+        ref erased_error_types,
+        excessive_nesting_per_fn,
         excessive_nesting_threshold,
+        ref expensive_constructors,
+        ref expensive_log_argument_fns,
+        flops_performance_suggestions,
+        flops_performance_suggestions_in_tests,
         future_size_threshold,
+        ref hardcoded_path_prefixes,
         ref ignore_interior_mutability,
         large_error_threshold,
+        lint_groups: _,
         literal_representation_threshold,
+        ref logging_macros,
         matches_for_let_else,
         max_fn_params_bools,
         max_include_file_size,
@@ -562,15 +657,20 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         max_suggested_slice_pattern_length,
         max_trait_bounds,
         min_ident_chars_threshold,
+        ref missing_debug_impl_ignore_types,
         missing_docs_in_crate_items,
         ref msrv,
         pass_by_value_size_limit,
+        pattern_type_mismatch_mut_only,
         semicolon_inside_block_ignore_singleline,
         semicolon_outside_block_ignore_multiline,
+        ref significant_drop_types,
         single_char_binding_names_threshold,
         stack_size_threshold,
         ref standard_macro_braces,
         struct_field_name_threshold,
+        suggest_libm,
+        ref supported_pointer_widths,
         suppress_restriction_lint_in_const,
         too_large_for_stack,
         too_many_arguments_threshold,
@@ -582,6 +682,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         upper_case_acronyms_aggressive,
         vec_box_size_threshold,
         verbose_bit_mask_threshold,
+        warn: _,
         warn_on_all_wildcard_imports,
         check_private_items,
         pub_underscore_fields_behavior,
@@ -632,6 +733,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         });
     }
 
+    // The expectations have to be recorded before the other passes emit lints
+    store.register_early_pass(|| Box::new(unfulfilled_clippy_expectations::ClippyExpectations));
     store.register_late_pass(move |_| {
         Box::new(operators::arithmetic_side_effects::ArithmeticSideEffects::new(
             arithmetic_side_effects_allowed
@@ -697,7 +800,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             allowed_dotfiles.clone(),
         ))
     });
-    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv())));
+    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv(), significant_drop_types.clone())));
     store.register_early_pass(move || Box::new(manual_non_exhaustive::ManualNonExhaustiveStruct::new(msrv())));
     store.register_late_pass(move |_| Box::new(manual_non_exhaustive::ManualNonExhaustiveEnum::new(msrv())));
     store.register_late_pass(move |_| Box::new(manual_strip::ManualStrip::new(msrv())));
@@ -710,7 +813,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(use_self::UseSelf::new(msrv())));
     store.register_late_pass(move |_| Box::new(missing_const_for_fn::MissingConstForFn::new(msrv())));
     store.register_late_pass(move |_| Box::new(needless_question_mark::NeedlessQuestionMark));
-    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv())));
+    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv(), supported_pointer_widths.clone())));
     store.register_early_pass(move || Box::new(unnested_or_patterns::UnnestedOrPatterns::new(msrv())));
     store.register_late_pass(|_| Box::new(size_of_in_element_count::SizeOfInElementCount));
     store.register_late_pass(|_| Box::new(same_name_method::SameNameMethod));
@@ -720,7 +823,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             msrv(),
         ))
     });
-    store.register_late_pass(|_| Box::<shadow::Shadow>::default());
+    store.register_late_pass(move |_| Box::new(shadow::Shadow::new(allowed_shadow_names.clone())));
     store.register_late_pass(|_| Box::new(unit_types::UnitTypes));
     store.register_late_pass(move |_| Box::new(loops::Loops::new(msrv(), enforce_iter_loop_reborrow)));
     store.register_late_pass(|_| Box::<main_recursion::MainRecursion>::default());
@@ -734,7 +837,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(borrow_deref_ref::BorrowDerefRef));
     store.register_late_pass(|_| Box::<no_effect::NoEffect>::default());
     store.register_late_pass(|_| Box::new(temporary_assignment::TemporaryAssignment));
-    store.register_late_pass(move |_| Box::new(transmute::Transmute::new(msrv())));
+    store.register_late_pass(move |_| Box::new(transmute::Transmute::new(msrv(), supported_pointer_widths.clone())));
     store.register_late_pass(move |_| {
         Box::new(cognitive_complexity::CognitiveComplexity::new(
             cognitive_complexity_threshold,
@@ -755,7 +858,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(drop_forget_ref::DropForgetRef));
     store.register_late_pass(|_| Box::new(empty_enum::EmptyEnum));
     store.register_late_pass(|_| Box::new(invalid_upcast_comparisons::InvalidUpcastComparisons));
-    store.register_late_pass(|_| Box::<regex::Regex>::default());
+    store.register_late_pass(move |_| Box::new(regex::Regex::new(expensive_constructors.clone())));
     store.register_late_pass(move |_| Box::new(copies::CopyAndPaste::new(ignore_interior_mutability.clone())));
     store.register_late_pass(|_| Box::new(copy_iterator::CopyIterator));
     store.register_late_pass(|_| Box::new(format::UselessFormat));
@@ -769,6 +872,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             too_many_lines_threshold,
             large_error_threshold,
             avoid_breaking_exported_api,
+            erased_error_types.clone(),
         ))
     });
     store.register_late_pass(move |_| Box::new(doc::Documentation::new(doc_valid_idents, check_private_items)));
@@ -783,7 +887,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(unused_io_amount::UnusedIoAmount));
     store.register_late_pass(move |_| Box::new(large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold)));
     store.register_late_pass(|_| Box::new(explicit_write::ExplicitWrite));
-    store.register_late_pass(|_| Box::new(needless_pass_by_value::NeedlessPassByValue));
+    store.register_late_pass(move |_| {
+        Box::new(needless_pass_by_value::NeedlessPassByValue::new(avoid_breaking_exported_api))
+    });
     store.register_late_pass(move |tcx| {
         Box::new(pass_by_ref_or_value::PassByRefOrValue::new(
             trivial_copy_size_limit,
@@ -809,6 +915,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| {
         Box::new(indexing_slicing::IndexingSlicing::new(
             suppress_restriction_lint_in_const,
+            allow_indexing_slicing_in_tests,
         ))
     });
     store.register_late_pass(move |_| Box::new(non_copy_const::NonCopyConst::new(ignore_interior_mutability.clone())));
@@ -869,11 +976,19 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::<default::Default>::default());
     store.register_late_pass(move |_| Box::new(unused_self::UnusedSelf::new(avoid_breaking_exported_api)));
     store.register_late_pass(|_| Box::new(mutable_debug_assertion::DebugAssertWithMutCall));
-    store.register_late_pass(|_| Box::new(exit::Exit));
+    store.register_late_pass(move |_| Box::new(exit::Exit::new(allowed_exit_callers.clone())));
     store.register_late_pass(|_| Box::new(to_digit_is_some::ToDigitIsSome));
     store.register_late_pass(move |_| Box::new(large_stack_arrays::LargeStackArrays::new(array_size_threshold.into())));
     store.register_late_pass(move |_| Box::new(large_const_arrays::LargeConstArrays::new(array_size_threshold.into())));
-    store.register_late_pass(|_| Box::new(floating_point_arithmetic::FloatingPointArithmetic));
+    store.register_late_pass(move |_| {
+        Box::new(floating_point_arithmetic::FloatingPointArithmetic::new(
+            check_reciprocal_division,
+            flops_performance_suggestions,
+            flops_performance_suggestions_in_tests,
+            suggest_libm,
+            msrv(),
+        ))
+    });
     store.register_late_pass(|_| Box::new(as_conversions::AsConversions));
     store.register_late_pass(|_| Box::new(let_underscore::LetUnderscore));
     store.register_early_pass(|| Box::<single_component_path_imports::SingleComponentPathImports>::default());
@@ -908,7 +1023,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     });
     store.register_early_pass(move || Box::new(nonstandard_macro_braces::MacroBraces::new(standard_macro_braces)));
     store.register_late_pass(|_| Box::<macro_use::MacroUseImports>::default());
-    store.register_late_pass(|_| Box::new(pattern_type_mismatch::PatternTypeMismatch));
+    store.register_late_pass(move |_| {
+        Box::new(pattern_type_mismatch::PatternTypeMismatch::new(pattern_type_mismatch_mut_only))
+    });
     store.register_late_pass(|_| Box::new(unwrap_in_result::UnwrapInResult));
     store.register_late_pass(|_| Box::new(semicolon_if_nothing_returned::SemicolonIfNothingReturned));
     store.register_late_pass(|_| Box::new(async_yields_async::AsyncYieldsAsync));
@@ -1040,6 +1157,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_early_pass(move || {
         Box::new(excessive_nesting::ExcessiveNesting {
             excessive_nesting_threshold,
+            excessive_nesting_per_fn,
             nodes: rustc_ast::node_id::NodeSet::new(),
         })
     });
@@ -1119,7 +1237,22 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(incompatible_msrv::IncompatibleMsrv::new(msrv())));
     store.register_late_pass(|_| Box::new(to_string_trait_impl::ToStringTraitImpl));
     store.register_early_pass(|| Box::new(multiple_bound_locations::MultipleBoundLocations));
-    store.register_late_pass(|_| Box::new(assigning_clones::AssigningClones));
+    store.register_late_pass(|_| Box::<assigning_clones::AssigningClones>::default());
+    store.register_late_pass(|_| Box::new(manual_ignore_case_cmp::ManualIgnoreCaseCmp));
+    store.register_late_pass(move |_| Box::new(manual_path_join::ManualPathJoin::new(hardcoded_path_prefixes.clone())));
+    store.register_late_pass(|_| Box::new(unbuffered_io::UnbufferedIo));
+    store.register_late_pass(move |_| {
+        Box::new(missing_debug_impl::MissingDebugImpl::new(
+            missing_debug_impl_ignore_types.clone(),
+        ))
+    });
+    store.register_late_pass(move |_| Box::new(library_env_access::LibraryEnvAccess::new(allowed_env_modules.clone())));
+    store.register_late_pass(move |_| {
+        Box::new(expensive_log_arguments::ExpensiveLogArguments::new(
+            logging_macros.clone(),
+            expensive_log_argument_fns.clone(),
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -1176,6 +1309,71 @@ pub fn register_renamed(ls: &mut rustc_lint::LintStore) {
     }
 }
 
+/// Register the lint groups defined with the `lint-groups` configuration option, as
+/// `clippy::<name>` with any `-` in the name replaced by `_`, like lint names on the command line.
+/// A group can contain other groups from the option, so each one is registered once all the groups
+/// it contains are. Must be called after all the lints and lint groups are registered.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_lint_groups(sess: &Session, store: &mut rustc_lint::LintStore, conf: &'static Conf) {
+    let mut pending = Vec::new();
+    for (name, members) in &conf.lint_groups {
+        let name = format!("clippy::{}", name.replace('-', "_"));
+        if store.find_lints(&name).is_ok() {
+            sess.dcx().err(format!(
+                "lint group `{name}` from `clippy.toml` conflicts with an existing lint or lint group"
+            ));
+        } else {
+            let members: Vec<_> = members.iter().map(|member| (member, member.replace('-', "_"))).collect();
+            pending.push((name, members));
+        }
+    }
+
+    while let Some(index) = pending.iter().position(|(_, members)| {
+        members
+            .iter()
+            .all(|(_, member)| pending.iter().all(|(name, _)| name != member))
+    }) {
+        let (name, members) = pending.remove(index);
+        let mut lints = Vec::new();
+        for (member, normalized) in members {
+            if let Ok(ids) = store.find_lints(&normalized) {
+                lints.extend(ids);
+            // `find_lints` doesn't tell removed lints apart from unknown ones
+            } else if let CheckLintNameResult::Removed(reason) =
+                store.check_lint_name(&normalized, None, &RegisteredTools::default())
+            {
+                sess.dcx().err(format!(
+                    "lint `{member}` in the lint group `{name}` from `clippy.toml` has been removed: {reason}"
+                ));
+            } else {
+                sess.dcx().err(format!(
+                    "unknown lint `{member}` in the lint group `{name}` from `clippy.toml`"
+                ));
+            }
+        }
+        store.register_group(true, name.leak(), None, lints);
+    }
+
+    // The groups left are in a cycle, or contain a group that is
+    for (name, _) in pending {
+        sess.dcx().err(format!(
+            "lint group `{name}` from `clippy.toml` is in or depends on a cycle of lint groups"
+        ));
+    }
+}
+
+/// Lints the `#[clippy::expect(..)]` attributes expecting a lint that was not emitted. Must be
+/// called after the lints are checked.
+///
+/// Used in `./src/driver.rs`.
+pub fn lint_unfulfilled_expectations(tcx: TyCtxt<'_>) {
+    clippy_utils::diagnostics::lint_unfulfilled_expectations(
+        tcx,
+        unfulfilled_clippy_expectations::UNFULFILLED_CLIPPY_EXPECTATIONS,
+    );
+}
+
 // only exists to let the dogfood integration test works.
 // Don't run clippy as an executable directly
 #[allow(dead_code)]
@@ -0,0 +1,106 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{match_def_path, path_def_id};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::{CRATE_DEF_ID, LOCAL_CRATE};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::config::CrateType;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to the functions of `std::env` reading or changing the environment
+    /// variables or the command line arguments of the process in library crates.
+    ///
+    /// Modules can be allowed to access the environment with the `allowed-env-modules`
+    /// configuration.
+    ///
+    /// ### Why is this bad?
+    /// The environment is global state owned by the application. Libraries reading it behave
+    /// differently depending on how the program is run, are harder to test, and changing it
+    /// can race with other threads.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn connect() {
+    ///     let url = std::env::var("DATABASE_URL").unwrap();
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn connect(url: &str) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub LIBRARY_ENV_ACCESS,
+    restriction,
+    "accessing the process environment in a library crate"
+}
+
+/// The functions of `std::env` giving access to the environment of the process.
+const ENV_FNS: [&str; 8] = [
+    "args",
+    "args_os",
+    "remove_var",
+    "set_var",
+    "var",
+    "var_os",
+    "vars",
+    "vars_os",
+];
+
+pub struct LibraryEnvAccess {
+    allowed_modules: FxHashSet<String>,
+}
+
+impl LibraryEnvAccess {
+    pub fn new(allowed_modules: FxHashSet<String>) -> Self {
+        Self { allowed_modules }
+    }
+
+    fn is_allowed_module(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        let module = cx.tcx.parent_module(expr.hir_id).to_local_def_id();
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        let path = if module == CRATE_DEF_ID {
+            crate_name.to_string()
+        } else {
+            format!("{crate_name}::{}", cx.tcx.def_path_str(module))
+        };
+        self.allowed_modules.iter().any(|allowed| {
+            path.strip_prefix(allowed.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+}
+
+impl_lint_pass!(LibraryEnvAccess => [LIBRARY_ENV_ACCESS]);
+
+impl<'tcx> LateLintPass<'tcx> for LibraryEnvAccess {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, _) = expr.kind
+            && let Some(def_id) = path_def_id(cx, func)
+            && let Some(name) = ENV_FNS
+                .iter()
+                .find(|&&name| match_def_path(cx, def_id, &["std", "env", name]))
+            && !in_external_macro(cx.sess(), expr.span)
+            && !cx
+                .tcx
+                .crate_types()
+                .iter()
+                .any(|t| matches!(t, CrateType::Executable | CrateType::ProcMacro))
+            && !self.is_allowed_module(cx, expr)
+        {
+            span_lint_and_help(
+                cx,
+                LIBRARY_ENV_ACCESS,
+                expr.span,
+                &format!("call to `std::env::{name}` in a library crate"),
+                None,
+                "consider letting the caller pass this configuration explicitly",
+            );
+        }
+    }
+}
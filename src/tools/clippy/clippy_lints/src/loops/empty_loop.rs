@@ -2,17 +2,44 @@
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::{is_in_panic_handler, is_no_std_crate};
 
-use rustc_hir::{Block, Expr};
+use rustc_hir::def::DefKind;
+use rustc_hir::{Block, Expr, ExprKind};
 use rustc_lint::LateContext;
 
 pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, loop_block: &Block<'_>) {
     if loop_block.stmts.is_empty() && loop_block.expr.is_none() && !is_in_panic_handler(cx, expr) {
         let msg = "empty `loop {}` wastes CPU cycles";
         let help = if is_no_std_crate(cx) {
-            "you should either use `panic!()` or add a call pausing or sleeping the thread to the loop body"
+            if is_halt_fn_tail(cx, expr) {
+                return;
+            }
+            "you should either use `panic!()` or add a call pausing the core to the loop body, like \
+            `core::hint::spin_loop()` or a target-specific instruction such as `wfi` or `hlt`"
         } else {
-            "you should either use `panic!()` or add `std::thread::sleep(..);` to the loop body"
+            "you should either use `panic!()` or add `std::thread::sleep(..);`, `std::thread::park();` \
+            or `std::hint::spin_loop();` to the loop body"
         };
         span_lint_and_help(cx, EMPTY_LOOP, expr.span, msg, None, help);
     }
 }
+
+/// Checks if the loop is the whole body of a function returning `!`, which is a common way to
+/// halt in `no_std` code.
+fn is_halt_fn_tail(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let map = cx.tcx.hir();
+    let owner = map.enclosing_body_owner(expr.hir_id);
+    if matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+        && cx
+            .tcx
+            .fn_sig(owner)
+            .instantiate_identity()
+            .output()
+            .skip_binder()
+            .is_never()
+        && let ExprKind::Block(block, _) = map.body(map.body_owned_by(owner)).value.kind
+    {
+        block.stmts.is_empty() && block.expr.is_some_and(|tail| tail.hir_id == expr.hir_id)
+    } else {
+        false
+    }
+}
@@ -14,6 +14,7 @@
 mod needless_range_loop;
 mod never_loop;
 mod same_item_push;
+mod sequential_await_in_loop;
 mod single_element_loop;
 mod unused_enumerate_index;
 mod utils;
@@ -265,6 +266,9 @@
     ///   - [`x86_64::instructions::hlt`](https://docs.rs/x86_64/0.12.2/x86_64/instructions/fn.hlt.html)
     ///   - [`cortex_m::asm::wfi`](https://docs.rs/cortex-m/0.6.3/cortex_m/asm/fn.wfi.html)
     ///
+    /// An empty loop that is the whole body of a function returning `!` is a common way to halt
+    /// in `no_std` code, so it isn't linted there.
+    ///
     /// ### Example
     /// ```no_run
     /// loop {}
@@ -678,6 +682,42 @@
     "possibly unintended infinite loop"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops in async code whose only `.await` is on a future that is
+    /// created from the loop item and does not depend on anything the loop changes.
+    ///
+    /// ### Why is this bad?
+    /// Each iteration waits for the previous future to complete before creating the next one,
+    /// although the futures are independent of each other. Collecting them and awaiting them
+    /// concurrently (e.g. with `futures::future::join_all` or a buffered stream) is usually
+    /// much faster, especially for I/O bound work.
+    ///
+    /// ### Known problems
+    /// Interior mutability, shared state and side effects of the futures are not taken into
+    /// account, and running the futures concurrently may be undesirable (e.g. to limit the
+    /// load on a server).
+    ///
+    /// ### Example
+    /// ```ignore
+    /// for url in urls {
+    ///     let body = fetch(url).await;
+    ///     println!("{body}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// let bodies = futures::future::join_all(urls.into_iter().map(fetch)).await;
+    /// for body in bodies {
+    ///     println!("{body}");
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub SEQUENTIAL_AWAIT_IN_LOOP,
+    nursery,
+    "awaiting independent futures one after another in a `for` loop"
+}
+
 pub struct Loops {
     msrv: Msrv,
     enforce_iter_loop_reborrow: bool,
@@ -713,6 +753,7 @@ pub fn new(msrv: Msrv, enforce_iter_loop_reborrow: bool) -> Self {
     MANUAL_WHILE_LET_SOME,
     UNUSED_ENUMERATE_INDEX,
     INFINITE_LOOP,
+    SEQUENTIAL_AWAIT_IN_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -793,6 +834,7 @@ fn check_for_loop<'tcx>(
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
         unused_enumerate_index::check(cx, pat, arg, body);
+        sequential_await_in_loop::check(cx, pat, body);
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
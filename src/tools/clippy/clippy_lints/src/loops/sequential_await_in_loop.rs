@@ -0,0 +1,113 @@
+use super::SEQUENTIAL_AWAIT_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::path_to_local;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, Local, MatchSource, Node, Pat};
+use rustc_lint::LateContext;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, body: &'tcx Expr<'_>) {
+    let mut visitor = AwaitVisitor::default();
+    visitor.visit_expr(body);
+
+    if !visitor.early_exit
+        && let [future] = visitor.futures[..]
+        && !future.span.from_expansion()
+        && let Some(mutated) = mutated_variables(body, cx)
+        && is_independent(cx, pat, body, future, &mutated)
+    {
+        span_lint_and_help(
+            cx,
+            SEQUENTIAL_AWAIT_IN_LOOP,
+            future.span,
+            "independent futures are awaited one after another in this loop",
+            None,
+            "consider collecting the futures and awaiting them concurrently, \
+            e.g. with `futures::future::join_all` or a buffered stream",
+        );
+    }
+}
+
+/// Collects the awaited futures of a loop body and whether the loop can be left early.
+#[derive(Default)]
+struct AwaitVisitor<'tcx> {
+    futures: Vec<&'tcx Expr<'tcx>>,
+    early_exit: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for AwaitVisitor<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            // `match IntoFuture::into_future(<future>) { .. }`, the arms contain the polling loop
+            ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) => {
+                if let ExprKind::Call(_, [future]) = scrutinee.kind {
+                    self.futures.push(future);
+                    self.visit_expr(future);
+                } else {
+                    self.early_exit = true;
+                }
+                return;
+            },
+            ExprKind::Break(..) | ExprKind::Ret(_) | ExprKind::Match(_, _, MatchSource::TryDesugar(_)) => {
+                self.early_exit = true;
+            },
+            // `.await`s and early exits of closures and `async` blocks don't belong to the loop body
+            ExprKind::Closure(_) => return,
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+}
+
+/// Checks whether `future` only depends on the loop item and on locals which are not mutated in
+/// the loop body, i.e. whether the futures of all iterations could be created upfront.
+fn is_independent<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &Pat<'_>,
+    body: &Expr<'_>,
+    future: &'tcx Expr<'tcx>,
+    mutated: &FxHashSet<HirId>,
+) -> bool {
+    let mut item_bindings = FxHashSet::default();
+    pat.each_binding(|_, id, _, _| {
+        item_bindings.insert(id);
+    });
+
+    let mut uses_item = false;
+    let mut seen = FxHashSet::default();
+    let mut pending = vec![future];
+    while let Some(e) = pending.pop() {
+        let depends_on_loop_state = for_each_expr(e, |e| {
+            if let Some(id) = path_to_local(e)
+                && seen.insert(id)
+            {
+                if item_bindings.contains(&id) {
+                    uses_item = true;
+                } else if mutated.contains(&id) {
+                    return ControlFlow::Break(());
+                } else if body.span.contains(cx.tcx.hir().span(id)) {
+                    // locals of the loop body have to be initialized from independent values as well
+                    let Some(Node::Local(Local { init: Some(init), .. })) = cx
+                        .tcx
+                        .hir()
+                        .parent_iter(id)
+                        .map(|(_, node)| node)
+                        .find(|node| !matches!(node, Node::Pat(_)))
+                    else {
+                        return ControlFlow::Break(());
+                    };
+                    pending.push(init);
+                }
+            }
+            ControlFlow::Continue(())
+        })
+        .is_some();
+        if depends_on_loop_state {
+            return false;
+        }
+    }
+    uses_item
+}
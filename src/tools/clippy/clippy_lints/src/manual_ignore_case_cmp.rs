@@ -0,0 +1,209 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for case-insensitive comparisons written by converting both sides, or one side
+    /// and a literal, to the same case, e.g. `a.to_ascii_lowercase() == b.to_ascii_lowercase()`.
+    ///
+    /// ### Why is this bad?
+    /// The conversions allocate a new `String` for every comparison. `eq_ignore_ascii_case`
+    /// compares in place without allocating.
+    ///
+    /// ### Known problems
+    /// `to_lowercase` and `to_uppercase` perform Unicode case conversion, while
+    /// `eq_ignore_ascii_case` only ignores the case of ASCII letters. The lint only suggests
+    /// the replacement for these methods if the other side is an ASCII literal.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn compare(a: &str, b: &str) -> bool {
+    ///     a.to_ascii_lowercase() == b.to_ascii_lowercase() || a.to_ascii_lowercase() == "abc"
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn compare(a: &str, b: &str) -> bool {
+    ///     a.eq_ignore_ascii_case(b) || a.eq_ignore_ascii_case("abc")
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub MANUAL_IGNORE_CASE_CMP,
+    perf,
+    "manual case-insensitive comparison that could use `eq_ignore_ascii_case`"
+}
+declare_lint_pass!(ManualIgnoreCaseCmp => [MANUAL_IGNORE_CASE_CMP]);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Upper,
+}
+
+struct CaseConversion<'tcx> {
+    recv: &'tcx Expr<'tcx>,
+    case: Case,
+    method_name: &'tcx str,
+    /// Whether the conversion only affects ASCII letters
+    ascii: bool,
+}
+
+/// Matches `recv.to_lowercase()` and friends on strings, and the `to_ascii_*` methods on `char`
+/// and `u8`.
+fn case_conversion<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<CaseConversion<'tcx>> {
+    let ExprKind::MethodCall(path, recv, [], _) = expr.kind else {
+        return None;
+    };
+    let method_name = path.ident.name.as_str();
+    let (case, ascii) = match method_name {
+        "to_lowercase" => (Case::Lower, false),
+        "to_uppercase" => (Case::Upper, false),
+        "to_ascii_lowercase" => (Case::Lower, true),
+        "to_ascii_uppercase" => (Case::Upper, true),
+        _ => return None,
+    };
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    let supported = match recv_ty.kind() {
+        ty::Str => true,
+        ty::Char | ty::Uint(ty::UintTy::U8) => ascii,
+        _ => is_type_lang_item(cx, recv_ty, LangItem::String),
+    };
+    supported.then_some(CaseConversion {
+        recv,
+        case,
+        method_name,
+        ascii,
+    })
+}
+
+/// Checks if `expr` is a string, char or byte literal that only contains ASCII characters and
+/// that is unchanged by the conversion to `case`.
+fn is_ascii_lit_in_case(expr: &Expr<'_>, case: Case) -> bool {
+    let ExprKind::Lit(lit) = expr.kind else {
+        return false;
+    };
+    let in_case = |c: char| {
+        c.is_ascii()
+            && match case {
+                Case::Lower => !c.is_ascii_uppercase(),
+                Case::Upper => !c.is_ascii_lowercase(),
+            }
+    };
+    match lit.node {
+        LitKind::Str(s, _) => s.as_str().chars().all(in_case),
+        LitKind::Char(c) => in_case(c),
+        LitKind::Byte(b) => in_case(char::from(b)),
+        _ => false,
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualIgnoreCaseCmp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let ExprKind::Binary(op, left, right) = expr.kind else {
+            return;
+        };
+        let negate = match op.node {
+            BinOpKind::Eq => false,
+            BinOpKind::Ne => true,
+            _ => return,
+        };
+
+        match (case_conversion(cx, left), case_conversion(cx, right)) {
+            (Some(l), Some(r)) if l.case == r.case => {
+                let ascii = l.ascii && r.ascii;
+                let note_method = if l.ascii { r.method_name } else { l.method_name };
+                emit(
+                    cx,
+                    expr.span,
+                    negate,
+                    l.recv,
+                    r.recv,
+                    ascii.then_some(true),
+                    note_method,
+                );
+            },
+            (Some(conv), None) if is_ascii_lit_in_case(right, conv.case) => {
+                emit(
+                    cx,
+                    expr.span,
+                    negate,
+                    conv.recv,
+                    right,
+                    Some(conv.ascii),
+                    conv.method_name,
+                );
+            },
+            (None, Some(conv)) if is_ascii_lit_in_case(left, conv.case) => {
+                emit(
+                    cx,
+                    expr.span,
+                    negate,
+                    conv.recv,
+                    left,
+                    Some(conv.ascii),
+                    conv.method_name,
+                );
+            },
+            _ => {},
+        }
+    }
+}
+
+/// `exact` is `None` if no suggestion should be made, `Some(false)` if the suggestion may not
+/// be equivalent because of Unicode case conversion.
+fn emit(
+    cx: &LateContext<'_>,
+    span: Span,
+    negate: bool,
+    recv: &Expr<'_>,
+    other: &Expr<'_>,
+    exact: Option<bool>,
+    method_name: &str,
+) {
+    span_lint_and_then(
+        cx,
+        MANUAL_IGNORE_CASE_CMP,
+        span,
+        "manual case-insensitive comparison",
+        |diag| {
+            if exact != Some(true) {
+                diag.note(format!("`{method_name}` converts the case of all Unicode characters"));
+                diag.note("`eq_ignore_ascii_case` only ignores the case of ASCII letters");
+            }
+            let Some(exact) = exact else {
+                diag.help("consider using `eq_ignore_ascii_case` if both sides are known to be ASCII");
+                return;
+            };
+            let mut app = if exact {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let recv = Sugg::hir_with_context(cx, recv, span.ctxt(), "..", &mut app).maybe_par();
+            let other_sugg = Sugg::hir_with_context(cx, other, span.ctxt(), "..", &mut app);
+            // `eq_ignore_ascii_case` takes its argument by reference
+            let other_sugg = match cx.typeck_results().expr_ty(other).kind() {
+                ty::Ref(..) => other_sugg,
+                _ => other_sugg.addr(),
+            };
+            let neg = if negate { "!" } else { "" };
+            diag.span_suggestion(
+                span,
+                "consider using `eq_ignore_ascii_case` instead",
+                format!("{neg}{recv}.eq_ignore_ascii_case({other_sugg})"),
+                app,
+            );
+        },
+    );
+}
@@ -2,11 +2,11 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::macros::root_macro_call;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{higher, in_constant};
+use clippy_utils::{eq_expr_value, higher, in_constant};
 use rustc_ast::ast::RangeLimits;
 use rustc_ast::LitKind::{Byte, Char};
 use rustc_errors::Applicability;
-use rustc_hir::{BorrowKind, Expr, ExprKind, PatKind, RangeEnd};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, PatKind, RangeEnd};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
 use rustc_span::def_id::DefId;
@@ -16,7 +16,8 @@
     /// ### What it does
     /// Suggests to use dedicated built-in methods,
     /// `is_ascii_(lowercase|uppercase|digit|hexdigit)` for checking on corresponding
-    /// ascii range
+    /// ascii range, written either as a `matches!` pattern, a range `contains` call or
+    /// a pair of comparisons
     ///
     /// ### Why is this bad?
     /// Using the built-in functions is more readable and makes it
@@ -34,6 +35,9 @@
     ///     ('0'..='9').contains(&'0');
     ///     ('a'..='z').contains(&'a');
     ///     ('A'..='Z').contains(&'A');
+    ///
+    ///     let c = 'x';
+    ///     c >= '0' && c <= '9';
     /// }
     /// ```
     /// Use instead:
@@ -48,6 +52,9 @@
     ///     '0'.is_ascii_digit();
     ///     'a'.is_ascii_lowercase();
     ///     'A'.is_ascii_uppercase();
+    ///
+    ///     let c = 'x';
+    ///     c.is_ascii_digit();
     /// }
     /// ```
     #[clippy::version = "1.67.0"]
@@ -118,12 +125,31 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             } else {
                 check_is_ascii(cx, expr.span, arg, &range);
             }
+        } else if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+            && op.node == BinOpKind::And
+            && let Some(lhs) = check_bound(lhs)
+            && let Some(rhs) = check_bound(rhs)
+            && lhs.is_lower != rhs.is_lower
+            && let (lower, upper) = if lhs.is_lower { (lhs, rhs) } else { (rhs, lhs) }
+            && eq_expr_value(cx, lower.value, upper.value)
+        {
+            let range = check_range(lower.bound, upper.bound);
+            check_is_ascii(cx, expr.span, lower.value, &range);
         }
     }
 
     extract_msrv_attr!(LateContext);
 }
 
+/// Checks if `start..=end` is one of the ranges that `MANUAL_IS_ASCII_CHECK` suggests a
+/// dedicated method for, so other lints can leave it alone.
+pub(crate) fn is_ascii_class_range(start: &Expr<'_>, end: &Expr<'_>) -> bool {
+    matches!(
+        check_range(start, end),
+        CharRange::LowerChar | CharRange::UpperChar | CharRange::Digit
+    )
+}
+
 fn check_is_ascii(cx: &LateContext<'_>, span: Span, recv: &Expr<'_>, range: &CharRange) {
     if let Some(sugg) = match range {
         CharRange::UpperChar => Some("is_ascii_uppercase"),
@@ -149,6 +175,32 @@ fn check_is_ascii(cx: &LateContext<'_>, span: Span, recv: &Expr<'_>, range: &Cha
     }
 }
 
+/// One side of a `c >= 'a' && c <= 'z'` check.
+struct Bound<'a> {
+    /// The value being checked, e.g. `c`
+    value: &'a Expr<'a>,
+    /// The literal it is compared against, e.g. `'a'`
+    bound: &'a Expr<'a>,
+    is_lower: bool,
+}
+
+/// Matches an inclusive comparison of an expression against a char or byte literal, in either
+/// operand order.
+fn check_bound<'a>(expr: &'a Expr<'a>) -> Option<Bound<'a>> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    let is_lit = |e: &Expr<'_>| matches!(e.kind, ExprKind::Lit(lit) if matches!(lit.node, Char(_) | Byte(_)));
+    let (value, bound, is_lower) = match op.node {
+        BinOpKind::Ge if is_lit(rhs) => (lhs, rhs, true),
+        BinOpKind::Le if is_lit(rhs) => (lhs, rhs, false),
+        BinOpKind::Le if is_lit(lhs) => (rhs, lhs, true),
+        BinOpKind::Ge if is_lit(lhs) => (rhs, lhs, false),
+        _ => return None,
+    };
+    Some(Bound { value, bound, is_lower })
+}
+
 fn check_pat(pat_kind: &PatKind<'_>) -> CharRange {
     match pat_kind {
         PatKind::Or(pats) => {
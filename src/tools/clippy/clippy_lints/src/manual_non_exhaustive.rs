@@ -7,11 +7,12 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
-use rustc_hir::{self as hir, Expr, ExprKind, QPath};
+use rustc_hir::{self as hir, Expr, ExprKind, PatKind, QPath};
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
 use rustc_session::impl_lint_pass;
 use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::{sym, Span};
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -21,6 +22,9 @@
     /// Using the #[non_exhaustive] attribute expresses better the intent
     /// and allows possible optimizations when applied to enums.
     ///
+    /// If the hidden variant of an enum is matched on in the crate, the suggestion
+    /// removes those match arms along with the variant.
+    ///
     /// ### Example
     /// ```no_run
     /// struct S {
@@ -79,7 +83,11 @@ pub fn new(msrv: Msrv) -> Self {
 pub struct ManualNonExhaustiveEnum {
     msrv: Msrv,
     constructed_enum_variants: FxHashSet<(DefId, DefId)>,
-    potential_enums: Vec<(LocalDefId, LocalDefId, Span, Span)>,
+    /// Match arms on unit variants, along with the span removing the arm
+    matched_enum_variants: Vec<(DefId, DefId, Span)>,
+    /// The enum, its hidden variant, the enum's span, the variant's span and the span removing the
+    /// variant with its attributes
+    potential_enums: Vec<(LocalDefId, LocalDefId, Span, Span, Span)>,
 }
 
 impl ManualNonExhaustiveEnum {
@@ -88,6 +96,7 @@ pub fn new(msrv: Msrv) -> Self {
         Self {
             msrv,
             constructed_enum_variants: FxHashSet::default(),
+            matched_enum_variants: Vec::new(),
             potential_enums: Vec::new(),
         }
     }
@@ -155,16 +164,25 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         if let hir::ItemKind::Enum(def, _) = &item.kind
             && def.variants.len() > 1
         {
-            let mut iter = def.variants.iter().filter_map(|v| {
-                (matches!(v.data, hir::VariantData::Unit(_, _))
+            let mut iter = def.variants.iter().enumerate().filter(|(_, v)| {
+                matches!(v.data, hir::VariantData::Unit(_, _))
                     && is_doc_hidden(cx.tcx.hir().attrs(v.hir_id))
-                    && !attr::contains_name(cx.tcx.hir().attrs(item.hir_id()), sym::non_exhaustive))
-                .then_some((v.def_id, v.span))
+                    && !attr::contains_name(cx.tcx.hir().attrs(item.hir_id()), sym::non_exhaustive)
             });
-            if let Some((id, span)) = iter.next()
+            if let Some((i, v)) = iter.next()
                 && iter.next().is_none()
             {
-                self.potential_enums.push((item.owner_id.def_id, id, item.span, span));
+                // remove the separator to the previous variant, or to the next one if it's first
+                let removal_span = match i.checked_sub(1) {
+                    Some(prev) => def.variants[prev].span.shrink_to_hi().to(v.span),
+                    None => {
+                        let attrs = cx.tcx.hir().attrs(v.hir_id);
+                        let lo = attrs.first().map_or(v.span, |attr| attr.span);
+                        lo.until(def.variants[i + 1].span)
+                    },
+                };
+                self.potential_enums
+                    .push((item.owner_id.def_id, v.def_id, item.span, v.span, removal_span));
             }
         }
     }
@@ -177,12 +195,30 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
             let enum_id = cx.tcx.parent(variant_id);
 
             self.constructed_enum_variants.insert((enum_id, variant_id));
+        } else if let ExprKind::Match(_, arms, _) = e.kind {
+            for (i, arm) in arms.iter().enumerate() {
+                if !arm.span.from_expansion()
+                    && let PatKind::Path(QPath::Resolved(None, p)) = arm.pat.kind
+                    && let Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Const), id) = p.res
+                {
+                    let variant_id = cx.tcx.parent(id);
+                    let enum_id = cx.tcx.parent(variant_id);
+                    // remove the arm along with the separator to the next arm, or to the previous one if it's last
+                    let span = match (arms.get(i + 1), i.checked_sub(1)) {
+                        (Some(next), _) => arm.span.until(next.span),
+                        (None, Some(prev)) => arms[prev].span.shrink_to_hi().to(arm.span),
+                        (None, None) => continue,
+                    };
+
+                    self.matched_enum_variants.push((enum_id, variant_id, span));
+                }
+            }
         }
     }
 
     fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
-        for &(enum_id, _, enum_span, variant_span) in
-            self.potential_enums.iter().filter(|&&(enum_id, variant_id, _, _)| {
+        for &(enum_id, variant_id, enum_span, variant_span, removal_span) in
+            self.potential_enums.iter().filter(|&&(enum_id, variant_id, _, _, _)| {
                 !self
                     .constructed_enum_variants
                     .contains(&(enum_id.to_def_id(), variant_id.to_def_id()))
@@ -205,7 +241,21 @@ fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
                             Applicability::Unspecified,
                         );
                     }
-                    diag.span_help(variant_span, "remove this variant");
+                    let mut arm_removals = self
+                        .matched_enum_variants
+                        .iter()
+                        .filter(|&&(e, v, _)| e == enum_id.to_def_id() && v == variant_id.to_def_id())
+                        .map(|&(_, _, span)| (span, String::new()))
+                        .peekable();
+                    if arm_removals.peek().is_some() {
+                        diag.multipart_suggestion(
+                            "remove this variant and the match arms on it",
+                            iter::once((removal_span, String::new())).chain(arm_removals).collect(),
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        diag.span_help(variant_span, "remove this variant");
+                    }
                 },
             );
         }
@@ -0,0 +1,234 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::macros::{find_format_args, root_macro_call_first_node};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{expr_or_init, path_def_id, peel_hir_expr_refs};
+use rustc_ast::{FormatArgsPiece, LitKind};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, ClauseKind};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for paths built by string formatting or concatenation with a `/` separator,
+    /// e.g. `format!("{dir}/{name}")` or `dir + "/" + name`, that are then used as a path,
+    /// like in `File::open` or `Path::new`.
+    ///
+    /// ### Why is this bad?
+    /// `Path::join` and `PathBuf::push` use the separator of the target platform and
+    /// handle details like trailing separators or absolute components.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// fn open(dir: &str, name: &str) -> std::io::Result<File> {
+    ///     File::open(format!("{dir}/{name}"))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::path::Path;
+    /// fn open(dir: &str, name: &str) -> std::io::Result<File> {
+    ///     File::open(Path::new(dir).join(name))
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub MANUAL_PATH_JOIN,
+    pedantic,
+    "building a path by string formatting or concatenation"
+}
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for string literals used as paths, e.g. in `File::open` or `Path::new`, that
+    /// contain a `\` separator or start with a platform-specific absolute prefix like `/tmp`
+    /// or `C:\`.
+    ///
+    /// The prefixes can be configured with the `hardcoded-path-prefixes` configuration.
+    ///
+    /// ### Why is this bad?
+    /// These paths only work on some platforms. `std::env::temp_dir` returns the temporary
+    /// directory of the current platform, and `Path::join` uses the right separator.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// let _ = File::create("/tmp/output.log");
+    /// let _ = File::open("data\\input.txt");
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::path::Path;
+    /// let _ = File::create(std::env::temp_dir().join("output.log"));
+    /// let _ = File::open(Path::new("data").join("input.txt"));
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub HARDCODED_PATHS,
+    restriction,
+    "string literals used as paths that only work on some platforms"
+}
+
+pub struct ManualPathJoin {
+    hardcoded_path_prefixes: Vec<String>,
+    /// Concatenations that were already linted, as a binding can be used as a path more than once
+    linted_concats: FxHashSet<Span>,
+}
+
+impl ManualPathJoin {
+    pub fn new(hardcoded_path_prefixes: Vec<String>) -> Self {
+        Self {
+            hardcoded_path_prefixes,
+            linted_concats: FxHashSet::default(),
+        }
+    }
+
+    fn check_path_literal(&self, cx: &LateContext<'_>, arg: &Expr<'_>) {
+        if let ExprKind::Lit(lit) = expr_or_init(cx, peel_hir_expr_refs(arg).0).kind
+            && !lit.span.from_expansion()
+            && let LitKind::Str(s, _) = lit.node
+        {
+            let s = s.as_str();
+            if let Some(prefix) = self
+                .hardcoded_path_prefixes
+                .iter()
+                .find(|prefix| s.starts_with(prefix.as_str()))
+            {
+                let help = if prefix.starts_with("/tmp") {
+                    "consider starting from `std::env::temp_dir()` and using `Path::join`"
+                } else {
+                    "consider starting from a configurable directory and using `Path::join`"
+                };
+                span_lint_and_help(
+                    cx,
+                    HARDCODED_PATHS,
+                    lit.span,
+                    &format!("hardcoded path starting with `{prefix}`"),
+                    None,
+                    help,
+                );
+            } else if s.contains('\\') {
+                span_lint_and_help(
+                    cx,
+                    HARDCODED_PATHS,
+                    lit.span,
+                    "hardcoded path with a `\\` separator",
+                    None,
+                    "use `Path::join` or `std::path::MAIN_SEPARATOR` instead",
+                );
+            }
+        }
+    }
+}
+
+impl_lint_pass!(ManualPathJoin => [MANUAL_PATH_JOIN, HARDCODED_PATHS]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualPathJoin {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let (fn_id, args, self_offset) = match expr.kind {
+            ExprKind::Call(func, args) => match path_def_id(cx, func) {
+                Some(id) => (id, args, 0),
+                None => return,
+            },
+            ExprKind::MethodCall(_, _, args, _) => match cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                Some(id) => (id, args, 1),
+                None => return,
+            },
+            _ => return,
+        };
+        // `Path::new(s)`, `PathBuf::from(s)` and similar conversions
+        let converts_to_path = matches!(expr.kind, ExprKind::Call(_, [_]))
+            && matches!(
+                cx.typeck_results().expr_ty(expr).peel_refs().kind(),
+                ty::Adt(adt, _) if matches!(cx.tcx.get_diagnostic_name(adt.did()), Some(sym::Path | sym::PathBuf))
+            );
+
+        for (i, arg) in args.iter().enumerate() {
+            if !(converts_to_path || is_as_ref_path_param(cx, fn_id, i + self_offset)) {
+                continue;
+            }
+            self.check_path_literal(cx, arg);
+            if let Some(concat_span) = manual_path_concat(cx, arg)
+                && self.linted_concats.insert(concat_span)
+            {
+                span_lint_and_then(
+                    cx,
+                    MANUAL_PATH_JOIN,
+                    concat_span,
+                    "building a path by concatenating strings",
+                    |diag| {
+                        if !concat_span.contains(arg.span) {
+                            diag.span_note(arg.span, "the string is used as a path here");
+                        }
+                        diag.help("use `Path::join` or `PathBuf::push` instead");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the parameter at `index` is generic over `P: AsRef<Path>`, as most functions in
+/// `std::fs` are.
+fn is_as_ref_path_param(cx: &LateContext<'_>, fn_id: DefId, index: usize) -> bool {
+    let sig = cx.tcx.fn_sig(fn_id).instantiate_identity().skip_binder();
+    let Some(&param_ty) = sig.inputs().get(index) else {
+        return false;
+    };
+    if !matches!(param_ty.kind(), ty::Param(_)) {
+        return false;
+    }
+    cx.tcx.param_env(fn_id).caller_bounds().iter().any(|clause| {
+        if let ClauseKind::Trait(pred) = clause.kind().skip_binder()
+            && pred.self_ty() == param_ty
+            && cx.tcx.is_diagnostic_item(sym::AsRef, pred.def_id())
+            && let Some(target) = pred.trait_ref.args.get(1).and_then(|arg| arg.as_type())
+        {
+            is_type_diagnostic_item(cx, target, sym::Path)
+        } else {
+            false
+        }
+    })
+}
+
+/// Returns the span of the `format!` call or string concatenation that `arg` (or the binding it
+/// refers to) was built with, if it joins two parts with a `/`.
+fn manual_path_concat(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<Span> {
+    let expr = expr_or_init(cx, peel_hir_expr_refs(arg).0);
+
+    if let Some(macro_call) = root_macro_call_first_node(cx, expr) {
+        if cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+            && let Some(format_args) = find_format_args(cx, expr, macro_call.expn)
+            && format_args.template.windows(2).any(|pieces| {
+                matches!(
+                    pieces,
+                    [FormatArgsPiece::Placeholder(_), FormatArgsPiece::Literal(lit)] if lit.as_str().starts_with('/')
+                )
+            })
+        {
+            return Some(macro_call.span);
+        }
+        return None;
+    }
+
+    // `a + "/" + b` is parsed as `(a + "/") + b`, so every right operand has something to its left
+    let mut current = expr;
+    while let ExprKind::Binary(op, lhs, rhs) = current.kind
+        && op.node == BinOpKind::Add
+    {
+        if let ExprKind::Lit(lit) = rhs.kind
+            && let LitKind::Str(s, _) = lit.node
+            && s.as_str().starts_with('/')
+        {
+            return Some(expr.span);
+        }
+        current = lhs;
+    }
+    None
+}
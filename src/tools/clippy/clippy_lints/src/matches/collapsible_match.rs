@@ -1,15 +1,15 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::higher::IfLetOrMatch;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_block_with_applicability, snippet_with_applicability};
 use clippy_utils::visitors::is_local_used;
 use clippy_utils::{
     is_res_lang_ctor, is_unit_expr, path_to_local, peel_blocks_with_stmt, peel_ref_operators, SpanlessEq,
 };
-use rustc_errors::MultiSpan;
+use rustc_errors::{Applicability, MultiSpan};
 use rustc_hir::LangItem::OptionNone;
-use rustc_hir::{Arm, Expr, HirId, Pat, PatKind};
+use rustc_hir::{Arm, BindingAnnotation, Expr, HirId, Node, Pat, PatKind};
 use rustc_lint::LateContext;
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 
 use super::COLLAPSIBLE_MATCH;
 
@@ -40,15 +40,15 @@ fn check_arm<'tcx>(
 ) {
     let inner_expr = peel_blocks_with_stmt(outer_then_body);
     if let Some(inner) = IfLetOrMatch::parse(cx, inner_expr)
-        && let Some((inner_scrutinee, inner_then_pat, inner_else_body)) = match inner {
-            IfLetOrMatch::IfLet(scrutinee, pat, _, els, _) => Some((scrutinee, pat, els)),
+        && let Some((inner_scrutinee, inner_then_pat, inner_then_body, inner_else_body)) = match inner {
+            IfLetOrMatch::IfLet(scrutinee, pat, then, els, _) => Some((scrutinee, pat, then, els)),
             IfLetOrMatch::Match(scrutinee, arms, ..) => if arms.len() == 2 && arms.iter().all(|a| a.guard.is_none())
                 // if there are more than two arms, collapsing would be non-trivial
                 // one of the arms must be "wild-like"
                 && let Some(wild_idx) = arms.iter().rposition(|a| arm_is_wild_like(cx, a))
             {
                 let (then, els) = (&arms[1 - wild_idx], &arms[wild_idx]);
-                Some((scrutinee, then.pat, Some(els.body)))
+                Some((scrutinee, then.pat, then.body, Some(els.body)))
             } else {
                 None
             },
@@ -90,20 +90,60 @@ fn check_arm<'tcx>(
             },
             if outer_is_match { "match" } else { "if let" },
         );
-        // collapsing patterns need an explicit field name in struct pattern matching
-        // ex: Struct {x: Some(1)}
-        let replace_msg = if is_innermost_parent_pat_struct {
-            format!(", prefixed by {}:", snippet(cx, binding_span, "their field name"))
+        // an inner `if let` brings its own block, an inner `match` arm only the expression
+        let body_span = if matches!(inner, IfLetOrMatch::IfLet(..)) {
+            outer_then_body.span
         } else {
-            String::new()
+            inner_expr.span
         };
+        if body_span.from_expansion() || binding_span.from_expansion() {
+            // collapsing patterns need an explicit field name in struct pattern matching
+            // ex: Struct {x: Some(1)}
+            let replace_msg = if is_innermost_parent_pat_struct {
+                format!(", prefixed by {}:", snippet(cx, binding_span, "their field name"))
+            } else {
+                String::new()
+            };
+            span_lint_and_then(cx, COLLAPSIBLE_MATCH, inner_expr.span, &msg, |diag| {
+                let mut help_span = MultiSpan::from_spans(vec![binding_span, inner_then_pat.span]);
+                help_span.push_span_label(binding_span, "replace this binding");
+                help_span.push_span_label(inner_then_pat.span, format!("with this pattern{replace_msg}"));
+                diag.span_help(
+                    help_span,
+                    "the outer pattern can be modified to include the inner pattern",
+                );
+            });
+            return;
+        }
+
         span_lint_and_then(cx, COLLAPSIBLE_MATCH, inner_expr.span, &msg, |diag| {
-            let mut help_span = MultiSpan::from_spans(vec![binding_span, inner_then_pat.span]);
-            help_span.push_span_label(binding_span, "replace this binding");
-            help_span.push_span_label(inner_then_pat.span, format!("with this pattern{replace_msg}"));
-            diag.span_help(
-                help_span,
+            // `ref`/`mut` bindings and dereferenced scrutinees change the binding mode of the inner pattern
+            let is_plain_binding = matches!(
+                cx.tcx.hir_node(binding_id),
+                Node::Pat(Pat {
+                    kind: PatKind::Binding(BindingAnnotation::NONE, ..),
+                    ..
+                })
+            );
+            let is_plain_scrutinee = peel_ref_operators(cx, inner_scrutinee).hir_id == inner_scrutinee.hir_id;
+            let mut app = if is_plain_binding && is_plain_scrutinee {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+
+            let inner_pat = snippet_with_applicability(cx, inner_then_pat.span, "..", &mut app);
+            // collapsing patterns need an explicit field name for shorthand struct fields
+            let pat = match shorthand_field_name(outer_pat, binding_id) {
+                Some(name) => format!("{name}: {inner_pat}"),
+                None => inner_pat.into_owned(),
+            };
+            let body = snippet_block_with_applicability(cx, inner_then_body.span, "..", Some(body_span), &mut app);
+
+            diag.multipart_suggestion(
                 "the outer pattern can be modified to include the inner pattern",
+                vec![(binding_span, pat), (body_span, body.into_owned())],
+                app,
             );
         });
     }
@@ -143,6 +183,23 @@ fn find_pat_binding_and_is_innermost_parent_pat_struct(pat: &Pat<'_>, hir_id: Hi
     (span, is_innermost_parent_pat_struct)
 }
 
+/// Returns the field name if the binding is a shorthand field of a struct pattern, e.g. `x` in
+/// `Struct { x }`
+fn shorthand_field_name(pat: &Pat<'_>, hir_id: HirId) -> Option<Symbol> {
+    let mut name = None;
+    pat.walk(|p| {
+        if let PatKind::Struct(_, fields, _) = p.kind
+            && let Some(field) = fields
+                .iter()
+                .find(|field| field.pat.hir_id == hir_id && field.is_shorthand)
+        {
+            name = Some(field.ident.name);
+        }
+        name.is_none()
+    });
+    name
+}
+
 fn pat_contains_or(pat: &Pat<'_>) -> bool {
     let mut result = false;
     pat.walk(|p| {
@@ -8,7 +8,7 @@
 use rustc_lint::LateContext;
 use rustc_span::{sym, SyntaxContext};
 
-use super::manual_utils::{check_with, SomeExpr};
+use super::manual_utils::{check_with, SomeExpr, SuggInfo};
 use super::MANUAL_FILTER;
 
 // Function called on the <expr> of `[&+]Some((ref | ref mut) x) => <expr>`
@@ -61,13 +61,11 @@ fn peels_blocks_incl_unsafe<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
 // }
 // Returns true if <expr> resolves to `Some(x)`, `false` otherwise
 fn is_some_expr(cx: &LateContext<'_>, target: HirId, ctxt: SyntaxContext, expr: &Expr<'_>) -> bool {
-    if let Some(inner_expr) = peels_blocks_incl_unsafe_opt(expr) {
-        // there can be not statements in the block as they would be removed when switching to `.filter`
-        if let ExprKind::Call(callee, [arg]) = inner_expr.kind {
-            return ctxt == expr.span.ctxt()
-                && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
-                && path_to_local_id(arg, target);
-        }
+    // there can be not statements in the block as they would be removed when switching to `.filter`
+    if let ExprKind::Call(callee, [arg]) = peels_blocks_incl_unsafe(expr).kind {
+        return ctxt == expr.span.ctxt()
+            && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+            && path_to_local_id(arg, target);
     };
     false
 }
@@ -112,6 +110,38 @@ pub(super) fn check_match<'tcx>(
             Some(second_arm.pat),
             second_arm.body,
         );
+    } else if is_type_diagnostic_item(cx, ty, sym::Option)
+        && let [first_arm, second_arm] = arms
+        && let Some(guard) = first_arm.guard
+        && !matches!(guard.kind, ExprKind::Let(..))
+        && second_arm.guard.is_none()
+        && matches!(second_arm.pat.kind, PatKind::Wild)
+    {
+        // `Some(x) if <cond> => Some(x), _ => None`
+        if let Some(sugg_info) = check_with(
+            cx,
+            expr,
+            scrutinee,
+            first_arm.pat,
+            first_arm.body,
+            Some(second_arm.pat),
+            second_arm.body,
+            |cx, pat, body, ctxt| {
+                if let PatKind::Binding(_, target, ..) = pat.kind
+                    && is_some_expr(cx, target, ctxt, body)
+                {
+                    Some(SomeExpr {
+                        expr: peels_blocks_incl_unsafe(guard),
+                        needs_unsafe_block: contains_unsafe_block(cx, guard),
+                        needs_negated: false,
+                    })
+                } else {
+                    None
+                }
+            },
+        ) {
+            lint(cx, expr, sugg_info);
+        }
     }
 }
 
@@ -145,22 +175,26 @@ fn check<'tcx>(
         else_body,
         get_cond_expr,
     ) {
-        let body_str = add_ampersand_if_copy(sugg_info.body_str, sugg_info.scrutinee_impl_copy);
-        span_lint_and_sugg(
-            cx,
-            MANUAL_FILTER,
-            expr.span,
-            "manual implementation of `Option::filter`",
-            "try",
-            if sugg_info.needs_brackets {
-                format!(
-                    "{{ {}{}.filter({body_str}) }}",
-                    sugg_info.scrutinee_str, sugg_info.as_ref_str
-                )
-            } else {
-                format!("{}{}.filter({body_str})", sugg_info.scrutinee_str, sugg_info.as_ref_str)
-            },
-            sugg_info.app,
-        );
+        lint(cx, expr, sugg_info);
     }
 }
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, sugg_info: SuggInfo<'_>) {
+    let body_str = add_ampersand_if_copy(sugg_info.body_str, sugg_info.scrutinee_impl_copy);
+    span_lint_and_sugg(
+        cx,
+        MANUAL_FILTER,
+        expr.span,
+        "manual implementation of `Option::filter`",
+        "try",
+        if sugg_info.needs_brackets {
+            format!(
+                "{{ {}{}.filter({body_str}) }}",
+                sugg_info.scrutinee_str, sugg_info.as_ref_str
+            )
+        } else {
+            format!("{}{}.filter({body_str})", sugg_info.scrutinee_str, sugg_info.as_ref_str)
+        },
+        sugg_info.app,
+    );
+}
@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::manual_utils::{is_none_expr, some_local, try_parse_option_pair, try_parse_pair_pattern};
+use super::MANUAL_OPTION_XOR;
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    let ctxt = expr.span.ctxt();
+    if let [some_arms @ .., wild_arm] = arms
+        && matches!(some_arms.len(), 1 | 2)
+        && arms.iter().all(|arm| arm.guard.is_none())
+        && matches!(wild_arm.pat.kind, PatKind::Wild)
+        && is_none_expr(cx, wild_arm.body)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Option)
+        && let Some((a, b)) = try_parse_option_pair(cx, scrutinee)
+    {
+        // Each of `(Some(x), None)` and `(None, Some(x))` must be mapped to `Some(x)` exactly once.
+        let mut seen_first = false;
+        let mut seen_second = false;
+        for arm in some_arms {
+            let Some(local) = some_local(cx, arm.body) else {
+                return;
+            };
+            let name = cx.tcx.hir().name(local);
+            let pats = if let PatKind::Or(pats) = arm.pat.kind {
+                pats
+            } else {
+                std::slice::from_ref(arm.pat)
+            };
+            for pat in pats {
+                let seen = match try_parse_pair_pattern(cx, pat, ctxt) {
+                    Some((Some((_, binding)), None)) if binding == name => &mut seen_first,
+                    Some((None, Some((_, binding)))) if binding == name => &mut seen_second,
+                    _ => return,
+                };
+                if *seen {
+                    return;
+                }
+                *seen = true;
+            }
+        }
+
+        if seen_first && seen_second {
+            let mut app = Applicability::MachineApplicable;
+            let recv = Sugg::hir_with_context(cx, a, ctxt, "..", &mut app).maybe_par();
+            let (arg, _) = snippet_with_context(cx, b.span, ctxt, "..", &mut app);
+            span_lint_and_sugg(
+                cx,
+                MANUAL_OPTION_XOR,
+                expr.span,
+                "manual implementation of `Option::xor`",
+                "try",
+                format!("{recv}.xor({arg})"),
+                app,
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::OptionSome;
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::manual_utils::{is_none_expr, try_parse_option_pair, try_parse_pair_pattern};
+use super::MANUAL_OPTION_ZIP;
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    if let [arm1, arm2] = arms
+        && arm1.guard.is_none()
+        && arm2.guard.is_none()
+        && matches!(arm2.pat.kind, PatKind::Wild)
+    {
+        check(cx, expr, scrutinee, arm1.pat, arm1.body, arm2.body);
+    }
+}
+
+pub(super) fn check_if_let<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    let_pat: &'tcx Pat<'_>,
+    let_expr: &'tcx Expr<'_>,
+    then_expr: &'tcx Expr<'_>,
+    else_expr: &'tcx Expr<'_>,
+) {
+    check(cx, expr, let_expr, let_pat, then_expr, else_expr);
+}
+
+fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    some_pat: &'tcx Pat<'_>,
+    some_body: &'tcx Expr<'_>,
+    none_body: &'tcx Expr<'_>,
+) {
+    let ctxt = expr.span.ctxt();
+    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Option)
+        && let Some((a, b)) = try_parse_option_pair(cx, scrutinee)
+        && let Some((Some((x, _)), Some((y, _)))) = try_parse_pair_pattern(cx, some_pat, ctxt)
+        && is_none_expr(cx, none_body)
+        // `Some((x, y))`
+        && let ExprKind::Call(callee, [arg]) = peel_blocks(some_body).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+        && let ExprKind::Tup([first, second]) = arg.kind
+        && path_to_local_id(first, x)
+        && path_to_local_id(second, y)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_context(cx, a, ctxt, "..", &mut app).maybe_par();
+        let (arg, _) = snippet_with_context(cx, b.span, ctxt, "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_OPTION_ZIP,
+            expr.span,
+            "manual implementation of `Option::zip`",
+            "try",
+            format!("{recv}.zip({arg})"),
+            app,
+        );
+    }
+}
@@ -13,7 +13,7 @@
 use rustc_hir::LangItem::{OptionNone, OptionSome};
 use rustc_hir::{BindingAnnotation, Expr, ExprKind, HirId, Mutability, Pat, PatKind, Path, QPath};
 use rustc_lint::LateContext;
-use rustc_span::{sym, SyntaxContext};
+use rustc_span::{sym, Symbol, SyntaxContext};
 
 #[expect(clippy::too_many_arguments)]
 #[expect(clippy::too_many_lines)]
@@ -271,6 +271,62 @@ fn f<'tcx>(
 }
 
 // Checks for the `None` value.
-fn is_none_expr(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+pub(super) fn is_none_expr(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     is_res_lang_ctor(cx, path_res(cx, peel_blocks(expr)), OptionNone)
 }
+
+// Checks for `Some(<local>)`, returning the local.
+pub(super) fn some_local(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::Call(callee, [arg]) = peel_blocks(expr).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+        && let Res::Local(id) = path_res(cx, arg)
+    {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+// Try to parse the scrutinee of a `match (a, b)` on two `Option` values.
+pub(super) fn try_parse_option_pair<'tcx>(
+    cx: &LateContext<'tcx>,
+    scrutinee: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Tup([a, b]) = scrutinee.kind
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(a), sym::Option)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(b), sym::Option)
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+// Try to parse a pattern on a pair of `Option` values into the patterns of each `Option`.
+// i.e. `(None, Some(x))`, where `x` must be bound by value.
+pub(super) fn try_parse_pair_pattern<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    ctxt: SyntaxContext,
+) -> Option<(Option<(HirId, Symbol)>, Option<(HirId, Symbol)>)> {
+    fn f<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, ctxt: SyntaxContext) -> Option<Option<(HirId, Symbol)>> {
+        match try_parse_pattern(cx, pat, ctxt)? {
+            OptionPat::None => Some(None),
+            OptionPat::Some { pattern, ref_count: 0 } => {
+                if let PatKind::Binding(BindingAnnotation::NONE, id, ident, None) = pattern.kind {
+                    Some(Some((id, ident.name)))
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+    if let PatKind::Tuple([a, b], dotdot) = pat.kind
+        && dotdot.as_opt_usize().is_none()
+    {
+        Some((f(cx, a, ctxt)?, f(cx, b, ctxt)?))
+    } else {
+        None
+    }
+}
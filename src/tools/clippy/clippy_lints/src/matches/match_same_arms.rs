@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_hir_and_then;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_with_applicability};
 use clippy_utils::{is_lint_allowed, path_to_local, search_same, SpanlessEq, SpanlessHash};
 use core::cmp::Ordering;
 use core::{iter, slice};
@@ -136,6 +136,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
             } else {
                 (arm2, arm1)
             };
+            let move_idx = if move_arm.hir_id == arm2.hir_id { j } else { i };
 
             span_lint_hir_and_then(
                 cx,
@@ -144,6 +145,30 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 keep_arm.span,
                 "this match arm has an identical body to another arm",
                 |diag| {
+                    if i.abs_diff(j) == 1 {
+                        // The arms are adjacent, so the other arm can be removed along with its separator
+                        // without reordering any arm in between.
+                        let mut app = Applicability::MachineApplicable;
+                        let move_pat_snip = snippet_with_applicability(cx, move_arm.pat.span, "<pat2>", &mut app);
+                        let keep_pat_snip = snippet_with_applicability(cx, keep_arm.pat.span, "<pat1>", &mut app);
+                        let remove_span = match arms.get(move_idx + 1) {
+                            Some(next_arm) => move_arm.span.until(next_arm.span),
+                            None => arms[move_idx - 1].span.shrink_to_hi().to(move_arm.span),
+                        };
+
+                        diag.help("or try changing either arm body")
+                            .span_note(move_arm.span, "other arm here")
+                            .multipart_suggestion(
+                                "try merging the arm patterns",
+                                vec![
+                                    (keep_arm.pat.span, format!("{keep_pat_snip} | {move_pat_snip}")),
+                                    (remove_span, String::new()),
+                                ],
+                                app,
+                            );
+                        return;
+                    }
+
                     let move_pat_snip = snippet(cx, move_arm.pat.span, "<pat2>");
                     let keep_pat_snip = snippet(cx, keep_arm.pat.span, "<pat1>");
 
@@ -2,6 +2,8 @@
 mod infallible_destructuring_match;
 mod manual_filter;
 mod manual_map;
+mod manual_option_xor;
+mod manual_option_zip;
 mod manual_unwrap_or;
 mod manual_utils;
 mod match_as_ref;
@@ -26,7 +28,9 @@
 
 use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::source::{snippet_opt, walk_span_to_context};
-use clippy_utils::{higher, in_constant, is_direct_expn_of, is_span_match, tokenize_with_text};
+use clippy_utils::{def_path_def_ids, higher, in_constant, is_direct_expn_of, is_span_match, tokenize_with_text};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
 use rustc_hir::{Arm, Expr, ExprKind, Local, MatchSource, Pat};
 use rustc_lexer::TokenKind;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
@@ -794,8 +798,9 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for temporaries returned from function calls in a match scrutinee that have the
-    /// `clippy::has_significant_drop` attribute.
+    /// Checks for temporaries returned from function calls in a `match` or `if let` scrutinee that
+    /// have the `clippy::has_significant_drop` attribute, or whose type is listed in the
+    /// `significant-drop-types` configuration.
     ///
     /// ### Why is this bad?
     /// The `clippy::has_significant_drop` attribute can be added to types whose Drop impls have
@@ -919,7 +924,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `match` which could be implemented using `filter`
+    /// Checks for usage of `match` which could be implemented using `filter`, including
+    /// matches with a guard such as `Some(x) if x > 0 => Some(x), _ => None`
     ///
     /// ### Why is this bad?
     /// Using the `filter` method is clearer and more concise.
@@ -975,17 +981,73 @@
     "checks for unnecessary guards in match expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `match` or `if let` which could be implemented using `Option::zip`.
+    ///
+    /// ### Why is this bad?
+    /// Using the `zip` method is clearer and more concise.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (a, b) = (Some(1), Some(2));
+    /// let pair = match (a, b) {
+    ///     (Some(x), Some(y)) => Some((x, y)),
+    ///     _ => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (a, b) = (Some(1), Some(2));
+    /// let pair = a.zip(b);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub MANUAL_OPTION_ZIP,
+    complexity,
+    "reimplementation of `Option::zip`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `match` which could be implemented using `Option::xor`.
+    ///
+    /// ### Why is this bad?
+    /// Using the `xor` method is clearer and more concise.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (a, b) = (Some(1), None);
+    /// let one = match (a, b) {
+    ///     (Some(x), None) | (None, Some(x)) => Some(x),
+    ///     _ => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (a, b) = (Some(1), None);
+    /// let one = a.xor(b);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub MANUAL_OPTION_XOR,
+    complexity,
+    "reimplementation of `Option::xor`"
+}
+
 pub struct Matches {
     msrv: Msrv,
     infallible_destructuring_match_linted: bool,
+    significant_drop_types: Vec<String>,
+    significant_drop_def_ids: FxHashSet<DefId>,
 }
 
 impl Matches {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
+    pub fn new(msrv: Msrv, significant_drop_types: Vec<String>) -> Self {
         Self {
             msrv,
             infallible_destructuring_match_linted: false,
+            significant_drop_types,
+            significant_drop_def_ids: FxHashSet::default(),
         }
     }
 }
@@ -1017,9 +1079,18 @@ pub fn new(msrv: Msrv) -> Self {
     MANUAL_MAP,
     MANUAL_FILTER,
     REDUNDANT_GUARDS,
+    MANUAL_OPTION_ZIP,
+    MANUAL_OPTION_XOR,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.significant_drop_types {
+            let segments: Vec<_> = path.split("::").collect();
+            self.significant_drop_def_ids.extend(def_path_def_ids(cx, &segments));
+        }
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if is_direct_expn_of(expr.span, "matches").is_none() && in_external_macro(cx.sess(), expr.span) {
             return;
@@ -1038,7 +1109,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 return;
             }
             if matches!(source, MatchSource::Normal | MatchSource::ForLoopDesugar) {
-                significant_drop_in_scrutinee::check(cx, expr, ex, arms, source);
+                significant_drop_in_scrutinee::check(cx, expr, ex, arms, source, &self.significant_drop_def_ids);
             }
 
             collapsible_match::check_match(cx, arms);
@@ -1073,6 +1144,12 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         manual_unwrap_or::check(cx, expr, ex, arms);
                         manual_map::check_match(cx, expr, ex, arms);
                         manual_filter::check_match(cx, ex, arms, expr);
+                        if self.msrv.meets(msrvs::OPTION_ZIP) {
+                            manual_option_zip::check_match(cx, expr, ex, arms);
+                        }
+                        if self.msrv.meets(msrvs::OPTION_XOR) {
+                            manual_option_xor::check_match(cx, expr, ex, arms);
+                        }
                     }
 
                     if self.infallible_destructuring_match_linted {
@@ -1086,6 +1163,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         } else if let Some(if_let) = higher::IfLet::hir(cx, expr) {
             collapsible_match::check_if_let(cx, if_let.let_pat, if_let.if_then, if_let.if_else);
             if !from_expansion {
+                significant_drop_in_scrutinee::check_if_let(
+                    cx,
+                    expr,
+                    if_let.let_expr,
+                    if_let.if_then,
+                    if_let.if_else,
+                    &self.significant_drop_def_ids,
+                );
                 if let Some(else_expr) = if_let.if_else {
                     if self.msrv.meets(msrvs::MATCHES_MACRO) {
                         match_like_matches::check_if_let(
@@ -1107,6 +1192,16 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             if_let.if_then,
                             else_expr,
                         );
+                        if self.msrv.meets(msrvs::OPTION_ZIP) {
+                            manual_option_zip::check_if_let(
+                                cx,
+                                expr,
+                                if_let.let_pat,
+                                if_let.let_expr,
+                                if_let.if_then,
+                                else_expr,
+                            );
+                        }
                     }
                 }
                 redundant_pattern_match::check_if_let(
@@ -3,6 +3,7 @@
 use clippy_utils::source::{indent_of, snippet};
 use clippy_utils::{get_attr, is_lint_allowed};
 use rustc_errors::{Applicability, Diag};
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_expr, Visitor};
 use rustc_hir::{Arm, Expr, ExprKind, MatchSource};
 use rustc_lint::{LateContext, LintContext};
@@ -17,27 +18,71 @@ pub(super) fn check<'tcx>(
     scrutinee: &'tcx Expr<'_>,
     arms: &'tcx [Arm<'_>],
     source: MatchSource,
+    sig_drop_types: &FxHashSet<DefId>,
 ) {
     if is_lint_allowed(cx, SIGNIFICANT_DROP_IN_SCRUTINEE, expr.hir_id) {
         return;
     }
 
-    if let Some((suggestions, message)) = has_significant_drop_in_scrutinee(cx, scrutinee, source) {
-        for found in suggestions {
-            span_lint_and_then(cx, SIGNIFICANT_DROP_IN_SCRUTINEE, found.found_span, message, |diag| {
-                set_diagnostic(diag, cx, expr, found);
-                let s = Span::new(expr.span.hi(), expr.span.hi(), expr.span.ctxt(), None);
-                diag.span_label(s, "temporary lives until here");
-                for span in has_significant_drop_in_arms(cx, arms) {
-                    diag.span_label(span, "another value with significant `Drop` created here");
-                }
-                diag.note("this might lead to deadlocks or other unexpected behavior");
-            });
-        }
+    if let Some((suggestions, message)) = has_significant_drop_in_scrutinee(cx, scrutinee, source, sig_drop_types) {
+        let arm_spans = has_significant_drop_in_arms(cx, arms.iter().map(|arm| arm.body), sig_drop_types);
+        lint(cx, expr, suggestions, message, "match", &arm_spans);
+    }
+}
+
+pub(super) fn check_if_let<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    scrutinee: &'tcx Expr<'_>,
+    if_then: &'tcx Expr<'tcx>,
+    if_else: Option<&'tcx Expr<'tcx>>,
+    sig_drop_types: &FxHashSet<DefId>,
+) {
+    if is_lint_allowed(cx, SIGNIFICANT_DROP_IN_SCRUTINEE, expr.hir_id) {
+        return;
+    }
+
+    if let Some(suggestions) = SigDropHelper::new(cx, sig_drop_types).find_sig_drop(scrutinee) {
+        let arm_spans = has_significant_drop_in_arms(cx, [if_then].into_iter().chain(if_else), sig_drop_types);
+        lint(
+            cx,
+            expr,
+            suggestions,
+            "temporary with significant `Drop` in `if let` scrutinee will live until the end of the `if let` expression",
+            "`if let`",
+            &arm_spans,
+        );
     }
 }
 
-fn set_diagnostic<'tcx>(diag: &mut Diag<'_, ()>, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, found: FoundSigDrop) {
+fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    suggestions: Vec<FoundSigDrop>,
+    message: &str,
+    construct: &str,
+    arm_spans: &FxHashSet<Span>,
+) {
+    for found in suggestions {
+        span_lint_and_then(cx, SIGNIFICANT_DROP_IN_SCRUTINEE, found.found_span, message, |diag| {
+            set_diagnostic(diag, cx, expr, found, construct);
+            let s = Span::new(expr.span.hi(), expr.span.hi(), expr.span.ctxt(), None);
+            diag.span_label(s, "temporary lives until here");
+            for &span in arm_spans {
+                diag.span_label(span, "another value with significant `Drop` created here");
+            }
+            diag.note("this might lead to deadlocks or other unexpected behavior");
+        });
+    }
+}
+
+fn set_diagnostic<'tcx>(
+    diag: &mut Diag<'_, ()>,
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    found: FoundSigDrop,
+    construct: &str,
+) {
     if found.lint_suggestion == LintSuggestion::MoveAndClone {
         // If our suggestion is to move and clone, then we want to leave it to the user to
         // decide how to address this lint, since it may be that cloning is inappropriate.
@@ -59,9 +104,9 @@ fn set_diagnostic<'tcx>(diag: &mut Diag<'_, ()>, cx: &LateContext<'tcx>, expr: &
     };
 
     let suggestion_message = if found.lint_suggestion == LintSuggestion::MoveOnly {
-        "try moving the temporary above the match"
+        format!("try moving the temporary above the {construct}")
     } else {
-        "try moving the temporary above the match and create a copy"
+        format!("try moving the temporary above the {construct} and create a copy")
     };
 
     let scrutinee_replacement = if found.is_unit_return_val {
@@ -86,8 +131,9 @@ fn has_significant_drop_in_scrutinee<'tcx>(
     cx: &LateContext<'tcx>,
     scrutinee: &'tcx Expr<'tcx>,
     source: MatchSource,
+    sig_drop_types: &FxHashSet<DefId>,
 ) -> Option<(Vec<FoundSigDrop>, &'static str)> {
-    let mut helper = SigDropHelper::new(cx);
+    let mut helper = SigDropHelper::new(cx, sig_drop_types);
     let scrutinee = match (source, &scrutinee.kind) {
         (MatchSource::ForLoopDesugar, ExprKind::Call(_, [e])) => e,
         _ => scrutinee,
@@ -105,13 +151,15 @@ fn has_significant_drop_in_scrutinee<'tcx>(
 struct SigDropChecker<'a, 'tcx> {
     seen_types: FxHashSet<Ty<'tcx>>,
     cx: &'a LateContext<'tcx>,
+    sig_drop_types: &'a FxHashSet<DefId>,
 }
 
 impl<'a, 'tcx> SigDropChecker<'a, 'tcx> {
-    fn new(cx: &'a LateContext<'tcx>) -> SigDropChecker<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, sig_drop_types: &'a FxHashSet<DefId>) -> SigDropChecker<'a, 'tcx> {
         SigDropChecker {
             seen_types: FxHashSet::default(),
             cx,
+            sig_drop_types,
         }
     }
 
@@ -125,7 +173,9 @@ fn has_seen_type(&mut self, ty: Ty<'tcx>) -> bool {
 
     fn has_sig_drop_attr(&mut self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
         if let Some(adt) = ty.ty_adt_def() {
-            if get_attr(cx.sess(), cx.tcx.get_attrs_unchecked(adt.did()), "has_significant_drop").count() > 0 {
+            if self.sig_drop_types.contains(&adt.did())
+                || get_attr(cx.sess(), cx.tcx.get_attrs_unchecked(adt.did()), "has_significant_drop").count() > 0
+            {
                 return true;
             }
         }
@@ -183,7 +233,7 @@ struct FoundSigDrop {
 }
 
 impl<'a, 'tcx> SigDropHelper<'a, 'tcx> {
-    fn new(cx: &'a LateContext<'tcx>) -> SigDropHelper<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, sig_drop_types: &'a FxHashSet<DefId>) -> SigDropHelper<'a, 'tcx> {
         SigDropHelper {
             cx,
             is_chain_end: true,
@@ -191,7 +241,7 @@ fn new(cx: &'a LateContext<'tcx>) -> SigDropHelper<'a, 'tcx> {
             current_sig_drop: None,
             sig_drop_spans: None,
             special_handling_for_binary_op: false,
-            sig_drop_checker: SigDropChecker::new(cx),
+            sig_drop_checker: SigDropChecker::new(cx, sig_drop_types),
         }
     }
 
@@ -369,18 +419,22 @@ struct ArmSigDropHelper<'a, 'tcx> {
 }
 
 impl<'a, 'tcx> ArmSigDropHelper<'a, 'tcx> {
-    fn new(cx: &'a LateContext<'tcx>) -> ArmSigDropHelper<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, sig_drop_types: &'a FxHashSet<DefId>) -> ArmSigDropHelper<'a, 'tcx> {
         ArmSigDropHelper {
-            sig_drop_checker: SigDropChecker::new(cx),
+            sig_drop_checker: SigDropChecker::new(cx, sig_drop_types),
             found_sig_drop_spans: FxHashSet::<Span>::default(),
         }
     }
 }
 
-fn has_significant_drop_in_arms<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) -> FxHashSet<Span> {
-    let mut helper = ArmSigDropHelper::new(cx);
-    for arm in arms {
-        helper.visit_expr(arm.body);
+fn has_significant_drop_in_arms<'tcx>(
+    cx: &LateContext<'tcx>,
+    bodies: impl IntoIterator<Item = &'tcx Expr<'tcx>>,
+    sig_drop_types: &FxHashSet<DefId>,
+) -> FxHashSet<Span> {
+    let mut helper = ArmSigDropHelper::new(cx, sig_drop_types);
+    for body in bodies {
+        helper.visit_expr(body);
     }
     helper.found_sig_drop_spans
 }
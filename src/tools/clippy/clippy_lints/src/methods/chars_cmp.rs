@@ -1,10 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{method_chain_args, path_def_id};
+use clippy_utils::{eq_expr_value, is_integer_literal, is_res_lang_ctor, method_chain_args, path_def_id, path_res};
+use rustc_ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::{BinOpKind, BorrowKind, LangItem, Mutability};
 use rustc_lint::{LateContext, Lint};
 use rustc_middle::ty;
+use rustc_span::sym;
 
 /// Wrapper fn for `CHARS_NEXT_CMP` and `CHARS_LAST_CMP` lints.
 pub(super) fn check(
@@ -46,3 +49,99 @@ pub(super) fn check(
 
     false
 }
+
+/// Wrapper fn for the byte based variants of `CHARS_NEXT_CMP` and `CHARS_LAST_CMP`, i.e.
+/// `s.as_bytes()[0] == b'_'` and `s.as_bytes().first() == Some(&b'_')`, or the `last` equivalents.
+pub(super) fn check_bytes(
+    cx: &LateContext<'_>,
+    info: &crate::methods::BinaryExprInfo<'_>,
+    first: bool,
+    lint: &'static Lint,
+    suggest: &str,
+) -> bool {
+    let (recv, byte, indexed) = match info.chain.kind {
+        hir::ExprKind::Index(bytes, index, _) => {
+            let hir::ExprKind::Lit(lit) = info.other.kind else {
+                return false;
+            };
+            let Some(recv) = as_bytes_recv(bytes) else {
+                return false;
+            };
+            let is_expected_index = if first {
+                is_integer_literal(index, 0)
+            } else if let hir::ExprKind::Binary(op, len, one) = index.kind
+                && let hir::ExprKind::MethodCall(path, len_recv, [], _) = len.kind
+            {
+                // `s.as_bytes()[s.len() - 1]`
+                op.node == BinOpKind::Sub
+                    && is_integer_literal(one, 1)
+                    && path.ident.name == sym::len
+                    && eq_expr_value(cx, len_recv, recv)
+            } else {
+                false
+            };
+            if !is_expected_index {
+                return false;
+            }
+            (recv, lit, true)
+        },
+        hir::ExprKind::MethodCall(path, bytes, [], _)
+            if path.ident.name == if first { sym!(first) } else { sym!(last) } =>
+        {
+            // `Some(&b'_')`
+            if let Some(recv) = as_bytes_recv(bytes)
+                && let hir::ExprKind::Call(fun, [arg]) = info.other.kind
+                && is_res_lang_ctor(cx, path_res(cx, fun), LangItem::OptionSome)
+                && let hir::ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, arg) = arg.kind
+                && let hir::ExprKind::Lit(lit) = arg.kind
+            {
+                (recv, lit, false)
+            } else {
+                return false;
+            }
+        },
+        _ => return false,
+    };
+
+    // only ASCII bytes are guaranteed to be a whole char
+    let LitKind::Byte(byte) = byte.node else {
+        return false;
+    };
+    if !byte.is_ascii() || *cx.typeck_results().expr_ty_adjusted(recv).peel_refs().kind() != ty::Str {
+        return false;
+    }
+
+    // indexing panics on an empty string, `starts_with` and `ends_with` don't
+    let mut applicability = if indexed {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    span_lint_and_sugg(
+        cx,
+        lint,
+        info.expr.span,
+        &format!("you should use the `{suggest}` method"),
+        "like this",
+        format!(
+            "{}{}.{suggest}('{}')",
+            if info.eq { "" } else { "!" },
+            snippet_with_applicability(cx, recv.span, "..", &mut applicability),
+            char::from(byte).escape_default()
+        ),
+        applicability,
+    );
+
+    true
+}
+
+/// Returns `s` given `s.as_bytes()`.
+fn as_bytes_recv<'a>(expr: &'a hir::Expr<'a>) -> Option<&'a hir::Expr<'a>> {
+    if let hir::ExprKind::MethodCall(path, recv, [], _) = expr.kind
+        && path.ident.name == sym!(as_bytes)
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
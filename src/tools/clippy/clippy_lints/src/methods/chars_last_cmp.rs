@@ -9,5 +9,6 @@ pub(super) fn check(cx: &LateContext<'_>, info: &crate::methods::BinaryExprInfo<
         true
     } else {
         chars_cmp::check(cx, info, &["chars", "next_back"], CHARS_LAST_CMP, "ends_with")
+            || chars_cmp::check_bytes(cx, info, false, CHARS_LAST_CMP, "ends_with")
     }
 }
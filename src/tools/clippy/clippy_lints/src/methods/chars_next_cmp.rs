@@ -5,4 +5,5 @@
 /// Checks for the `CHARS_NEXT_CMP` lint.
 pub(super) fn check(cx: &LateContext<'_>, info: &crate::methods::BinaryExprInfo<'_>) -> bool {
     crate::methods::chars_cmp::check(cx, info, &["chars", "next"], CHARS_NEXT_CMP, "starts_with")
+        || crate::methods::chars_cmp::check_bytes(cx, info, true, CHARS_NEXT_CMP, "starts_with")
 }
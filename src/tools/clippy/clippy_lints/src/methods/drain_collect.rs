@@ -1,9 +1,11 @@
 use crate::methods::DRAIN_COLLECT;
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_range_full;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::{is_integer_literal, is_range_full};
 use rustc_errors::Applicability;
+use rustc_hir::def::Res;
 use rustc_hir::{Expr, ExprKind, LangItem, Path, QPath};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
@@ -48,6 +50,39 @@ fn check_collections(cx: &LateContext<'_>, expr: Ty<'_>, recv: Ty<'_>) -> Option
         .or_else(|| types_match_diagnostic_item(cx, expr, recv, sym::BinaryHeap).then_some("BinaryHeap"))
 }
 
+/// Checks if `recv` is an owned local that is used again after `expr`. Moving the buffer out with
+/// `mem::take` would throw away the capacity the original keeps for that later use.
+fn is_reused_local(cx: &LateContext<'_>, expr: &Expr<'_>, recv_ty: Ty<'_>, recv_path: &Path<'_>) -> bool {
+    if !matches!(recv_ty.kind(), ty::Ref(..))
+        && let Res::Local(local_id) = recv_path.res
+    {
+        local_used_after_expr(cx, local_id, expr)
+    } else {
+        false
+    }
+}
+
+/// Checks if `recv` is an owned local that is not used after `expr`, so nothing is lost by moving
+/// its buffer out with `mem::take`.
+fn is_dead_local(cx: &LateContext<'_>, expr: &Expr<'_>, recv_ty: Ty<'_>, recv_path: &Path<'_>) -> bool {
+    if !matches!(recv_ty.kind(), ty::Ref(..))
+        && let Res::Local(local_id) = recv_path.res
+    {
+        !local_used_after_expr(cx, local_id, expr)
+    } else {
+        false
+    }
+}
+
+fn take_sugg(cx: &LateContext<'_>, recv: &Expr<'_>, recv_ty: Ty<'_>) -> String {
+    let recv = snippet(cx, recv.span, "<expr>");
+    if let ty::Ref(..) = recv_ty.kind() {
+        format!("std::mem::take({recv})")
+    } else {
+        format!("std::mem::take(&mut {recv})")
+    }
+}
+
 pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, recv: &Expr<'_>) {
     let expr_ty = cx.typeck_results().expr_ty(expr);
     let recv_ty = cx.typeck_results().expr_ty(recv);
@@ -59,11 +94,13 @@ pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, re
             .or_else(|| check_string(cx, args, expr_ty, recv_ty_no_refs, recv_path).then_some("String"))
             .or_else(|| check_collections(cx, expr_ty, recv_ty_no_refs))
     {
-        let recv = snippet(cx, recv.span, "<expr>");
-        let sugg = if let ty::Ref(..) = recv_ty.kind() {
-            format!("std::mem::take({recv})")
+        let (help, sugg) = if matches!(typename, "Vec" | "String") && is_reused_local(cx, expr, recv_ty, recv_path) {
+            (
+                "consider using `split_off`",
+                format!("{}.split_off(0)", snippet(cx, recv.span, "<expr>")),
+            )
         } else {
-            format!("std::mem::take(&mut {recv})")
+            ("consider using `mem::take`", take_sugg(cx, recv, recv_ty))
         };
 
         span_lint_and_sugg(
@@ -71,9 +108,40 @@ pub(super) fn check(cx: &LateContext<'_>, args: &[Expr<'_>], expr: &Expr<'_>, re
             DRAIN_COLLECT,
             expr.span,
             &format!("you seem to be trying to move all elements into a new `{typename}`"),
-            "consider using `mem::take`",
+            help,
             sugg,
             Applicability::MachineApplicable,
         );
     }
 }
+
+/// Checks `recv.split_off(0)` on an owned collection that is not used afterwards. Behind a
+/// reference, the caller would lose the capacity of its collection.
+pub(super) fn check_split_off(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let recv_ty_no_refs = recv_ty.peel_refs();
+
+    if is_integer_literal(arg, 0)
+        && let ExprKind::Path(QPath::Resolved(_, recv_path)) = recv.kind
+        && let Some(typename) = if is_type_diagnostic_item(cx, recv_ty_no_refs, sym::Vec) {
+            Some("Vec")
+        } else if is_type_diagnostic_item(cx, recv_ty_no_refs, sym::VecDeque) {
+            Some("VecDeque")
+        } else if is_type_lang_item(cx, recv_ty_no_refs, LangItem::String) {
+            Some("String")
+        } else {
+            None
+        }
+        && is_dead_local(cx, expr, recv_ty, recv_path)
+    {
+        span_lint_and_sugg(
+            cx,
+            DRAIN_COLLECT,
+            expr.span,
+            &format!("you seem to be trying to move all elements into a new `{typename}`"),
+            "consider using `mem::take`",
+            take_sugg(cx, recv, recv_ty),
+            Applicability::MachineApplicable,
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{get_type_diagnostic_name, is_copy, is_type_lang_item};
+use clippy_utils::{is_expr_used_or_unified, match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+use super::EXTEND_SINGLE_ELEMENT;
+
+/// Returns the only element of `iter` if it yields a single value, e.g. `iter::once(x)`, and
+/// whether that value is cloned.
+fn single_element<'tcx>(cx: &LateContext<'_>, iter: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    match iter.kind {
+        ExprKind::Call(func, [elem])
+            if path_def_id(cx, func).is_some_and(|id| match_def_path(cx, id, &paths::ITER_ONCE)) =>
+        {
+            Some((elem, false))
+        },
+        ExprKind::Array([elem]) => Some((elem, false)),
+        // `[x].iter().cloned()`
+        ExprKind::MethodCall(name, recv, [], _)
+            if matches!(name.ident.as_str(), "cloned" | "copied")
+                && let ExprKind::MethodCall(iter_name, array, [], _) = recv.kind
+                && iter_name.ident.as_str() == "iter"
+                && let ExprKind::Array([elem]) = array.kind =>
+        {
+            Some((elem, true))
+        },
+        _ => None,
+    }
+}
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    call_span: Span,
+) {
+    let Some((elem, cloned)) = single_element(cx, arg) else {
+        return;
+    };
+    let ctxt = expr.span.ctxt();
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    let ty::Adt(_, recv_args) = recv_ty.kind() else {
+        return;
+    };
+    let elem_ty = cx.typeck_results().expr_ty(elem);
+    let mut app = if cloned && !is_copy(cx, elem_ty) {
+        // The element is moved instead of cloned
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+
+    // Collections also implement `Extend<&T>`, in which case `push(&x)` wouldn't compile
+    let is_item_ty = |i: usize, e: &Expr<'_>| recv_args.type_at(i) == cx.typeck_results().expr_ty(e);
+
+    let (method, args) = match get_type_diagnostic_name(cx, recv_ty) {
+        Some(sym::Vec | sym::BinaryHeap) if is_item_ty(0, elem) => ("push", vec![elem]),
+        Some(sym::VecDeque | sym::LinkedList) if is_item_ty(0, elem) => ("push_back", vec![elem]),
+        // `insert` returns a value, which `extend` doesn't
+        Some(sym::HashSet | sym::BTreeSet) if !is_expr_used_or_unified(cx.tcx, expr) && is_item_ty(0, elem) => {
+            ("insert", vec![elem])
+        },
+        Some(sym::HashMap | sym::BTreeMap)
+            if !is_expr_used_or_unified(cx.tcx, expr)
+                && let ExprKind::Tup([key, value]) = elem.kind
+                && is_item_ty(0, key)
+                && is_item_ty(1, value) =>
+        {
+            ("insert", vec![key, value])
+        },
+        _ if is_type_lang_item(cx, recv_ty, LangItem::String) => match elem_ty.kind() {
+            ty::Char => ("push", vec![elem]),
+            ty::Ref(_, inner, _) if inner.is_str() => ("push_str", vec![elem]),
+            _ => return,
+        },
+        _ => return,
+    };
+
+    let args = args
+        .iter()
+        .map(|arg| snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0)
+        .collect::<Vec<_>>()
+        .join(", ");
+    span_lint_and_sugg(
+        cx,
+        EXTEND_SINGLE_ELEMENT,
+        call_span,
+        &format!("extending with a single element, use `{method}` instead"),
+        "try",
+        format!("{method}({args})"),
+        app,
+    );
+}
@@ -0,0 +1,52 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_trait_method, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::FLOAT_SORT_BY_PARTIAL_CMP;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>, msrv: &Msrv) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.type_of(impl_id).instantiate_identity().is_slice()
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let closure_body = cx.tcx.hir().body(body)
+        && let [_, _] = closure_body.params
+        && let body_expr = peel_blocks(closure_body.value)
+        && let ExprKind::MethodCall(unwrap_path, cmp_expr, _, _) = body_expr.kind
+        && [sym::unwrap, sym::expect].contains(&unwrap_path.ident.name)
+        && let ExprKind::MethodCall(cmp_path, lhs, [rhs], _) = cmp_expr.kind
+        && cmp_path.ident.name == sym::partial_cmp
+        && is_trait_method(cx, cmp_expr, sym::PartialOrd)
+        && let ty = cx.typeck_results().expr_ty_adjusted(lhs).peel_refs()
+        && ty.is_floating_point()
+    {
+        span_lint_and_then(
+            cx,
+            FLOAT_SORT_BY_PARTIAL_CMP,
+            body_expr.span,
+            &format!("sorting `{ty}` values with `partial_cmp` and `{}`", unwrap_path.ident),
+            |diag| {
+                let mut app = Applicability::MachineApplicable;
+                let ctxt = body_expr.span.ctxt();
+                let lhs_snip = snippet_with_context(cx, lhs.span, ctxt, "..", &mut app).0;
+                let rhs_snip = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0;
+                diag.note("this panics if any of the compared values is NaN");
+                if msrv.meets(msrvs::TOTAL_CMP) {
+                    diag.span_suggestion(
+                        body_expr.span,
+                        "use a total order instead",
+                        format!("{lhs_snip}.total_cmp({rhs_snip})"),
+                        app,
+                    );
+                } else {
+                    diag.help("handle the NaN case explicitly with a comparator that defines a total order");
+                }
+            },
+        );
+    }
+}
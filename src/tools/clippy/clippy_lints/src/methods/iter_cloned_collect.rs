@@ -1,9 +1,10 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
@@ -27,3 +28,29 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir:
         );
     }
 }
+
+/// Checks for `s.chars().collect::<String>()` where `s` is a `&str` or a `String`.
+pub(super) fn check_chars<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    // `to_owned` on a `&&str` would only remove one reference
+    let is_string = |inner: ty::Ty<'_>| inner.is_str() || is_type_lang_item(cx, inner, hir::LangItem::String);
+    let recv_is_string = match recv_ty.kind() {
+        ty::Ref(_, inner, _) => is_string(*inner),
+        _ => is_type_lang_item(cx, recv_ty, hir::LangItem::String),
+    };
+    if recv_is_string
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), hir::LangItem::String)
+        && let Some(to_replace) = expr.span.trim_start(recv.span.source_callsite())
+    {
+        span_lint_and_sugg(
+            cx,
+            ITER_CLONED_COLLECT,
+            to_replace,
+            "called `chars().collect()` on a string to create a `String`. Calling `to_owned()` is both faster and \
+            more readable",
+            "try",
+            ".to_owned()".to_string(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
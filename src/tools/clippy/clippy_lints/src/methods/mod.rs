@@ -17,6 +17,7 @@
 mod drain_collect;
 mod err_expect;
 mod expect_fun_call;
+mod extend_single_element;
 mod extend_with_drain;
 mod filetype_is_file;
 mod filter_map;
@@ -26,6 +27,7 @@
 mod filter_next;
 mod flat_map_identity;
 mod flat_map_option;
+mod float_sort_by_partial_cmp;
 mod format_collect;
 mod from_iter_instead_of_collect;
 mod get_first;
@@ -866,16 +868,18 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `.chars().next()` on a `str` to check
-    /// if it starts with a given char.
+    /// if it starts with a given char. Also checks for comparing the first
+    /// byte of `.as_bytes()` against an ASCII byte literal.
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely as
-    /// `_.starts_with(_)`.
+    /// `_.starts_with(_)`. Indexing the bytes also panics on an empty string.
     ///
     /// ### Example
     /// ```no_run
     /// let name = "foo";
     /// if name.chars().next() == Some('_') {};
+    /// if name.as_bytes()[0] == b'_' {};
     /// ```
     ///
     /// Use instead:
@@ -1440,11 +1444,11 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// Checks for the use of `.cloned().collect()` or `.copied().collect()` on slice to
+    /// create a `Vec`, and of `.chars().collect()` on a string to create a `String`.
     ///
     /// ### Why is this bad?
-    /// `.to_vec()` is clearer
+    /// `.to_vec()` and `.to_owned()` are clearer, and allocate the exact size up front.
     ///
     /// ### Example
     /// ```no_run
@@ -1466,10 +1470,12 @@
     /// ### What it does
     /// Checks for usage of `_.chars().last()` or
     /// `_.chars().next_back()` on a `str` to check if it ends with a given char.
+    /// Also checks for comparing the last byte of `.as_bytes()` against an
+    /// ASCII byte literal.
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely as
-    /// `_.ends_with(_)`.
+    /// `_.ends_with(_)`. Indexing the bytes also panics on an empty string.
     ///
     /// ### Example
     /// ```no_run
@@ -1628,7 +1634,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calls to `map` followed by a `count`.
+    /// Checks for calls to `map` followed by a `count`. If the closure has no side effects,
+    /// removing the `map` call is suggested.
     ///
     /// ### Why is this bad?
     /// It looks suspicious. Maybe `map` was confused with `filter`.
@@ -2262,7 +2269,7 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for unnecessary calls to [`ToOwned::to_owned`](https://doc.rust-lang.org/std/borrow/trait.ToOwned.html#tymethod.to_owned)
-    /// and other `to_owned`-like functions.
+    /// and other `to_owned`-like functions, including `String::from` on a `&str`.
     ///
     /// ### Why is this bad?
     /// The unnecessary calls result in useless allocations.
@@ -3123,10 +3130,12 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of File::read_to_end and File::read_to_string.
+    /// Checks for usage of File::read_to_end and File::read_to_string, and for loops calling
+    /// File::read on a file opened with File::open to append each chunk to a `Vec`.
     ///
     /// ### Why is this bad?
     /// `fs::{read, read_to_string}` provide the same functionality when `buf` is empty with fewer imports and no intermediate values.
+    /// They also pre-size the buffer based on the size of the file.
     /// See also: [fs::read docs](https://doc.rust-lang.org/std/fs/fn.read.html), [fs::read_to_string docs](https://doc.rust-lang.org/std/fs/fn.read_to_string.html)
     ///
     /// ### Example
@@ -3145,7 +3154,7 @@
     #[clippy::version = "1.44.0"]
     pub VERBOSE_FILE_READS,
     restriction,
-    "use of `File::read_to_end` or `File::read_to_string`"
+    "use of `File::read_to_end`, `File::read_to_string` or a `File::read` loop"
 }
 
 declare_clippy_lint! {
@@ -3354,7 +3363,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calls to `.drain()` that clear the collection, immediately followed by a call to `.collect()`.
+    /// Checks for calls to `.drain()` that clear the collection, immediately followed by a call to `.collect()`,
+    /// and for calls to `.split_off(0)` on a collection that is not used afterwards.
     ///
     /// > "Collection" in this context refers to any type with a `drain` method:
     /// > `Vec`, `VecDeque`, `BinaryHeap`, `HashSet`,`HashMap`, `String`
@@ -3364,6 +3374,10 @@
     /// When using `mem::take`, the old collection is replaced with an empty one and ownership of
     /// the old collection is returned.
     ///
+    /// If a local `Vec`, `VecDeque` or `String` is used again after being drained, `split_off(0)`
+    /// is suggested instead, which keeps the capacity on the original without moving the
+    /// elements one by one.
+    ///
     /// ### Known issues
     /// `mem::take(&mut vec)` is almost equivalent to `vec.drain(..).collect()`, except that
     /// it also moves the **capacity**. The user might have explicitly written it this way
@@ -4044,6 +4058,57 @@
     "calling `.get().is_some()` or `.get().is_none()` instead of `.contains()` or `.contains_key()`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for sorting slices of floats with a comparator that calls `partial_cmp`
+    /// and unwraps the result, e.g. `v.sort_by(|a, b| a.partial_cmp(b).unwrap())`.
+    ///
+    /// ### Why is this bad?
+    /// `partial_cmp` returns `None` if either value is NaN, so the sort panics as soon as
+    /// a NaN ends up in the slice. `f32::total_cmp` and `f64::total_cmp` define a total
+    /// order over all values, including NaN, and never panic.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![1.0_f64, 0.5, 2.0];
+    /// v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![1.0_f64, 0.5, 2.0];
+    /// v.sort_by(|a, b| a.total_cmp(b));
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub FLOAT_SORT_BY_PARTIAL_CMP,
+    suspicious,
+    "sorting floats with `partial_cmp(..).unwrap()`, which panics on NaN"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for collections extended with a single element, e.g. with
+    /// `v.extend(std::iter::once(x))` or `v.extend([x])`.
+    ///
+    /// ### Why is this bad?
+    /// Adding the element directly with `push`, `push_back`, `push_str` or `insert`,
+    /// depending on the collection, is simpler and doesn't go through an iterator.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// v.extend(std::iter::once(3));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// v.push(3);
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub EXTEND_SINGLE_ELEMENT,
+    complexity,
+    "extending a collection with a single element instead of adding it directly"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4205,6 +4270,8 @@ pub fn new(
     UNNECESSARY_RESULT_MAP_OR_ELSE,
     MANUAL_C_STR_LITERALS,
     UNNECESSARY_GET_THEN_CHECK,
+    FLOAT_SORT_BY_PARTIAL_CMP,
+    EXTEND_SINGLE_ELEMENT,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4233,6 +4300,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 from_iter_instead_of_collect::check(cx, expr, args, func);
                 unnecessary_fallible_conversions::check_function(cx, expr, func);
                 manual_c_str_literals::check(cx, expr, func, args, &self.msrv);
+                unnecessary_to_owned::check_string_from(cx, expr, func, args);
             },
             hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
@@ -4464,6 +4532,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
                             iter_cloned_collect::check(cx, name, expr, recv2);
                         },
+                        Some(("chars", recv2, [], _, _)) => {
+                            iter_cloned_collect::check_chars(cx, expr, recv2);
+                        },
                         Some(("map", m_recv, [m_arg], m_ident_span, _)) => {
                             map_collect_result_unit::check(cx, expr, m_recv, m_arg);
                             format_collect::check(cx, expr, m_arg, m_ident_span);
@@ -4486,7 +4557,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
                         iter_count::check(cx, expr, recv2, name2);
                     },
-                    Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
+                    Some(("map", recv2, [arg], _, _)) => suspicious_map::check(cx, expr, recv, recv2, arg),
                     Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
                     Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
                     _ => {},
@@ -4538,6 +4609,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("extend", [arg]) => {
                     string_extend_chars::check(cx, expr, recv, arg);
                     extend_with_drain::check(cx, expr, recv, arg);
+                    extend_single_element::check(cx, expr, recv, arg, call_span);
                 },
                 ("filter", [arg]) => {
                     if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
@@ -4739,6 +4811,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
                 },
+                ("read", [_]) => {
+                    verbose_file_reads::check_read_loop(cx, expr, recv);
+                },
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
                 },
@@ -4793,13 +4868,16 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("sort_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    float_sort_by_partial_cmp::check(cx, expr, arg, &self.msrv);
                 },
                 ("sort_unstable_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    float_sort_by_partial_cmp::check(cx, expr, arg, &self.msrv);
                 },
                 ("split", [arg]) => {
                     str_split::check(cx, expr, recv, arg);
                 },
+                ("split_off", [arg]) => drain_collect::check_split_off(cx, expr, recv, arg),
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some(Constant::Int(count)) = constant(cx, cx.typeck_results(), count_arg) {
                         suspicious_splitn::check(cx, name, expr, recv, count);
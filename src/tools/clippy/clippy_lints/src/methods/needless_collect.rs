@@ -4,8 +4,8 @@
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{is_type_diagnostic_item, make_normalized_projection, make_projection};
 use clippy_utils::{
-    can_move_expr_to_closure, fn_def_id, get_enclosing_block, higher, is_trait_method, path_to_local, path_to_local_id,
-    CaptureKind,
+    can_move_expr_to_closure, fn_def_id, get_enclosing_block, higher, is_integer_literal, is_trait_method,
+    path_to_local, path_to_local_id, CaptureKind,
 };
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, MultiSpan};
@@ -32,6 +32,24 @@ pub(super) fn check<'tcx>(
         Node::Expr(parent) => {
             check_collect_into_intoiterator(cx, parent, collect_expr, call_span, iter_expr);
 
+            // `iter.collect::<Vec<_>>()[0]`
+            if let ExprKind::Index(base, index, _) = parent.kind
+                && base.hir_id == collect_expr.hir_id
+                && is_integer_literal(index, 0)
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(collect_expr), sym::Vec)
+            {
+                span_lint_and_sugg(
+                    cx,
+                    NEEDLESS_COLLECT,
+                    call_span.with_hi(parent.span.hi()),
+                    NEEDLESS_COLLECT_MSG,
+                    "replace with",
+                    "next().unwrap()".into(),
+                    Applicability::MaybeIncorrect,
+                );
+                return;
+            }
+
             if let ExprKind::MethodCall(name, _, args @ ([] | [_]), _) = parent.kind {
                 let mut app = Applicability::MachineApplicable;
                 let name = name.ident.as_str();
@@ -56,6 +74,11 @@ pub(super) fn check<'tcx>(
                     {
                         "next().is_none()".into()
                     },
+                    // `first` returns a reference to the element, `next` returns the element itself
+                    "first" if args.is_empty() && is_type_diagnostic_item(cx, collect_ty, sym::Vec) => {
+                        app = Applicability::MaybeIncorrect;
+                        "next()".into()
+                    },
                     "contains" => {
                         if is_contains_sig(cx, parent.hir_id, iter_expr)
                             && let Some(arg) = args.first()
@@ -254,6 +277,7 @@ fn get_iter_method(&self, cx: &LateContext<'_>) -> String {
             IterFunctionKind::IntoIter => String::new(),
             IterFunctionKind::Len => String::from(".count()"),
             IterFunctionKind::IsEmpty => String::from(".next().is_none()"),
+            IterFunctionKind::First => String::from(".next()"),
             IterFunctionKind::Contains(span) => {
                 let s = snippet(cx, *span, "..");
                 if let Some(stripped) = s.strip_prefix('&') {
@@ -275,6 +299,7 @@ fn get_suggestion_text(&self) -> &'static str {
             IterFunctionKind::IsEmpty => {
                 "check if the original Iterator has anything instead of collecting it and seeing if it's empty"
             },
+            IterFunctionKind::First => "take the original Iterator's first item instead of collecting it",
             IterFunctionKind::Contains(_) => {
                 "check if the original Iterator contains an element instead of collecting then checking"
             },
@@ -285,6 +310,7 @@ enum IterFunctionKind {
     IntoIter,
     Len,
     IsEmpty,
+    First,
     Contains(Span),
 }
 
@@ -349,6 +375,10 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
                             func: IterFunctionKind::IsEmpty,
                             span: expr.span,
                         })),
+                        "first" => self.uses.push(Some(IterFunction {
+                            func: IterFunctionKind::First,
+                            span: expr.span,
+                        })),
                         "contains" => self.uses.push(Some(IterFunction {
                             func: IterFunctionKind::Contains(args[0].span),
                             span: expr.span,
@@ -1,13 +1,22 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::usage::mutated_variables;
 use clippy_utils::{expr_or_init, is_trait_method};
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
 use super::SUSPICIOUS_MAP;
 
-pub fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, count_recv: &hir::Expr<'_>, map_arg: &hir::Expr<'_>) {
+pub fn check(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    count_recv: &hir::Expr<'_>,
+    map_recv: &hir::Expr<'_>,
+    map_arg: &hir::Expr<'_>,
+) {
     if is_trait_method(cx, count_recv, sym::Iterator)
         && let hir::ExprKind::Closure(closure) = expr_or_init(cx, map_arg).kind
         && let closure_body = cx.tcx.hir().body(closure.body)
@@ -19,13 +28,21 @@ pub fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, count_recv: &hir::Expr<
                 return;
             }
         }
-        span_lint_and_help(
+        span_lint_and_then(
             cx,
             SUSPICIOUS_MAP,
             expr.span,
             "this call to `map()` won't have an effect on the call to `count()`",
-            None,
-            "make sure you did not confuse `map` with `filter`, `for_each` or `inspect`",
+            |diag| {
+                // The closure can't panic or otherwise have side effects, so removing it doesn't change
+                // the result
+                if switch_to_eager_eval(cx, closure_body.value) {
+                    let mut app = Applicability::MachineApplicable;
+                    let recv = snippet_with_applicability(cx, map_recv.span, "..", &mut app);
+                    diag.span_suggestion(expr.span, "remove the call to `map()`", format!("{recv}.count()"), app);
+                }
+                diag.help("make sure you did not confuse `map` with `filter`, `for_each` or `inspect`");
+            },
         );
     }
 }
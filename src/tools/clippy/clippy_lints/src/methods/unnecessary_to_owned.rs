@@ -7,7 +7,8 @@
     get_iterator_item_ty, implements_trait, is_copy, is_type_diagnostic_item, is_type_lang_item, peel_mid_ty_refs,
 };
 use clippy_utils::visitors::find_all_ret_expressions;
-use clippy_utils::{fn_def_id, get_parent_expr, is_diag_item_method, is_diag_trait_item, return_ty};
+use clippy_utils::{fn_def_id, get_parent_expr, is_diag_item_method, is_diag_trait_item, path_def_id, return_ty};
+use rustc_ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::DefId;
@@ -61,6 +62,45 @@ pub fn check<'tcx>(
     }
 }
 
+/// Checks for `String::from(s)` with `s: &str` passed where `s` could be passed directly, i.e. as
+/// `&String::from(s)` to a `&str` parameter or as an argument satisfying an `AsRef<str>` or
+/// `Deref<Target = str>` bound.
+pub fn check_string_from<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    func: &Expr<'_>,
+    args: &'tcx [Expr<'_>],
+) {
+    if let [arg] = args
+        && let Some(fn_id) = path_def_id(cx, func)
+        && is_diag_trait_item(cx, fn_id, sym::From)
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), LangItem::String)
+        && let ty::Ref(_, arg_ty, Mutability::Not) = cx.typeck_results().expr_ty(arg).kind()
+        && arg_ty.is_str()
+        // `&String::from("")` is linted by `unnecessary_owned_empty_strings`
+        && !matches!(arg.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(s, _) if s.as_str().is_empty()))
+    {
+        if let Some(parent) = get_parent_expr(cx, expr)
+            && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) = parent.kind
+            && let ty::Ref(_, target_ty, _) = cx.typeck_results().expr_ty_adjusted(parent).kind()
+            && target_ty.is_str()
+            && let Some(arg_snippet) = snippet_opt(cx, arg.span)
+        {
+            span_lint_and_sugg(
+                cx,
+                UNNECESSARY_TO_OWNED,
+                parent.span,
+                "unnecessary use of `String::from`",
+                "use",
+                arg_snippet,
+                Applicability::MachineApplicable,
+            );
+        } else {
+            check_other_call_arg(cx, expr, Symbol::intern("String::from"), arg);
+        }
+    }
+}
+
 /// Checks whether `expr` is a referent in an `AddrOf` expression and, if so, determines whether its
 /// call of a `to_owned`-like function is unnecessary.
 #[allow(clippy::too_many_lines)]
@@ -1,7 +1,13 @@
-use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::is_trait_method;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
-use rustc_hir::{Expr, ExprKind, QPath};
+use clippy_utils::visitors::{for_each_expr, local_used_once};
+use clippy_utils::{
+    get_enclosing_block, get_enclosing_loop_or_multi_call_closure, is_diag_item_method_named, is_trait_method,
+    path_def_id, path_to_local,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId, MatchSource, Node, PatKind, QPath};
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
@@ -12,6 +18,10 @@
     "use of `File::read_to_string`",
     "consider using `fs::read_to_string` instead",
 );
+pub(super) const READ_LOOP_MSG: (&str, &str) = (
+    "use of `File::read` in a loop to read the whole file",
+    "consider using `fs::read` instead",
+);
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -23,6 +33,80 @@ pub(super) fn check<'tcx>(
         && matches!(recv.kind, ExprKind::Path(QPath::Resolved(None, _)))
         && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty_adjusted(recv).peel_refs(), sym::File)
     {
-        span_lint_and_help(cx, VERBOSE_FILE_READS, expr.span, msg, None, help);
+        emit(cx, expr, recv, msg, help);
+    }
+}
+
+/// Checks for `file.read(&mut chunk)` in a loop appending each chunk to a `Vec`, on a `File` opened
+/// with `File::open` and not used otherwise.
+pub(super) fn check_read_loop<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
+    if !expr.span.from_expansion()
+        && is_trait_method(cx, expr, sym::IoRead)
+        && let Some(file_id) = path_to_local(recv)
+        && opened_once(cx, file_id).is_some()
+        && let Some(loop_expr) = get_enclosing_loop_or_multi_call_closure(cx, expr)
+        && matches!(loop_expr.kind, ExprKind::Loop(..))
+        && extends_vec_from_slice(cx, loop_expr)
+    {
+        let (msg, help) = READ_LOOP_MSG;
+        emit(cx, loop_expr, recv, msg, help);
+    }
+}
+
+fn emit(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, msg: &str, help: &str) {
+    span_lint_and_then(cx, VERBOSE_FILE_READS, expr.span, msg, |diag| {
+        // Point at `File::open(path)` if the file is not used for anything else
+        if let Some(file_id) = path_to_local(recv)
+            && let Some((open, path)) = opened_once(cx, file_id)
+        {
+            diag.span_note(open.span, "the file is opened here and not used otherwise");
+            diag.help(format!("{help}, passing `{}`", snippet(cx, path.span, "..")));
+        } else {
+            diag.help(help);
+        }
+    });
+}
+
+/// If the local is bound by `let` to `File::open(path)`, optionally followed by `?`, `unwrap()` or
+/// `expect(..)`, and used only once, returns the initializer and the path argument.
+fn opened_once<'tcx>(cx: &LateContext<'tcx>, id: HirId) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let Node::Pat(pat) = cx.tcx.hir_node(id)
+        && let PatKind::Binding(..) = pat.kind
+        && let Node::Local(local) = cx.tcx.parent_hir_node(id)
+        && let Some(init) = local.init
+        && let ExprKind::Call(func, [path]) = peel_unwrap_or_try(init).kind
+        && path_def_id(cx, func).is_some_and(|id| is_diag_item_method_named(cx, id, sym::File, "open"))
+        && let Some(block) = get_enclosing_block(cx, id)
+        && local_used_once(cx, block, id).is_some()
+    {
+        Some((init, path))
+    } else {
+        None
+    }
+}
+
+fn peel_unwrap_or_try<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match expr.kind {
+        ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_))
+            if let ExprKind::Call(_, [inner]) = scrutinee.kind =>
+        {
+            inner
+        },
+        ExprKind::MethodCall(path, recv, _, _) if matches!(path.ident.name, sym::unwrap | sym::expect) => recv,
+        _ => expr,
     }
 }
+
+fn extends_vec_from_slice<'tcx>(cx: &LateContext<'tcx>, loop_expr: &'tcx Expr<'tcx>) -> bool {
+    for_each_expr(loop_expr, |e| {
+        if let ExprKind::MethodCall(path, recv, [_], _) = e.kind
+            && path.ident.as_str() == "extend_from_slice"
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Vec)
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
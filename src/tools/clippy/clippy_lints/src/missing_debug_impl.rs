@@ -0,0 +1,108 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::{LocalDefId, LOCAL_CRATE};
+use rustc_hir::ItemKind;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::config::CrateType;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported structs, enums and unions in library crates that neither implement
+    /// nor derive `Debug`.
+    ///
+    /// Unlike rustc's `missing_debug_implementations` lint, which can only be silenced with an
+    /// `#[allow]` on each type, types can be excluded with the `missing-debug-impl-ignore-types`
+    /// configuration. This also works for types generated by macros or build scripts, e.g. FFI
+    /// bindings, that can't be annotated.
+    ///
+    /// ### Why is this bad?
+    /// Users of a library can't derive `Debug` for their own types containing such a type, nor
+    /// print it while debugging.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub struct Config {
+    ///     pub verbose: bool,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub struct Config {
+    ///     pub verbose: bool,
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub MISSING_DEBUG_IMPL,
+    restriction,
+    "exported type in a library crate without a `Debug` implementation"
+}
+
+pub struct MissingDebugImpl {
+    ignore_types: FxHashSet<String>,
+}
+
+impl MissingDebugImpl {
+    pub fn new(ignore_types: FxHashSet<String>) -> Self {
+        Self { ignore_types }
+    }
+}
+
+impl_lint_pass!(MissingDebugImpl => [MISSING_DEBUG_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingDebugImpl {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        if cx
+            .tcx
+            .crate_types()
+            .iter()
+            .any(|t| matches!(t, CrateType::Executable | CrateType::ProcMacro))
+        {
+            return;
+        }
+        let Some(debug_id) = cx.tcx.get_diagnostic_item(sym::Debug) else {
+            return;
+        };
+
+        // Collect the local types `Debug` is implemented for, including derives
+        let mut impls: FxHashSet<LocalDefId> = FxHashSet::default();
+        cx.tcx.for_each_impl(debug_id, |impl_id| {
+            if let Some(adt) = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()
+                && let Some(local_id) = adt.did().as_local()
+            {
+                impls.insert(local_id);
+            }
+        });
+
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        for id in cx.tcx.hir().items() {
+            let item = cx.tcx.hir().item(id);
+            let def_id = item.owner_id.def_id;
+            if matches!(
+                item.kind,
+                ItemKind::Struct(..) | ItemKind::Enum(..) | ItemKind::Union(..)
+            ) && cx.effective_visibilities.is_exported(def_id)
+                && !impls.contains(&def_id)
+                && !in_external_macro(cx.sess(), item.span)
+                && !self.ignore_types.contains(item.ident.as_str())
+                && !self
+                    .ignore_types
+                    .contains(&format!("{crate_name}::{}", cx.tcx.def_path_str(def_id)))
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    MISSING_DEBUG_IMPL,
+                    item.hir_id(),
+                    item.ident.span,
+                    &format!("exported type `{}` does not implement `Debug`", item.ident),
+                    |diag| {
+                        diag.help("consider adding `#[derive(Debug)]` or a manual implementation");
+                    },
+                );
+            }
+        }
+    }
+}
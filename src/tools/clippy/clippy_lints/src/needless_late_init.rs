@@ -158,7 +158,7 @@ fn assignment_suggestions<'tcx>(
     cx: &LateContext<'tcx>,
     binding_id: HirId,
     exprs: impl IntoIterator<Item = &'tcx Expr<'tcx>>,
-) -> Option<(Applicability, Vec<(Span, String)>)> {
+) -> Option<Vec<(Span, String)>> {
     let mut assignments = Vec::new();
 
     for expr in exprs {
@@ -190,15 +190,9 @@ fn assignment_suggestions<'tcx>(
         .map(|span| (span, String::new()))
         .collect::<Vec<(Span, String)>>();
 
-    match suggestions.len() {
-        // All of `exprs` are never types
-        // https://github.com/rust-lang/rust-clippy/issues/8911
-        0 => None,
-        1 => Some((Applicability::MachineApplicable, suggestions)),
-        // multiple suggestions don't work with rustfix in multipart_suggest
-        // https://github.com/rust-lang/rustfix/issues/141
-        _ => Some((Applicability::Unspecified, suggestions)),
-    }
+    // All of `exprs` are never types
+    // https://github.com/rust-lang/rust-clippy/issues/8911
+    (!suggestions.is_empty()).then_some(suggestions)
 }
 
 struct Usage<'tcx> {
@@ -250,6 +244,34 @@ fn local_snippet_without_semicolon(cx: &LateContext<'_>, local: &Local<'_>) -> O
     snippet_opt(cx, span)
 }
 
+/// Lints a late initialization by an `if` or `match` expression, turning it into the initializer
+/// of the `let` statement.
+fn lint_expr_init(
+    cx: &LateContext<'_>,
+    local_stmt: &Stmt<'_>,
+    usage: &Usage<'_>,
+    let_snippet: &str,
+    mut suggestions: Vec<(Span, String)>,
+    msg: impl FnOnce() -> String,
+) {
+    span_lint_and_then(
+        cx,
+        NEEDLESS_LATE_INIT,
+        local_stmt.span,
+        "unneeded late initialization",
+        |diag| {
+            suggestions.push((local_stmt.span, String::new()));
+            suggestions.push((usage.stmt.span.shrink_to_lo(), format!("{let_snippet} = ")));
+
+            if usage.needs_semi {
+                suggestions.push((usage.stmt.span.shrink_to_hi(), ";".to_owned()));
+            }
+
+            diag.multipart_suggestion(msg(), suggestions, Applicability::MachineApplicable);
+        },
+    );
+}
+
 fn check<'tcx>(
     cx: &LateContext<'tcx>,
     local: &'tcx Local<'tcx>,
@@ -274,87 +296,25 @@ fn check<'tcx>(
                 msg_span,
                 "unneeded late initialization",
                 |diag| {
-                    diag.tool_only_span_suggestion(
-                        local_stmt.span,
-                        "remove the local",
-                        "",
-                        Applicability::MachineApplicable,
-                    );
-
-                    diag.span_suggestion(
-                        assign.lhs_span,
-                        format!("declare `{binding_name}` here"),
-                        let_snippet,
+                    diag.multipart_suggestion(
+                        format!("move the declaration `{binding_name}` here"),
+                        vec![(local_stmt.span, String::new()), (assign.lhs_span, let_snippet)],
                         Applicability::MachineApplicable,
                     );
                 },
             );
         },
         ExprKind::If(cond, then_expr, Some(else_expr)) if !contains_let(cond) => {
-            let (applicability, suggestions) = assignment_suggestions(cx, binding_id, [then_expr, else_expr])?;
-
-            span_lint_and_then(
-                cx,
-                NEEDLESS_LATE_INIT,
-                local_stmt.span,
-                "unneeded late initialization",
-                |diag| {
-                    diag.tool_only_span_suggestion(local_stmt.span, "remove the local", String::new(), applicability);
-
-                    diag.span_suggestion_verbose(
-                        usage.stmt.span.shrink_to_lo(),
-                        format!("declare `{binding_name}` here"),
-                        format!("{let_snippet} = "),
-                        applicability,
-                    );
-
-                    diag.multipart_suggestion("remove the assignments from the branches", suggestions, applicability);
-
-                    if usage.needs_semi {
-                        diag.span_suggestion(
-                            usage.stmt.span.shrink_to_hi(),
-                            "add a semicolon after the `if` expression",
-                            ";",
-                            applicability,
-                        );
-                    }
-                },
-            );
+            let suggestions = assignment_suggestions(cx, binding_id, [then_expr, else_expr])?;
+            lint_expr_init(cx, local_stmt, &usage, &let_snippet, suggestions, || {
+                format!("move the declaration `{binding_name}` here and remove the assignments from the branches")
+            });
         },
         ExprKind::Match(_, arms, MatchSource::Normal) => {
-            let (applicability, suggestions) = assignment_suggestions(cx, binding_id, arms.iter().map(|arm| arm.body))?;
-
-            span_lint_and_then(
-                cx,
-                NEEDLESS_LATE_INIT,
-                local_stmt.span,
-                "unneeded late initialization",
-                |diag| {
-                    diag.tool_only_span_suggestion(local_stmt.span, "remove the local", String::new(), applicability);
-
-                    diag.span_suggestion_verbose(
-                        usage.stmt.span.shrink_to_lo(),
-                        format!("declare `{binding_name}` here"),
-                        format!("{let_snippet} = "),
-                        applicability,
-                    );
-
-                    diag.multipart_suggestion(
-                        "remove the assignments from the `match` arms",
-                        suggestions,
-                        applicability,
-                    );
-
-                    if usage.needs_semi {
-                        diag.span_suggestion(
-                            usage.stmt.span.shrink_to_hi(),
-                            "add a semicolon after the `match` expression",
-                            ";",
-                            applicability,
-                        );
-                    }
-                },
-            );
+            let suggestions = assignment_suggestions(cx, binding_id, arms.iter().map(|arm| arm.body))?;
+            lint_expr_init(cx, local_stmt, &usage, &let_snippet, suggestions, || {
+                format!("move the declaration `{binding_name}` here and remove the assignments from the `match` arms")
+            });
         },
         _ => {},
     };
@@ -5,19 +5,16 @@
 use clippy_utils::ty::{
     implements_trait, implements_trait_with_env_from_iter, is_copy, is_type_diagnostic_item, is_type_lang_item,
 };
+use clippy_utils::usage::moved_variables;
 use rustc_ast::ast::Attribute;
 use rustc_errors::{Applicability, Diag};
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
-    BindingAnnotation, Body, FnDecl, GenericArg, HirId, HirIdSet, Impl, ItemKind, LangItem, Mutability, Node, PatKind,
-    QPath, TyKind,
+    BindingAnnotation, Body, FnDecl, GenericArg, Impl, ItemKind, LangItem, Mutability, Node, PatKind, QPath, TyKind,
 };
-use rustc_hir_typeck::expr_use_visitor as euv;
-use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::mir::FakeReadCause;
 use rustc_middle::ty::{self, Ty, TypeVisitableExt};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::symbol::kw;
 use rustc_span::{sym, Span};
@@ -59,7 +56,19 @@
     "functions taking arguments by value, but not consuming them in its body"
 }
 
-declare_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
+pub struct NeedlessPassByValue {
+    avoid_breaking_exported_api: bool,
+}
+
+impl NeedlessPassByValue {
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
 
 macro_rules! need {
     ($e: expr) => {
@@ -99,6 +108,11 @@ fn check_fn(
             FnKind::Closure => return,
         }
 
+        // Taking the argument by reference would break the callers in other crates
+        if self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(fn_def_id) {
+            return;
+        }
+
         // Exclude non-inherent impls
         if let Node::Item(item) = cx.tcx.parent_hir_node(hir_id) {
             if matches!(
@@ -132,12 +146,7 @@ fn check_fn(
 
         // Collect moved variables and spans which will need dereferencings from the
         // function body.
-        let MovedVariablesCtxt { moved_vars } = {
-            let mut ctx = MovedVariablesCtxt::default();
-            let infcx = cx.tcx.infer_ctxt().build();
-            euv::ExprUseVisitor::new(&mut ctx, &infcx, fn_def_id, cx.param_env, cx.typeck_results()).consume_body(body);
-            ctx
-        };
+        let moved_vars = moved_variables(cx, fn_def_id, body);
 
         let fn_sig = cx.tcx.fn_sig(fn_def_id).instantiate_identity();
         let fn_sig = cx.tcx.liberate_late_bound_regions(fn_def_id.to_def_id(), fn_sig);
@@ -303,28 +312,3 @@ pub(crate) fn requires_exact_signature(attrs: &[Attribute]) -> bool {
             .any(|&allow| attr.has_name(allow))
     })
 }
-
-#[derive(Default)]
-struct MovedVariablesCtxt {
-    moved_vars: HirIdSet,
-}
-
-impl MovedVariablesCtxt {
-    fn move_common(&mut self, cmt: &euv::PlaceWithHirId<'_>) {
-        if let euv::PlaceBase::Local(vid) = cmt.place.base {
-            self.moved_vars.insert(vid);
-        }
-    }
-}
-
-impl<'tcx> euv::Delegate<'tcx> for MovedVariablesCtxt {
-    fn consume(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId) {
-        self.move_common(cmt);
-    }
-
-    fn borrow(&mut self, _: &euv::PlaceWithHirId<'tcx>, _: HirId, _: ty::BorrowKind) {}
-
-    fn mutate(&mut self, _: &euv::PlaceWithHirId<'tcx>, _: HirId) {}
-
-    fn fake_read(&mut self, _: &rustc_hir_typeck::expr_use_visitor::PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
-}
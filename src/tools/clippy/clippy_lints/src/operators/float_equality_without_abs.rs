@@ -1,5 +1,6 @@
+use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{match_def_path, paths, sugg};
+use clippy_utils::{expr_or_init, match_def_path, paths, sugg};
 use rustc_ast::util::parser::AssocOp;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
@@ -34,9 +35,7 @@ pub(crate) fn check<'tcx>(
         ) = lhs.kind
 
         // right hand side matches either f32::EPSILON or f64::EPSILON
-        && let ExprKind::Path(ref epsilon_path) = rhs.kind
-        && let Res::Def(DefKind::AssocConst, def_id) = cx.qpath_res(epsilon_path, rhs.hir_id)
-        && (match_def_path(cx, def_id, &paths::F32_EPSILON) || match_def_path(cx, def_id, &paths::F64_EPSILON))
+        && is_epsilon(cx, rhs)
 
         // values of the subtractions on the left hand side are of the type float
         && let t_val_l = cx.typeck_results().expr_ty(val_l)
@@ -63,3 +62,17 @@ pub(crate) fn check<'tcx>(
         );
     }
 }
+
+/// Checks if `expr` is `f32::EPSILON` or `f64::EPSILON`, either directly or through a constant or
+/// an immutable local initialized with it, e.g. `let error_margin = f64::EPSILON;`.
+fn is_epsilon(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let expr = expr_or_init(cx, expr);
+    if let ExprKind::Path(ref epsilon_path) = expr.kind
+        && let Res::Def(DefKind::AssocConst, def_id) = cx.qpath_res(epsilon_path, expr.hir_id)
+        && (match_def_path(cx, def_id, &paths::F32_EPSILON) || match_def_path(cx, def_id, &paths::F64_EPSILON))
+    {
+        return true;
+    }
+    constant(cx, cx.typeck_results(), expr)
+        .is_some_and(|value| value == Constant::F32(f32::EPSILON) || value == Constant::F64(f64::EPSILON))
+}
@@ -449,7 +449,9 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for statements of the form `(a - b) < f32::EPSILON` or
-    /// `(a - b) < f64::EPSILON`. Notes the missing `.abs()`.
+    /// `(a - b) < f64::EPSILON`. Notes the missing `.abs()`. The epsilon may also
+    /// be given through a constant or a local, e.g. `a - b < error_margin` with
+    /// `let error_margin = f64::EPSILON;`.
     ///
     /// ### Why is this bad?
     /// The code without `.abs()` is more likely to have a bug.
@@ -731,7 +733,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for explicit self-assignments.
+    /// Checks for explicit self-assignments. Assignments expanded from macros, which may be
+    /// deliberate, are ignored.
     ///
     /// ### Why is this bad?
     /// Self-assignments are redundant and unlikely to be
@@ -1,16 +1,18 @@
 use std::{cmp, iter};
 
+use crate::needless_pass_by_value::NEEDLESS_PASS_BY_VALUE;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::{for_each_top_level_late_bound_region, is_copy};
-use clippy_utils::{is_self, is_self_ty};
+use clippy_utils::usage::moved_variables;
+use clippy_utils::{is_lint_allowed, is_self, is_self_ty};
 use core::ops::ControlFlow;
 use rustc_ast::attr;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::intravisit::FnKind;
-use rustc_hir::{BindingAnnotation, Body, FnDecl, Impl, ItemKind, MutTy, Mutability, Node, PatKind};
+use rustc_hir::{BindingAnnotation, Body, FnDecl, HirIdSet, Impl, ItemKind, MutTy, Mutability, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::adjustment::{Adjust, PointerCoercion};
 use rustc_middle::ty::layout::LayoutOf;
@@ -73,11 +75,15 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for functions taking arguments by value, where
-    /// the argument type is `Copy` and large enough to be worth considering
+    /// the argument type is large enough to be worth considering
     /// passing by reference. Does not trigger if the function is being exported,
     /// because that might induce API breakage, if the parameter is declared as mutable,
     /// or if the argument is a `self`.
     ///
+    /// Arguments whose type isn't `Copy` are only linted if the function never moves
+    /// out of them and `needless_pass_by_value` is allowed, since it reports them otherwise.
+    /// The size limit can be set with the `pass-by-value-size-limit` configuration.
+    ///
     /// ### Why is this bad?
     /// Arguments passed by value might result in an unnecessary
     /// shallow copy, taking up more space in the stack and requiring a call to
@@ -143,6 +149,9 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
         let fn_sig = cx.tcx.fn_sig(def_id).instantiate_identity();
         let fn_body = cx.enclosing_body.map(|id| cx.tcx.hir().body(id));
 
+        // The variables moved in the body, computed when first needed.
+        let mut moved_vars = None;
+
         // Gather all the lifetimes found in the output type which may affect whether
         // `TRIVIALLY_COPY_PASS_BY_REF` should be linted.
         let mut output_regions = FxHashSet::default();
@@ -219,7 +228,8 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
 
                 ty::Adt(_, _) | ty::Array(_, _) | ty::Tuple(_) => {
                     // if function has a body and parameter is annotated with mut, ignore
-                    if let Some(param) = fn_body.and_then(|body| body.params.get(index)) {
+                    let param = fn_body.and_then(|body| body.params.get(index));
+                    if let Some(param) = param {
                         match param.pat.kind {
                             PatKind::Binding(BindingAnnotation::NONE, _, _, _) => {},
                             _ => continue,
@@ -227,10 +237,10 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
                     }
                     let ty = cx.tcx.instantiate_bound_regions_with_erased(ty);
 
-                    if is_copy(cx, ty)
-                        && !is_self_ty(input)
+                    if !is_self_ty(input)
                         && let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes())
                         && size > self.value_max_size
+                        && (is_copy(cx, ty) || is_unconsumed_param(cx, def_id, fn_body, param, &mut moved_vars))
                     {
                         span_lint_and_sugg(
                             cx,
@@ -253,6 +263,24 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
     }
 }
 
+/// Checks if a parameter of a type which isn't `Copy` is never moved out of in the body of the
+/// function, and isn't already linted by `needless_pass_by_value`.
+fn is_unconsumed_param<'tcx>(
+    cx: &LateContext<'tcx>,
+    def_id: LocalDefId,
+    body: Option<&'tcx Body<'tcx>>,
+    param: Option<&hir::Param<'_>>,
+    moved_vars: &mut Option<HirIdSet>,
+) -> bool {
+    let (Some(body), Some(param)) = (body, param) else {
+        return false;
+    };
+    is_lint_allowed(cx, NEEDLESS_PASS_BY_VALUE, param.hir_id)
+        && !moved_vars
+            .get_or_insert_with(|| moved_variables(cx, def_id, body))
+            .contains(&param.pat.hir_id)
+}
+
 impl_lint_pass!(PassByRefOrValue => [TRIVIALLY_COPY_PASS_BY_REF, LARGE_TYPES_PASSED_BY_VALUE]);
 
 impl<'tcx> LateLintPass<'tcx> for PassByRefOrValue {
@@ -1,9 +1,14 @@
-use clippy_utils::diagnostics::span_lint_and_help;
-use rustc_hir::{intravisit, Body, Expr, ExprKind, FnDecl, Let, LocalSource, Mutability, Pat, PatKind, Stmt, StmtKind};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    intravisit, BindingAnnotation, Body, Expr, ExprKind, FnDecl, Let, LocalSource, Mutability, Pat, PatKind, Stmt,
+    StmtKind,
+};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
-use rustc_session::declare_lint_pass;
+use rustc_middle::ty::binding::BindingMode;
+use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
 
@@ -72,13 +77,27 @@
     ///     *a += b;
     /// }
     /// ```
+    ///
+    /// ### Configuration
+    /// With `pattern-type-mismatch-mut-only` set, only patterns matching through `&mut`
+    /// references, and thus introducing `ref mut` bindings, are linted.
     #[clippy::version = "1.47.0"]
     pub PATTERN_TYPE_MISMATCH,
     restriction,
     "type of pattern does not match the expression type"
 }
 
-declare_lint_pass!(PatternTypeMismatch => [PATTERN_TYPE_MISMATCH]);
+pub struct PatternTypeMismatch {
+    mut_only: bool,
+}
+
+impl PatternTypeMismatch {
+    pub fn new(mut_only: bool) -> Self {
+        Self { mut_only }
+    }
+}
+
+impl_lint_pass!(PatternTypeMismatch => [PATTERN_TYPE_MISMATCH]);
 
 impl<'tcx> LateLintPass<'tcx> for PatternTypeMismatch {
     fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
@@ -90,7 +109,7 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
                 LocalSource::Normal => DerefPossible::Possible,
                 _ => DerefPossible::Impossible,
             };
-            apply_lint(cx, local.pat, deref_possible);
+            apply_lint(cx, self.mut_only, local.pat, deref_possible);
         }
     }
 
@@ -98,13 +117,13 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Match(_, arms, _) = expr.kind {
             for arm in arms {
                 let pat = &arm.pat;
-                if apply_lint(cx, pat, DerefPossible::Possible) {
+                if apply_lint(cx, self.mut_only, pat, DerefPossible::Possible) {
                     break;
                 }
             }
         }
         if let ExprKind::Let(Let { pat, .. }) = expr.kind {
-            apply_lint(cx, pat, DerefPossible::Possible);
+            apply_lint(cx, self.mut_only, pat, DerefPossible::Possible);
         }
     }
 
@@ -118,7 +137,7 @@ fn check_fn(
         _: LocalDefId,
     ) {
         for param in body.params {
-            apply_lint(cx, param.pat, DerefPossible::Impossible);
+            apply_lint(cx, self.mut_only, param.pat, DerefPossible::Impossible);
         }
     }
 }
@@ -129,26 +148,34 @@ enum DerefPossible {
     Impossible,
 }
 
-fn apply_lint(cx: &LateContext<'_>, pat: &Pat<'_>, deref_possible: DerefPossible) -> bool {
-    let maybe_mismatch = find_first_mismatch(cx, pat);
+fn apply_lint(cx: &LateContext<'_>, mut_only: bool, pat: &Pat<'_>, deref_possible: DerefPossible) -> bool {
+    let maybe_mismatch = find_first_mismatch(cx, pat, mut_only);
     if let Some((span, mutability, level)) = maybe_mismatch {
-        span_lint_and_help(
+        span_lint_and_then(
             cx,
             PATTERN_TYPE_MISMATCH,
             span,
             "type of pattern does not match the expression type",
-            None,
-            &format!(
-                "{}explicitly match against a `{}` pattern and adjust the enclosed variable bindings",
-                match (deref_possible, level) {
-                    (DerefPossible::Possible, Level::Top) => "use `*` to dereference the match expression or ",
-                    _ => "",
-                },
-                match mutability {
-                    Mutability::Mut => "&mut _",
-                    Mutability::Not => "&_",
-                },
-            ),
+            |diag| {
+                diag.help(format!(
+                    "{}explicitly match against a `{}` pattern and adjust the enclosed variable bindings",
+                    match (deref_possible, level) {
+                        (DerefPossible::Possible, Level::Top) => "use `*` to dereference the match expression or ",
+                        _ => "",
+                    },
+                    match mutability {
+                        Mutability::Mut => "&mut _",
+                        Mutability::Not => "&_",
+                    },
+                ));
+                if let Some(sugg) = explicit_pattern_sugg(cx, pat) {
+                    diag.multipart_suggestion(
+                        "make the reference patterns and binding modes explicit",
+                        sugg,
+                        Applicability::MachineApplicable,
+                    );
+                }
+            },
         );
         true
     } else {
@@ -162,7 +189,7 @@ enum Level {
     Lower,
 }
 
-fn find_first_mismatch(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<(Span, Mutability, Level)> {
+fn find_first_mismatch(cx: &LateContext<'_>, pat: &Pat<'_>, mut_only: bool) -> Option<(Span, Mutability, Level)> {
     let mut result = None;
     pat.walk(|p| {
         if result.is_some() {
@@ -176,6 +203,11 @@ fn find_first_mismatch(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<(Span, Mut
             _ => p,
         };
         if let Some(adjustments) = cx.typeck_results().pat_adjustments().get(adjust_pat.hir_id) {
+            // A shared reference peeled here or in a parent pattern makes the bindings `ref`, so only
+            // the bindings themselves tell whether the pattern introduces a `ref mut`
+            if mut_only && !has_implicit_ref_mut_binding(cx, p) {
+                return false;
+            }
             if let [first, ..] = **adjustments {
                 if let ty::Ref(.., mutability) = *first.kind() {
                     let level = if p.hir_id == pat.hir_id {
@@ -191,3 +223,56 @@ fn find_first_mismatch(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<(Span, Mut
     });
     result
 }
+
+/// Checks if `pat` contains a binding that is `ref mut` because of the default binding mode.
+fn has_implicit_ref_mut_binding(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    let binding_modes = cx.typeck_results().pat_binding_modes();
+    !pat.walk_short(|p| {
+        !(matches!(p.kind, PatKind::Binding(BindingAnnotation::NONE, ..))
+            && matches!(
+                binding_modes.get(p.hir_id),
+                Some(BindingMode::BindByReference(Mutability::Mut))
+            ))
+    })
+}
+
+/// Builds the insertions making the whole pattern explicit: the peeled references are matched
+/// against with `&`/`&mut` patterns and the bindings affected by the default binding mode get an
+/// explicit `ref`/`ref mut`.
+fn explicit_pattern_sugg(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<Vec<(Span, String)>> {
+    let typeck = cx.typeck_results();
+    let mut sugg = Vec::new();
+    let mut ok = true;
+    pat.walk(|p| {
+        if let Some(adjustments) = typeck.pat_adjustments().get(p.hir_id) {
+            if p.span.from_expansion() {
+                ok = false;
+            }
+            let mut prefix = String::new();
+            for ty in adjustments {
+                if let ty::Ref(.., mutability) = *ty.kind() {
+                    prefix.push_str(mutability.ref_prefix_str());
+                } else {
+                    ok = false;
+                }
+            }
+            if matches!(p.kind, PatKind::Range(..)) {
+                // `&0..=9` would not parse as a reference to a range pattern
+                sugg.push((p.span.shrink_to_lo(), format!("{prefix}(")));
+                sugg.push((p.span.shrink_to_hi(), ")".into()));
+            } else {
+                sugg.push((p.span.shrink_to_lo(), prefix));
+            }
+        }
+        if let PatKind::Binding(BindingAnnotation::NONE, _, ident, _) = p.kind
+            && let Some(&BindingMode::BindByReference(mutability)) = typeck.pat_binding_modes().get(p.hir_id)
+        {
+            if ident.span.from_expansion() {
+                ok = false;
+            }
+            sugg.push((ident.span.shrink_to_lo(), format!("ref {}", mutability.prefix_str())));
+        }
+        ok
+    });
+    (ok && !sugg.is_empty()).then_some(sugg)
+}
@@ -1,17 +1,19 @@
+use crate::manual_is_ascii_check::{is_ascii_class_range, MANUAL_IS_ASCII_CHECK};
 use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::{snippet, snippet_opt, snippet_with_applicability};
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{get_parent_expr, higher, in_constant, is_integer_const, path_to_local};
+use clippy_utils::{get_parent_expr, higher, in_constant, is_integer_const, is_lint_allowed, path_to_local, sext};
 use rustc_ast::ast::RangeLimits;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, HirId};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::impl_lint_pass;
 use rustc_span::source_map::Spanned;
 use rustc_span::Span;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 
 declare_clippy_lint! {
@@ -137,6 +139,11 @@
     /// Checks for expressions like `x >= 3 && x < 8` that could
     /// be more readably expressed as `(3..8).contains(x)`.
     ///
+    /// An exclusive lower bound, as in `x > 2 && x < 8`, is also linted
+    /// if it is an integer literal, since it can be turned into the
+    /// inclusive bound of `(3..8)`. Comparisons that would result in an
+    /// empty range are never linted.
+    ///
     /// ### Why is this bad?
     /// `contains` expresses the intent better and has less
     /// failure modes (such as fencepost errors or using `||` instead of `&&`).
@@ -181,7 +188,7 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Binary(ref op, l, r) = expr.kind {
             if self.msrv.meets(msrvs::RANGE_CONTAINS) {
-                check_possible_range_contains(cx, op.node, l, r, expr, expr.span);
+                check_possible_range_contains(cx, op.node, l, r, expr, expr.span, &self.msrv);
             }
         }
 
@@ -199,6 +206,7 @@ fn check_possible_range_contains(
     right: &Expr<'_>,
     expr: &Expr<'_>,
     span: Span,
+    msrv: &Msrv,
 ) {
     if in_constant(cx, expr.hir_id) {
         return;
@@ -219,24 +227,34 @@ fn check_possible_range_contains(
         let ord = Constant::partial_cmp(cx.tcx, cx.typeck_results().expr_ty(l.expr), &l.val, &r.val);
         if combine_and && ord == Some(r.ord) {
             // order lower bound and upper bound
-            let (l_span, u_span, l_inc, u_inc) = if r.ord == Ordering::Less {
-                (l.val_span, r.val_span, l.inc, r.inc)
+            let (lower, upper) = if r.ord == Ordering::Less { (&l, &r) } else { (&r, &l) };
+            let (range_type, range_op) = if upper.inc {
+                ("RangeInclusive", "..=")
             } else {
-                (r.val_span, l.val_span, r.inc, l.inc)
+                ("Range", "..")
             };
-            // we only lint inclusive lower bounds
-            if !l_inc {
+            // `MANUAL_IS_ASCII_CHECK` has a better suggestion for these, leave them to it if it lints
+            if op == BinOpKind::And
+                && span == expr.span
+                && lower.inc
+                && upper.inc
+                && msrv.meets(msrvs::IS_ASCII_DIGIT)
+                && !is_lint_allowed(cx, MANUAL_IS_ASCII_CHECK, expr.hir_id)
+                && is_ascii_class_range(lower.expr, upper.expr)
+            {
                 return;
             }
-            let (range_type, range_op) = if u_inc {
-                ("RangeInclusive", "..=")
+            let mut applicability = Applicability::MachineApplicable;
+            let lo = if lower.inc {
+                snippet_with_applicability(cx, lower.val_span, "_", &mut applicability)
+            } else if let Some(lo) = exclusive_int_lower_bound(cx, lower, upper) {
+                // `x > lo` is the same as `x >= lo + 1` for integers
+                Cow::Owned(lo.to_string())
             } else {
-                ("Range", "..")
+                return;
             };
-            let mut applicability = Applicability::MachineApplicable;
             let name = snippet_with_applicability(cx, l.name_span, "_", &mut applicability);
-            let lo = snippet_with_applicability(cx, l_span, "_", &mut applicability);
-            let hi = snippet_with_applicability(cx, u_span, "_", &mut applicability);
+            let hi = snippet_with_applicability(cx, upper.val_span, "_", &mut applicability);
             let space = if lo.ends_with('.') { " " } else { "" };
             span_lint_and_sugg(
                 cx,
@@ -289,7 +307,7 @@ fn check_possible_range_contains(
         // Do not continue if we have mismatched number of parens, otherwise the suggestion is wrong
         && snip.matches('(').count() == snip.matches(')').count()
     {
-        check_possible_range_contains(cx, op, new_lhs, right, expr, new_span);
+        check_possible_range_contains(cx, op, new_lhs, right, expr, new_span, msrv);
     }
 }
 
@@ -303,6 +321,41 @@ struct RangeBounds<'a, 'tcx> {
     inc: bool,
 }
 
+/// Returns `lower + 1` if `lower` is an exclusive integer literal bound and the resulting
+/// inclusive range `lower + 1..upper` (or `..=upper`) is not empty.
+fn exclusive_int_lower_bound(
+    cx: &LateContext<'_>,
+    lower: &RangeBounds<'_, '_>,
+    upper: &RangeBounds<'_, '_>,
+) -> Option<i128> {
+    let is_lit = |e: &Expr<'_>| match e.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Unary(UnOp::Neg, e) => matches!(e.kind, ExprKind::Lit(_)),
+        _ => false,
+    };
+    if !is_lit(lower.expr) {
+        return None;
+    }
+    let lo = int_bound_value(cx, lower)?.checked_add(1)?;
+    let hi = int_bound_value(cx, upper)?;
+    if lo < hi || (upper.inc && lo == hi) {
+        Some(lo)
+    } else {
+        None
+    }
+}
+
+fn int_bound_value(cx: &LateContext<'_>, bound: &RangeBounds<'_, '_>) -> Option<i128> {
+    let Constant::Int(bits) = bound.val else {
+        return None;
+    };
+    match *cx.typeck_results().expr_ty(bound.expr).kind() {
+        ty::Int(ity) => Some(sext(cx.tcx, bits, ity)),
+        ty::Uint(_) => i128::try_from(bits).ok(),
+        _ => None,
+    }
+}
+
 // Takes a binary expression such as x <= 2 as input
 // Breaks apart into various pieces, such as the value of the number,
 // hir id of the variable, and direction/inclusiveness of the operator
@@ -1,13 +1,19 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_with_applicability};
 use rustc_ast::ast::{Block, Expr, ExprKind, Stmt, StmtKind};
 use rustc_ast::visit::{walk_expr, Visitor};
+use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::declare_lint_pass;
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::Span;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `else` blocks that can be removed without changing semantics.
+    /// Checks for `else` blocks that can be removed without changing semantics, i.e. `else` blocks
+    /// following an `if` block that always ends in `return`, `break`, `continue` or a panicking macro
+    /// such as `panic!` or `unreachable!`.
     ///
     /// ### Why is this bad?
     /// The `else` block adds unnecessary indentation and verbosity.
@@ -77,17 +83,69 @@ fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &Stmt) {
                 _ => break,
             }
         }
-        span_lint_and_help(
-            cx,
-            REDUNDANT_ELSE,
-            els.span,
-            "redundant else block",
-            None,
-            "remove the `else` block and move the contents out",
-        );
+        span_lint_and_then(cx, REDUNDANT_ELSE, els.span, "redundant else block", |diag| {
+            let ExprKind::Block(els_block, _) = &els.kind else {
+                return;
+            };
+            if els.span.from_expansion() || then.span.from_expansion() {
+                diag.help("remove the `else` block and move the contents out");
+                return;
+            }
+
+            // Moving `let` statements out of the block changes their scope
+            let mut app = if els_block
+                .stmts
+                .iter()
+                .any(|stmt| matches!(stmt.kind, StmtKind::Local(_)))
+            {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let snip = snippet_with_applicability(cx, els.span, "{ .. }", &mut app);
+            let body = snip
+                .strip_prefix('{')
+                .and_then(|snip| snip.strip_suffix('}'))
+                .unwrap_or(&snip)
+                .trim();
+            let indent = indent_of(cx, stmt.span);
+            let mut sugg = format!(
+                "\n{}{}",
+                " ".repeat(indent.unwrap_or(0)),
+                reindent_multiline(body.into(), true, indent)
+            );
+
+            // `if .. { .. } else { .. };` keeps its semicolon only if the `else` block has a tail
+            // expression
+            let mut span = then.span.shrink_to_hi().to(els.span);
+            if let StmtKind::Semi(_) = stmt.kind {
+                span = span.with_hi(stmt.span.hi());
+                if let Some(Stmt {
+                    kind: StmtKind::Expr(_),
+                    ..
+                }) = els_block.stmts.last()
+                {
+                    sugg.push(';');
+                }
+            }
+
+            diag.span_suggestion(span, "remove the `else` block and move the contents out", sugg, app);
+        });
     }
 }
 
+/// Checks if the span comes from the expansion of a macro that always panics.
+fn is_panic_expansion(span: Span) -> bool {
+    span.from_expansion()
+        && span.macro_backtrace().any(|expn| {
+            matches!(
+                expn.kind,
+                ExpnKind::Macro(MacroKind::Bang, name)
+                    if matches!(name.as_str(), "panic" | "unreachable" | "todo" | "unimplemented")
+            )
+        })
+}
+
 /// Call `check` functions to check if an expression always breaks control flow
 #[derive(Default)]
 struct BreakVisitor {
@@ -103,6 +161,10 @@ fn visit_block(&mut self, block: &'ast Block) {
     }
 
     fn visit_expr(&mut self, expr: &'ast Expr) {
+        if is_panic_expansion(expr.span) {
+            self.is_break = true;
+            return;
+        }
         self.is_break = match expr.kind {
             ExprKind::Break(..) | ExprKind::Continue(..) | ExprKind::Ret(..) => true,
             ExprKind::Match(_, ref arms) => arms.iter().all(|arm|
@@ -3,13 +3,16 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::source::snippet_opt;
-use clippy_utils::{def_path_def_ids, path_def_id, paths};
+use clippy_utils::visitors::is_const_evaluatable;
+use clippy_utils::{
+    def_path_def_ids, get_enclosing_loop_or_multi_call_closure, get_parent_expr, is_trait_method, path_def_id, paths,
+};
 use rustc_ast::ast::{LitKind, StrStyle};
-use rustc_hir::def_id::DefIdMap;
+use rustc_hir::def_id::{DefIdMap, DefIdSet};
 use rustc_hir::{BorrowKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
-use rustc_span::{BytePos, Span};
+use rustc_span::{sym, BytePos, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -55,6 +58,39 @@
     "trivial regular expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [regex](https://crates.io/crates/regex) compilation with a constant pattern
+    /// inside of a loop or of a closure that may be called multiple times.
+    ///
+    /// Additional constructors of values that are expensive to create can be configured with
+    /// `expensive-constructors`.
+    ///
+    /// ### Why is this bad?
+    /// Compiling a regex is much more expensive than using one, and a compiled regex can be used
+    /// multiple times. The regex documentation lists this as an
+    /// [anti-pattern](https://docs.rs/regex/latest/regex/#avoid-re-compiling-regexes-especially-in-a-loop).
+    ///
+    /// ### Example
+    /// ```ignore
+    /// for line in lines {
+    ///     let re = Regex::new(r"^\d+$").unwrap();
+    ///     if re.is_match(line) { /* .. */ }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// let re = Regex::new(r"^\d+$").unwrap();
+    /// for line in lines {
+    ///     if re.is_match(line) { /* .. */ }
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub REGEX_CREATION_IN_LOOPS,
+    perf,
+    "regular expression compilation performed in a loop"
+}
+
 #[derive(Copy, Clone)]
 enum RegexKind {
     Unicode,
@@ -63,12 +99,23 @@ enum RegexKind {
     BytesSet,
 }
 
-#[derive(Default)]
 pub struct Regex {
     definitions: DefIdMap<RegexKind>,
+    expensive_constructors: Vec<String>,
+    expensive_constructor_ids: DefIdSet,
+}
+
+impl Regex {
+    pub fn new(expensive_constructors: Vec<String>) -> Self {
+        Self {
+            definitions: DefIdMap::default(),
+            expensive_constructors,
+            expensive_constructor_ids: DefIdSet::default(),
+        }
+    }
 }
 
-impl_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX]);
+impl_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX, REGEX_CREATION_IN_LOOPS]);
 
 impl<'tcx> LateLintPass<'tcx> for Regex {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
@@ -89,23 +136,63 @@ fn check_crate(&mut self, cx: &LateContext<'tcx>) {
         resolve(&paths::REGEX_BYTES_NEW, RegexKind::Bytes);
         resolve(&paths::REGEX_BYTES_BUILDER_NEW, RegexKind::Bytes);
         resolve(&paths::REGEX_BYTES_SET_NEW, RegexKind::BytesSet);
+
+        for path in &self.expensive_constructors {
+            let segs: Vec<_> = path.split("::").collect();
+            self.expensive_constructor_ids.extend(def_path_def_ids(cx, &segs));
+        }
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if let ExprKind::Call(fun, [arg]) = expr.kind
-            && let Some(def_id) = path_def_id(cx, fun)
+        let ExprKind::Call(fun, args) = expr.kind else {
+            return;
+        };
+        let Some(def_id) = path_def_id(cx, fun) else {
+            return;
+        };
+        if let [arg] = args
             && let Some(regex_kind) = self.definitions.get(&def_id)
         {
+            check_creation_in_loop(cx, expr, args, "compiling a regex");
             match regex_kind {
                 RegexKind::Unicode => check_regex(cx, arg, true),
                 RegexKind::UnicodeSet => check_set(cx, arg, true),
                 RegexKind::Bytes => check_regex(cx, arg, false),
                 RegexKind::BytesSet => check_set(cx, arg, false),
             }
+        } else if self.expensive_constructor_ids.contains(&def_id) {
+            check_creation_in_loop(cx, expr, args, &format!("calling `{}`", cx.tcx.def_path_str(def_id)));
         }
     }
 }
 
+fn check_creation_in_loop<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, args: &'tcx [Expr<'_>], what: &str) {
+    if !expr.span.from_expansion()
+        && args.iter().all(|arg| is_const_evaluatable(cx, arg))
+        && let Some(enclosing) = get_enclosing_loop_or_multi_call_closure(cx, expr)
+        // Closures are only known to be called repeatedly when passed to an iterator adapter, other
+        // closures may be the lazy initialization this lint suggests
+        && (matches!(enclosing.kind, ExprKind::Loop(..))
+            || get_parent_expr(cx, enclosing).is_some_and(|parent| {
+                matches!(parent.kind, ExprKind::MethodCall(..)) && is_trait_method(cx, parent, sym::Iterator)
+            }))
+    {
+        let (kind, help) = if let ExprKind::Loop(..) = enclosing.kind {
+            ("loop", "move the construction outside of this loop")
+        } else {
+            ("closure", "move the construction outside of this closure")
+        };
+        span_lint_and_help(
+            cx,
+            REGEX_CREATION_IN_LOOPS,
+            expr.span,
+            &format!("{what} with a constant argument in a {kind}"),
+            Some(enclosing.span),
+            help,
+        );
+    }
+}
+
 fn lint_syntax_error(cx: &LateContext<'_>, error: &regex_syntax::Error, unescaped: &str, base: Span, offset: u8) {
     let parts: Option<(_, _, &dyn Display)> = match &error {
         regex_syntax::Error::Parse(e) => Some((e.span(), e.auxiliary_span(), e.kind())),
@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_note;
 use clippy_utils::source::snippet;
 use clippy_utils::visitors::is_local_used;
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def::Res;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::hir_id::ItemLocalId;
@@ -97,9 +97,18 @@
     "rebinding a name without even using the original value"
 }
 
-#[derive(Default)]
 pub(crate) struct Shadow {
     bindings: Vec<(FxHashMap<Symbol, Vec<ItemLocalId>>, LocalDefId)>,
+    allowed_names: FxHashSet<String>,
+}
+
+impl Shadow {
+    pub fn new(allowed_names: FxHashSet<String>) -> Self {
+        Self {
+            bindings: Vec::new(),
+            allowed_names,
+        }
+    }
 }
 
 impl_lint_pass!(Shadow => [SHADOW_SAME, SHADOW_REUSE, SHADOW_UNRELATED]);
@@ -118,6 +127,10 @@ fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
             return;
         }
 
+        if self.allowed_names.contains(ident.name.as_str()) {
+            return;
+        }
+
         let HirId { owner, local_id } = id;
         // get (or insert) the list of items for this owner and symbol
         let (ref mut data, scope_owner) = *self.bindings.last_mut().unwrap();
@@ -1,5 +1,5 @@
 use clippy_utils::ast_utils::{eq_id, is_useless_with_eq_exprs, IdentIter};
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_with_applicability;
 use core::ops::{Add, AddAssign};
 use rustc_ast::ast::{BinOpKind, Expr, ExprKind, StmtKind};
@@ -167,9 +167,10 @@ fn check_binops(cx: &EarlyContext<'_>, binops: &[&BinaryOp<'_>]) {
                         return;
                     };
 
-                    if let Some(sugg) = ident_swap_sugg(cx, &paired_identifiers, binop, changed_loc, &mut applicability)
+                    if let Some((sugg, operand)) =
+                        ident_swap_sugg(cx, &paired_identifiers, binop, changed_loc, &mut applicability)
                     {
-                        emit_suggestion(cx, binop.span, sugg, applicability);
+                        emit_suggestion(cx, binop.span, operand, sugg, applicability);
                     }
                 }
             },
@@ -207,6 +208,7 @@ fn attempt_to_emit_no_difference_lint(
                 emit_suggestion(
                     cx,
                     binop.span,
+                    binop.left.span,
                     replace_left_sugg(cx, binop, &sugg, &mut applicability),
                     applicability,
                 );
@@ -221,6 +223,7 @@ fn attempt_to_emit_no_difference_lint(
                 emit_suggestion(
                     cx,
                     binop.span,
+                    binop.right.span,
                     replace_right_sugg(cx, binop, &sugg, &mut applicability),
                     applicability,
                 );
@@ -230,15 +233,17 @@ fn attempt_to_emit_no_difference_lint(
     }
 }
 
-fn emit_suggestion(cx: &EarlyContext<'_>, span: Span, sugg: String, applicability: Applicability) {
-    span_lint_and_sugg(
+/// Emits the lint on `span`, pointing out the `operand` that breaks the pattern.
+fn emit_suggestion(cx: &EarlyContext<'_>, span: Span, operand: Span, sugg: String, applicability: Applicability) {
+    span_lint_and_then(
         cx,
         SUSPICIOUS_OPERATION_GROUPINGS,
         span,
         "this sequence of operators looks suspiciously like a bug",
-        "did you mean",
-        sugg,
-        applicability,
+        |diag| {
+            diag.span_suggestion(span, "did you mean", sugg, applicability);
+            diag.span_note(operand, "this operand breaks the pattern of the surrounding operations");
+        },
     );
 }
 
@@ -248,7 +253,7 @@ fn ident_swap_sugg(
     binop: &BinaryOp<'_>,
     location: IdentLocation,
     applicability: &mut Applicability,
-) -> Option<String> {
+) -> Option<(String, Span)> {
     let left_ident = get_ident(binop.left, location)?;
     let right_ident = get_ident(binop.right, location)?;
 
@@ -268,7 +273,10 @@ fn ident_swap_sugg(
 
             let right_suggestion = suggestion_with_swapped_ident(cx, binop.right, location, left_ident, applicability)?;
 
-            replace_right_sugg(cx, binop, &right_suggestion, applicability)
+            (
+                replace_right_sugg(cx, binop, &right_suggestion, applicability),
+                binop.right.span,
+            )
         },
         (false, true) => {
             // We haven't seen a pair involving the left one, so
@@ -276,14 +284,20 @@ fn ident_swap_sugg(
 
             let right_suggestion = suggestion_with_swapped_ident(cx, binop.right, location, left_ident, applicability)?;
 
-            replace_right_sugg(cx, binop, &right_suggestion, applicability)
+            (
+                replace_right_sugg(cx, binop, &right_suggestion, applicability),
+                binop.right.span,
+            )
         },
         (true, false) => {
             // We haven't seen a pair involving the right one, so
             // it's probably what is wanted.
             let left_suggestion = suggestion_with_swapped_ident(cx, binop.left, location, right_ident, applicability)?;
 
-            replace_left_sugg(cx, binop, &left_suggestion, applicability)
+            (
+                replace_left_sugg(cx, binop, &left_suggestion, applicability),
+                binop.left.span,
+            )
         },
     };
 
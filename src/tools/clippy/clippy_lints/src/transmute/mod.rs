@@ -7,6 +7,7 @@
 mod transmute_int_to_non_zero;
 mod transmute_null_to_fn;
 mod transmute_num_to_bytes;
+mod transmute_pointer_sized_int;
 mod transmute_ptr_to_ptr;
 mod transmute_ptr_to_ref;
 mod transmute_ref_to_ref;
@@ -520,8 +521,40 @@
     "eager evaluation of `transmute`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between `usize` or `isize` and fixed-width integer types
+    /// outside of code gated by `#[cfg(target_pointer_width = "..")]`.
+    ///
+    /// The pointer widths of the supported targets can be set with the
+    /// `supported-pointer-widths` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The transmute only compiles on targets whose pointers have the same width as the
+    /// fixed-width integer type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # #[cfg(target_pointer_width = "64")]
+    /// # {
+    /// let len: usize = 42;
+    /// let len: u64 = unsafe { std::mem::transmute(len) };
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let len: usize = 42;
+    /// let len = u64::try_from(len).unwrap();
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub TRANSMUTE_POINTER_SIZED_INT,
+    pedantic,
+    "transmutes between pointer-sized and fixed-width integers"
+}
+
 pub struct Transmute {
     msrv: Msrv,
+    supported_pointer_widths: Vec<u64>,
 }
 impl_lint_pass!(Transmute => [
     CROSSPOINTER_TRANSMUTE,
@@ -542,11 +575,15 @@ pub struct Transmute {
     TRANSMUTING_NULL,
     TRANSMUTE_NULL_TO_FN,
     EAGER_TRANSMUTE,
+    TRANSMUTE_POINTER_SIZED_INT,
 ]);
 impl Transmute {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, supported_pointer_widths: Vec<u64>) -> Self {
+        Self {
+            msrv,
+            supported_pointer_widths,
+        }
     }
 }
 impl<'tcx> LateLintPass<'tcx> for Transmute {
@@ -587,6 +624,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                 | transmute_int_to_non_zero::check(cx, e, from_ty, to_ty, arg)
                 | transmute_float_to_int::check(cx, e, from_ty, to_ty, arg, const_context)
                 | transmute_num_to_bytes::check(cx, e, from_ty, to_ty, arg, const_context)
+                | transmute_pointer_sized_int::check(cx, e, from_ty, to_ty, &self.supported_pointer_widths)
                 | (unsound_collection_transmute::check(cx, e, from_ty, to_ty)
                     || transmute_undefined_repr::check(cx, e, from_ty, to_ty))
                 | (eager_transmute::check(cx, e, arg, from_ty, to_ty));
@@ -0,0 +1,56 @@
+use super::TRANSMUTE_POINTER_SIZED_INT;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::enclosing_cfg_pointer_width;
+use clippy_utils::ty::is_isize_or_usize;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use std::slice;
+
+/// Returns the width of a fixed-width integer type, or `None` for pointer-sized and
+/// non-integer types.
+fn fixed_bit_width(ty: Ty<'_>) -> Option<u64> {
+    match ty.kind() {
+        ty::Int(int_ty) => int_ty.bit_width(),
+        ty::Uint(uint_ty) => uint_ty.bit_width(),
+        _ => None,
+    }
+}
+
+/// Checks for `transmute_pointer_sized_int` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    from_ty: Ty<'tcx>,
+    to_ty: Ty<'tcx>,
+    supported_pointer_widths: &[u64],
+) -> bool {
+    let bits = if is_isize_or_usize(from_ty) {
+        fixed_bit_width(to_ty)
+    } else if is_isize_or_usize(to_ty) {
+        fixed_bit_width(from_ty)
+    } else {
+        None
+    };
+    let Some(bits) = bits else {
+        return false;
+    };
+
+    // Code gated on a pointer width only needs to work with that width
+    let pinned_width = enclosing_cfg_pointer_width(cx.tcx, e.hir_id);
+    let widths = pinned_width.as_ref().map_or(supported_pointer_widths, slice::from_ref);
+    if widths.iter().all(|&width| width == bits) {
+        return false;
+    }
+
+    span_lint_and_help(
+        cx,
+        TRANSMUTE_POINTER_SIZED_INT,
+        e.span,
+        &format!("transmute from a `{from_ty}` to a `{to_ty}` assumes {bits}-bit wide pointers"),
+        None,
+        &format!("use `{to_ty}::try_from(..)` or gate this code with `#[cfg(target_pointer_width = \"{bits}\")]`"),
+    );
+    true
+}
@@ -9,7 +9,9 @@
 mod utils;
 mod vec_box;
 
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
     Body, FnDecl, FnRetTy, GenericArg, ImplItem, ImplItemKind, Item, ItemKind, Local, MutTy, QPath, TraitItem,
@@ -53,11 +55,14 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `Vec<Box<T>>` where T: Sized anywhere in the code.
+    /// `VecDeque<Box<T>>`, and the values of `HashMap<K, Box<T>>` and `BTreeMap<K, Box<T>>`
+    /// are checked as well.
     /// Check the [Box documentation](https://doc.rust-lang.org/std/boxed/index.html) for more information.
     ///
     /// ### Why is this bad?
     /// `Vec` already keeps its contents in a separate area on
-    /// the heap. So if you `Box` its contents, you just add another level of indirection.
+    /// the heap. So if you `Box` its contents, you just add another level of indirection,
+    /// and an allocation for each element.
     ///
     /// ### Known problems
     /// Vec<Box<T: Sized>> makes sense if T is a large type (see [#3530](https://github.com/rust-lang/rust-clippy/issues/3530),
@@ -227,9 +232,14 @@
     /// type with an interior mutable container (such as `RefCell` or `Mutex`) would normally
     /// be used.
     ///
+    /// This pattern can still be desirable to avoid the overhead of a `RefCell` or `Mutex` for
+    /// cases where mutation only happens before there are any additional references, so the
+    /// lint is not emitted for buffer types the crate mutates or takes back out of the pointer,
+    /// e.g. with `Rc::make_mut()` or `Rc::try_unwrap()`.
+    ///
     /// ### Known problems
-    /// This pattern can be desirable to avoid the overhead of a `RefCell` or `Mutex` for
-    /// cases where mutation only happens before there are any additional references.
+    /// Only the uses in the current crate are considered, and all `Rc<T>` of the same buffer
+    /// type are treated alike.
     ///
     /// ### Example
     /// ```rust,ignore
@@ -307,6 +317,8 @@ pub struct Types {
     vec_box_size_threshold: u64,
     type_complexity_threshold: u64,
     avoid_breaking_exported_api: bool,
+    /// The buffers wrapped in an `Rc` or `Arc` which are mutated in the crate, see `rc_buffer`.
+    mutated_rc_buffers: Option<FxHashSet<(DefId, DefId)>>,
 }
 
 impl_lint_pass!(Types => [BOX_COLLECTION, VEC_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION, RC_BUFFER, RC_MUTEX, TYPE_COMPLEXITY]);
@@ -441,6 +453,7 @@ pub fn new(vec_box_size_threshold: u64, type_complexity_threshold: u64, avoid_br
             vec_box_size_threshold,
             type_complexity_threshold,
             avoid_breaking_exported_api,
+            mutated_rc_buffers: None,
         }
     }
 
@@ -494,7 +507,7 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>, mut
                         let mut triggered = false;
                         triggered |= box_collection::check(cx, hir_ty, qpath, def_id);
                         triggered |= redundant_allocation::check(cx, hir_ty, qpath, def_id);
-                        triggered |= rc_buffer::check(cx, hir_ty, qpath, def_id);
+                        triggered |= rc_buffer::check(cx, hir_ty, qpath, def_id, &mut self.mutated_rc_buffers);
                         triggered |= vec_box::check(cx, hir_ty, qpath, def_id, self.vec_box_size_threshold);
                         triggered |= option_option::check(cx, hir_ty, qpath, def_id);
                         triggered |= linked_list::check(cx, hir_ty, def_id);
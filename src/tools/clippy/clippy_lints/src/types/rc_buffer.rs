@@ -1,15 +1,36 @@
+use std::ops::ControlFlow;
+
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{path_def_id, qpath_generic_tys};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::DefId;
-use rustc_hir::{self as hir, QPath, TyKind};
+use rustc_hir::{self as hir, ExprKind, QPath, TyKind};
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::symbol::sym;
 
 use super::RC_BUFFER;
 
-pub(super) fn check(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, qpath: &QPath<'_>, def_id: DefId) -> bool {
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    hir_ty: &hir::Ty<'_>,
+    qpath: &QPath<'_>,
+    def_id: DefId,
+    mutated_buffers: &mut Option<FxHashSet<(DefId, DefId)>>,
+) -> bool {
+    if (cx.tcx.is_diagnostic_item(sym::Rc, def_id) || cx.tcx.is_diagnostic_item(sym::Arc, def_id))
+        && let Some(buffer_id) = qpath_generic_tys(qpath).next().and_then(|ty| path_def_id(cx, ty))
+        && mutated_buffers
+            .get_or_insert_with(|| collect_mutated_buffers(cx))
+            .contains(&(def_id, buffer_id))
+    {
+        return false;
+    }
+
     let app = Applicability::Unspecified;
     if cx.tcx.is_diagnostic_item(sym::Rc, def_id) {
         if let Some(alternate) = match_buffer_type(cx, qpath) {
@@ -102,3 +123,30 @@ fn match_buffer_type(cx: &LateContext<'_>, qpath: &QPath<'_>) -> Option<&'static
     };
     Some(path)
 }
+
+/// Collects the `(pointer, buffer)` pairs of the `Rc` and `Arc` pointers whose buffer is mutated
+/// or taken back out of the pointer somewhere in the crate, e.g. with `Rc::make_mut`.
+fn collect_mutated_buffers(cx: &LateContext<'_>) -> FxHashSet<(DefId, DefId)> {
+    let mut mutated_buffers = FxHashSet::default();
+    for body_owner in cx.tcx.hir().body_owners() {
+        let typeck_results = cx.tcx.typeck(body_owner);
+        let body = cx.tcx.hir().body(cx.tcx.hir().body_owned_by(body_owner));
+        for_each_expr(body.value, |e| {
+            if let ExprKind::Path(ref qpath) = e.kind
+                && let Res::Def(DefKind::AssocFn, fn_id) = typeck_results.qpath_res(qpath, e.hir_id)
+                && matches!(
+                    cx.tcx.item_name(fn_id).as_str(),
+                    "get_mut" | "get_mut_unchecked" | "into_inner" | "make_mut" | "try_unwrap" | "unwrap_or_clone"
+                )
+                && let Some(impl_id) = cx.tcx.impl_of_method(fn_id)
+                && let Some(pointer) = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()
+                && let ty::FnDef(_, args) = typeck_results.expr_ty(e).kind()
+                && let Some(buffer) = args.types().next().and_then(ty::Ty::ty_adt_def)
+            {
+                mutated_buffers.insert((pointer.did(), buffer.did()));
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+    mutated_buffers
+}
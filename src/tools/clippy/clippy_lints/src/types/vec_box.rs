@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::last_path_segment;
 use clippy_utils::source::snippet;
+use itertools::Itertools;
 use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{self as hir, GenericArg, LangItem, QPath, TyKind};
@@ -8,10 +9,19 @@
 use rustc_lint::LateContext;
 use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::TypeVisitableExt;
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{sym, Symbol};
 
 use super::VEC_BOX;
 
+/// The collections storing their elements on the heap, with the name of the collection, the
+/// index of the generic parameter of its elements and the index of its allocator parameter.
+const COLLECTIONS: [(Symbol, &str, usize, Option<usize>); 4] = [
+    (sym::Vec, "Vec", 0, Some(1)),
+    (sym::VecDeque, "VecDeque", 0, Some(1)),
+    (sym::HashMap, "HashMap", 1, None),
+    (sym::BTreeMap, "BTreeMap", 1, Some(2)),
+];
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     hir_ty: &hir::Ty<'_>,
@@ -19,59 +29,72 @@ pub(super) fn check<'tcx>(
     def_id: DefId,
     box_size_threshold: u64,
 ) -> bool {
-    if cx.tcx.is_diagnostic_item(sym::Vec, def_id) {
-        if let Some(last) = last_path_segment(qpath).args
-            // Get the _ part of Vec<_>
-            && let Some(GenericArg::Type(ty)) = last.args.first()
-            // extract allocator from the Vec for later
-            && let vec_alloc_ty = last.args.get(1)
-            // ty is now _ at this point
-            && let TyKind::Path(ref ty_qpath) = ty.kind
-            && let res = cx.qpath_res(ty_qpath, ty.hir_id)
-            && let Some(def_id) = res.opt_def_id()
-            && Some(def_id) == cx.tcx.lang_items().owned_box()
-            // At this point, we know ty is Box<T>, now get T
-            && let Some(last) = last_path_segment(ty_qpath).args
-            && let Some(GenericArg::Type(boxed_ty)) = last.args.first()
-            // extract allocator from the Box for later
-            && let boxed_alloc_ty = last.args.get(1)
-            && let ty_ty = hir_ty_to_ty(cx.tcx, boxed_ty)
-            && !ty_ty.has_escaping_bound_vars()
-            && ty_ty.is_sized(cx.tcx, cx.param_env)
-            && let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes())
-            && ty_ty_size < box_size_threshold
-            // https://github.com/rust-lang/rust-clippy/issues/7114
-            && match (vec_alloc_ty, boxed_alloc_ty) {
-                (None, None) => true,
-                // this is in the event that we have something like
-                // Vec<_, Global>, in which case is equivalent to
-                // Vec<_>
-                (None, Some(GenericArg::Type(inner))) | (Some(GenericArg::Type(inner)), None) => {
-                    if let TyKind::Path(path) = inner.kind
-                        && let Some(did) = cx.qpath_res(&path, inner.hir_id).opt_def_id() {
-                        cx.tcx.lang_items().get(LangItem::GlobalAlloc) == Some(did)
-                    } else {
-                        false
-                    }
-                },
-                (Some(GenericArg::Type(l)), Some(GenericArg::Type(r))) =>
-                    hir_ty_to_ty(cx.tcx, l) == hir_ty_to_ty(cx.tcx, r),
-                _ => false
-            }
-        {
-            span_lint_and_sugg(
-                cx,
-                VEC_BOX,
-                hir_ty.span,
-                "`Vec<T>` is already on the heap, the boxing is unnecessary",
-                "try",
-                format!("Vec<{}>", snippet(cx, boxed_ty.span, "..")),
-                Applicability::Unspecified,
-            );
-            true
-        } else {
-            false
+    if let Some(&(_, name, elem_index, alloc_index)) = COLLECTIONS
+        .iter()
+        .find(|(diag_name, ..)| cx.tcx.is_diagnostic_item(*diag_name, def_id))
+        && let Some(last) = last_path_segment(qpath).args
+        // Get the _ part of Vec<_> or HashMap<K, _>
+        && let Some(GenericArg::Type(ty)) = last.args.get(elem_index)
+        // extract allocator from the collection for later
+        && let coll_alloc_ty = alloc_index.and_then(|index| last.args.get(index))
+        // ty is now _ at this point
+        && let TyKind::Path(ref ty_qpath) = ty.kind
+        && let res = cx.qpath_res(ty_qpath, ty.hir_id)
+        && let Some(def_id) = res.opt_def_id()
+        && Some(def_id) == cx.tcx.lang_items().owned_box()
+        // At this point, we know ty is Box<T>, now get T
+        && let Some(last_box) = last_path_segment(ty_qpath).args
+        && let Some(GenericArg::Type(boxed_ty)) = last_box.args.first()
+        // extract allocator from the Box for later
+        && let boxed_alloc_ty = last_box.args.get(1)
+        && let ty_ty = hir_ty_to_ty(cx.tcx, boxed_ty)
+        && !ty_ty.has_escaping_bound_vars()
+        && ty_ty.is_sized(cx.tcx, cx.param_env)
+        && let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes())
+        && ty_ty_size < box_size_threshold
+        // https://github.com/rust-lang/rust-clippy/issues/7114
+        && match (coll_alloc_ty, boxed_alloc_ty) {
+            (None, None) => true,
+            // this is in the event that we have something like
+            // Vec<_, Global>, in which case is equivalent to
+            // Vec<_>
+            (None, Some(GenericArg::Type(inner))) | (Some(GenericArg::Type(inner)), None) => {
+                if let TyKind::Path(path) = inner.kind
+                    && let Some(did) = cx.qpath_res(&path, inner.hir_id).opt_def_id() {
+                    cx.tcx.lang_items().get(LangItem::GlobalAlloc) == Some(did)
+                } else {
+                    false
+                }
+            },
+            (Some(GenericArg::Type(l)), Some(GenericArg::Type(r))) =>
+                hir_ty_to_ty(cx.tcx, l) == hir_ty_to_ty(cx.tcx, r),
+            _ => false
         }
+    {
+        let args = last
+            .args
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| Some(index) != alloc_index)
+            .map(|(index, arg)| {
+                if index == elem_index {
+                    snippet(cx, boxed_ty.span, "..")
+                } else {
+                    snippet(cx, arg.span(), "..")
+                }
+            })
+            .join(", ");
+        let generics = if elem_index == 0 { "T" } else { "K, V" };
+        span_lint_and_sugg(
+            cx,
+            VEC_BOX,
+            hir_ty.span,
+            &format!("`{name}<{generics}>` is already on the heap, the boxing is unnecessary"),
+            "try",
+            format!("{name}<{args}>"),
+            Applicability::Unspecified,
+        );
+        true
     } else {
         false
     }
@@ -0,0 +1,107 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{get_enclosing_loop_or_multi_call_closure, is_trait_method, path_to_local, paths};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::Ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for reads and writes, including `write!` and `writeln!`, directly on a `File` or
+    /// a `TcpStream` inside of a loop, when the `File` or `TcpStream` is created outside of it.
+    ///
+    /// ### Why is this bad?
+    /// Every call on an unbuffered `File` or `TcpStream` is a system call. Wrapping it in a
+    /// `BufWriter` or `BufReader` groups many small reads or writes into few system calls.
+    ///
+    /// ### Known problems
+    /// Reading or writing large chunks at a time doesn't benefit from buffering.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// # fn f(lines: &[&str]) -> std::io::Result<()> {
+    /// let mut file = File::create("out.txt")?;
+    /// for line in lines {
+    ///     writeln!(file, "{line}")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::{BufWriter, Write};
+    /// # fn f(lines: &[&str]) -> std::io::Result<()> {
+    /// let mut file = BufWriter::new(File::create("out.txt")?);
+    /// for line in lines {
+    ///     writeln!(file, "{line}")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub UNBUFFERED_IO,
+    pedantic,
+    "reading or writing a `File` or `TcpStream` in a loop without buffering"
+}
+declare_lint_pass!(UnbufferedIo => [UNBUFFERED_IO]);
+
+impl<'tcx> LateLintPass<'tcx> for UnbufferedIo {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(path, recv, _, _) = expr.kind
+            && let Some((trait_sym, op, buffer)) = match path.ident.as_str() {
+                "write" | "write_all" | "write_fmt" => Some((sym::IoWrite, "write to", "BufWriter")),
+                "read" | "read_exact" => Some((sym::IoRead, "read from", "BufReader")),
+                _ => None,
+            }
+            && is_trait_method(cx, expr, trait_sym)
+            && let Some(span) = call_span(cx, expr)
+            && let Some(ty_name) = unbuffered_ty_name(cx, cx.typeck_results().expr_ty(recv).peel_refs())
+            && let Some(loop_expr) = get_enclosing_loop_or_multi_call_closure(cx, expr)
+            && matches!(loop_expr.kind, ExprKind::Loop(..))
+            // A `File` created in the loop may only be used once per iteration
+            && let Some(id) = path_to_local(recv)
+            && !loop_expr.span.contains(cx.tcx.hir().span(id))
+        {
+            span_lint_and_help(
+                cx,
+                UNBUFFERED_IO,
+                span,
+                &format!("unbuffered {op} a `{ty_name}` in a loop"),
+                None,
+                &format!("consider wrapping the `{ty_name}` in a `{buffer}` before the loop"),
+            );
+        }
+    }
+}
+
+/// Returns the span of the `write!` or `writeln!` call if `expr` was expanded from one, or the
+/// span of `expr` if it isn't from an expansion.
+fn call_span(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    if !expr.span.from_expansion() {
+        return Some(expr.span);
+    }
+    root_macro_call_first_node(cx, expr)
+        .filter(|macro_call| {
+            matches!(
+                cx.tcx.get_diagnostic_name(macro_call.def_id),
+                Some(sym::write_macro | sym::writeln_macro)
+            )
+        })
+        .map(|macro_call| macro_call.span)
+}
+
+fn unbuffered_ty_name(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Symbol> {
+    if is_type_diagnostic_item(cx, ty, sym::File) {
+        Some(sym::File)
+    } else if match_type(cx, ty, &paths::TCP_STREAM) {
+        Some(Symbol::intern("TcpStream"))
+    } else {
+        None
+    }
+}
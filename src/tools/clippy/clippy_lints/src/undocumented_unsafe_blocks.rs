@@ -1,18 +1,20 @@
 use std::ops::ControlFlow;
 
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
 use clippy_utils::is_lint_allowed;
 use clippy_utils::source::walk_span_to_context;
 use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
 use hir::HirId;
+use rustc_ast::ImplPolarity;
 use rustc_data_structures::sync::Lrc;
 use rustc_hir as hir;
 use rustc_hir::{Block, BlockCheckMode, ItemKind, Node, UnsafeSource};
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, GenericArgKind, Ty};
 use rustc_session::impl_lint_pass;
-use rustc_span::{BytePos, Pos, RelativeBytePos, Span, SyntaxContext};
+use rustc_span::{sym, BytePos, Pos, RelativeBytePos, Span, SyntaxContext};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -92,6 +94,37 @@
     "annotating safe code with a safety comment"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe impl Send` and `unsafe impl Sync` items that either lack a safety
+    /// comment, or are for a type with fields containing raw pointers or `Rc`.
+    ///
+    /// ### Why is this bad?
+    /// Implementing `Send` or `Sync` manually opts out of the compiler's thread safety checks.
+    /// Every such impl should explain why it is sound, and fields that are neither `Send` nor
+    /// `Sync` deserve a closer look, since they are usually the reason the impl is needed in
+    /// the first place.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Buffer {
+    ///     ptr: *mut u8,
+    /// }
+    ///
+    /// unsafe impl Send for Buffer {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Buffer {
+    ///     ptr: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub UNSAFE_SEND_SYNC_IMPL,
+    restriction,
+    "manual `unsafe impl Send` or `unsafe impl Sync`"
+}
+
 #[derive(Copy, Clone)]
 pub struct UndocumentedUnsafeBlocks {
     accept_comment_above_statement: bool,
@@ -107,7 +140,11 @@ pub fn new(accept_comment_above_statement: bool, accept_comment_above_attributes
     }
 }
 
-impl_lint_pass!(UndocumentedUnsafeBlocks => [UNDOCUMENTED_UNSAFE_BLOCKS, UNNECESSARY_SAFETY_COMMENT]);
+impl_lint_pass!(UndocumentedUnsafeBlocks => [
+    UNDOCUMENTED_UNSAFE_BLOCKS,
+    UNNECESSARY_SAFETY_COMMENT,
+    UNSAFE_SEND_SYNC_IMPL,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
@@ -198,6 +235,7 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
         };
 
         let item_has_safety_comment = item_has_safety_comment(cx, item);
+        check_send_sync_impl(cx, item, !matches!(item_has_safety_comment, HasSafetyComment::No));
         match (&item.kind, item_has_safety_comment) {
             // lint unsafe impl without safety comment
             (hir::ItemKind::Impl(impl_), HasSafetyComment::No) if impl_.unsafety == hir::Unsafety::Unsafe => {
@@ -279,6 +317,74 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
     }
 }
 
+/// Checks an `unsafe impl Send` or `unsafe impl Sync` item for a missing safety comment and for
+/// fields that are neither `Send` nor `Sync`.
+fn check_send_sync_impl(cx: &LateContext<'_>, item: &hir::Item<'_>, has_safety_comment: bool) {
+    if let hir::ItemKind::Impl(impl_) = item.kind
+        && impl_.unsafety == hir::Unsafety::Unsafe
+        && impl_.polarity == ImplPolarity::Positive
+        && let Some(trait_ref) = &impl_.of_trait
+        && let Some(trait_id) = trait_ref.trait_def_id()
+        && let Some(trait_name @ (sym::Send | sym::Sync)) = cx.tcx.get_diagnostic_name(trait_id)
+        && !is_lint_allowed(cx, UNSAFE_SEND_SYNC_IMPL, item.hir_id())
+        && !is_unsafe_from_proc_macro(cx, item.span)
+    {
+        let mut fields = Vec::new();
+        if let ty::Adt(adt, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind() {
+            for field in adt.all_fields() {
+                if let Some(field_ty) = field.ty(cx.tcx, args).walk().find_map(|arg| match arg.unpack() {
+                    GenericArgKind::Type(ty) if is_thread_unsafe_ty(cx, ty) => Some(ty),
+                    _ => None,
+                }) {
+                    fields.push((field, field_ty));
+                }
+            }
+        }
+        if has_safety_comment && fields.is_empty() {
+            return;
+        }
+
+        let source_map = cx.tcx.sess.source_map();
+        let span = if source_map.is_multiline(item.span) {
+            source_map.span_until_char(item.span, '\n')
+        } else {
+            item.span
+        };
+        span_lint_and_then(
+            cx,
+            UNSAFE_SEND_SYNC_IMPL,
+            span,
+            &format!("manual `unsafe impl {trait_name}`"),
+            |diag| {
+                for (field, field_ty) in fields {
+                    diag.span_note(
+                        cx.tcx.def_span(field.did),
+                        format!(
+                            "field `{}` contains `{field_ty}`, which is neither `Send` nor `Sync`",
+                            field.name
+                        ),
+                    );
+                }
+                if !has_safety_comment {
+                    diag.help("consider adding a safety comment on the preceding line");
+                }
+            },
+        );
+    }
+}
+
+/// Checks for raw pointers, `NonNull` and `Rc`.
+fn is_thread_unsafe_ty(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::RawPtr(_) => true,
+        ty::Adt(adt, _) => matches!(
+            cx.tcx.get_diagnostic_name(adt.did()),
+            Some(sym::NonNull | sym::Rc | sym::RcWeak)
+        ),
+        _ => false,
+    }
+}
+
 fn expr_has_unnecessary_safety_comment<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx hir::Expr<'tcx>,
@@ -0,0 +1,91 @@
+use clippy_utils::attrs::get_attr;
+use clippy_utils::diagnostics::expect_lint;
+use itertools::Itertools;
+use rustc_ast::ast::{AssocItem, Attribute, Crate, Expr, Item, Local};
+use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[clippy::expect(..)]` attributes expecting a Clippy lint that is not emitted
+    /// within the item, statement or expression they are applied to.
+    ///
+    /// `#[clippy::expect(lint)]` suppresses `lint` like `#[allow(lint)]` does, and records whether
+    /// it was emitted. Unlike `#[expect(lint)]`, it doesn't need the `lint_reasons` feature.
+    ///
+    /// ### Why is this bad?
+    /// The expectation is stale: the false positive it worked around was fixed, or the code
+    /// changed, and it would hide new occurrences of the lint.
+    ///
+    /// ### Known problems
+    /// The lint is emitted at the crate level, so it can only be allowed for the whole crate.
+    /// Lints checked before macro expansion, like `deprecated_cfg_attr`, can't be expected.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[clippy::expect(clippy::needless_return)]
+    /// fn f() -> u32 {
+    ///     1
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn f() -> u32 {
+    ///     1
+    /// }
+    /// ```
+    #[clippy::version = "1.78.0"]
+    pub UNFULFILLED_CLIPPY_EXPECTATIONS,
+    suspicious,
+    "`#[clippy::expect(..)]` attributes expecting a lint that is not emitted"
+}
+
+// Only records the expectations, they are checked once every lint is emitted, see
+// `clippy_utils::diagnostics::lint_unfulfilled_expectations`
+declare_lint_pass!(ClippyExpectations => [UNFULFILLED_CLIPPY_EXPECTATIONS]);
+
+impl EarlyLintPass for ClippyExpectations {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
+        add_expectations(cx, &krate.attrs, krate.spans.inner_span);
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        add_expectations(cx, &item.attrs, item.span);
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &AssocItem) {
+        add_expectations(cx, &item.attrs, item.span);
+    }
+
+    fn check_trait_item(&mut self, cx: &EarlyContext<'_>, item: &AssocItem) {
+        add_expectations(cx, &item.attrs, item.span);
+    }
+
+    fn check_local(&mut self, cx: &EarlyContext<'_>, local: &Local) {
+        add_expectations(cx, &local.attrs, local.span);
+    }
+
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        add_expectations(cx, &expr.attrs, expr.span);
+    }
+}
+
+fn add_expectations(cx: &EarlyContext<'_>, attrs: &[Attribute], span: Span) {
+    for attr in get_attr(cx.sess(), attrs, "expect") {
+        let Some(items) = attr.meta_item_list() else {
+            cx.sess().dcx().span_err(attr.span, "expected a list of lints");
+            continue;
+        };
+        for item in items {
+            if let Some(meta) = item.meta_item()
+                && meta.is_word()
+            {
+                let lint = meta.path.segments.iter().map(|segment| segment.ident.name).join("::");
+                expect_lint(lint, span, item.span());
+            } else {
+                cx.sess().dcx().span_err(item.span(), "expected a lint name");
+            }
+        }
+    }
+}
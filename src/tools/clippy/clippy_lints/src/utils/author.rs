@@ -1,11 +1,13 @@
 //! A group of attributes that can be attached to Rust code in order
 //! to generate a clippy lint detecting said code automatically.
 
+use clippy_utils::consts::{constant, Constant};
 use clippy_utils::{get_attr, higher};
 use rustc_ast::ast::{LitFloatType, LitKind};
 use rustc_ast::LitIntType;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{
     ArrayLen, BindingAnnotation, CaptureBy, Closure, ClosureKind, CoroutineKind, ExprKind, FnRetTy, HirId, Lit,
     PatKind, QPath, StmtKind, TyKind,
@@ -270,6 +272,38 @@ fn qpath(&self, qpath: &Binding<&QPath<'_>>) {
         }
     }
 
+    /// Checks what a path expression resolves to, as matching the path's segments doesn't account
+    /// for imports and re-exports
+    fn path_res(&self, expr: &Binding<&hir::Expr<'_>>, qpath: &QPath<'_>) {
+        // Slice elements are bound by indexing, e.g. `args[0]`, and need to be borrowed
+        let expr_ref = if expr.name.ends_with(']') {
+            format!("&{expr}")
+        } else {
+            expr.to_string()
+        };
+        match self.cx.qpath_res(qpath, expr.value.hir_id) {
+            Res::Def(DefKind::Const | DefKind::AssocConst, _) => {
+                if let Some(typeck_results) = self.cx.maybe_typeck_results()
+                    && let Some(value) = constant(self.cx, typeck_results, expr.value)
+                    && let Some(value) = constant_to_string(&value)
+                {
+                    let constant = self.next("value");
+                    chain!(
+                        self,
+                        "let Some({constant}) = constant(cx, cx.typeck_results(), {expr_ref})"
+                    );
+                    chain!(self, "{constant} == {value}");
+                }
+            },
+            Res::Def(_, def_id) => {
+                if let Some(name) = self.cx.tcx.get_diagnostic_name(def_id) {
+                    chain!(self, "is_path_diagnostic_item(cx, {expr_ref}, sym::{name})");
+                }
+            },
+            _ => {},
+        }
+    }
+
     fn lit(&self, lit: &Binding<&Lit>) {
         let kind = |kind| chain!(self, "let LitKind::{kind} = {lit}.node");
         macro_rules! kind {
@@ -548,6 +582,7 @@ macro_rules! kind {
                 bind!(self, qpath);
                 kind!("Path(ref {qpath})");
                 self.qpath(qpath);
+                self.path_res(expr, qpath.value);
             },
             ExprKind::AddrOf(kind, mutability, inner) => {
                 bind!(self, inner);
@@ -752,6 +787,19 @@ fn has_attr(cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
     get_attr(cx.sess(), attrs, "author").count() > 0
 }
 
+fn constant_to_string(value: &Constant<'_>) -> Option<String> {
+    match *value {
+        Constant::Str(ref s) => Some(format!("Constant::Str(String::from({s:?}))")),
+        Constant::Char(c) => Some(format!("Constant::Char({c:?})")),
+        Constant::Int(i) => Some(format!("Constant::Int({i})")),
+        // NaN and infinities aren't valid float literals
+        Constant::F32(f) if f.is_finite() => Some(format!("Constant::F32({f:?})")),
+        Constant::F64(f) if f.is_finite() => Some(format!("Constant::F64({f:?})")),
+        Constant::Bool(b) => Some(format!("Constant::Bool({b})")),
+        _ => None,
+    }
+}
+
 fn path_to_string(path: &QPath<'_>) -> Result<String, ()> {
     fn inner(s: &mut String, path: &QPath<'_>) -> Result<(), ()> {
         match *path {
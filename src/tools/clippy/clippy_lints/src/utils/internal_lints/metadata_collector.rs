@@ -65,12 +65,17 @@
 /// This prefix is in front of the lint groups in the lint store. The prefix will be trimmed
 /// to only keep the actual lint group in the output.
 const CLIPPY_LINT_GROUP_PREFIX: &str = "clippy::";
-const LINT_EMISSION_FUNCTIONS: [&[&str]; 7] = [
+const LINT_EMISSION_FUNCTIONS: [&[&str]; 8] = [
     &["clippy_utils", "diagnostics", "span_lint"],
     &["clippy_utils", "diagnostics", "span_lint_and_help"],
     &["clippy_utils", "diagnostics", "span_lint_and_note"],
     &["clippy_utils", "diagnostics", "span_lint_hir"],
     &["clippy_utils", "diagnostics", "span_lint_and_sugg"],
+    &[
+        "clippy_utils",
+        "diagnostics",
+        "span_lint_and_sugg_unless_external_macro",
+    ],
     &["clippy_utils", "diagnostics", "span_lint_and_then"],
     &["clippy_utils", "diagnostics", "span_lint_hir_and_then"],
 ];
@@ -180,6 +185,19 @@ fn get_lint_configs(&self, lint_name: &str) -> Option<String> {
             })
     }
 
+    fn get_lint_config_options(&self, lint_name: &str) -> Vec<LintConfigOption> {
+        self.config
+            .iter()
+            .filter(|config| config.deprecation_reason.is_none())
+            .filter(|config| config.lints.iter().any(|lint| lint == lint_name))
+            .map(|config| LintConfigOption {
+                name: config.name.clone(),
+                default: config.default.clone(),
+                doc: config.doc.clone(),
+            })
+            .collect()
+    }
+
     fn configs_to_markdown(&self, map_fn: fn(&ClippyConfiguration) -> String) -> String {
         self.config
             .iter()
@@ -220,6 +238,7 @@ fn drop(&mut self) {
         let mut lints = std::mem::take(&mut self.lints).into_sorted_vec();
         for x in &mut lints {
             x.applicability = Some(applicability_info.remove(&x.id).unwrap_or_default());
+            x.config = self.get_lint_config_options(&x.id);
             replace_produces(&x.id, &mut x.docs, &self.clippy_project_root);
         }
 
@@ -272,6 +291,18 @@ struct LintMetadata {
     /// All the past names of lints which have been renamed.
     #[serde(skip_serializing_if = "BTreeSet::is_empty")]
     former_ids: BTreeSet<String>,
+    /// The configuration options affecting this lint. This field is also only
+    /// mapped shortly before the actual output.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    config: Vec<LintConfigOption>,
+}
+
+/// A `clippy.toml` option, in the form it is exported with the lints it affects
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct LintConfigOption {
+    name: String,
+    default: String,
+    doc: String,
 }
 
 impl LintMetadata {
@@ -292,6 +323,7 @@ fn new(
             docs,
             applicability: None,
             former_ids: BTreeSet::new(),
+            config: Vec::new(),
         }
     }
 }
@@ -23,6 +23,7 @@ pub enum DeprecationStatus {
     ("dump",                  DeprecationStatus::None),
     ("msrv",                  DeprecationStatus::None),
     ("has_significant_drop",  DeprecationStatus::None),
+    ("expect",                DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
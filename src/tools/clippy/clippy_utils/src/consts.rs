@@ -327,6 +327,18 @@ pub fn constant_with_source<'tcx>(
     res.map(|x| (x, ctxt.source))
 }
 
+/// Attempts to evaluate the expression as a constant, also folding calls of pure inherent float
+/// methods whose result may depend on the platform, like `ln` or `powf`, on constant arguments.
+pub fn constant_with_float_methods<'tcx>(
+    lcx: &LateContext<'tcx>,
+    typeck_results: &ty::TypeckResults<'tcx>,
+    e: &Expr<'_>,
+) -> Option<Constant<'tcx>> {
+    let mut ctxt = ConstEvalLateContext::new(lcx, typeck_results);
+    ctxt.fold_float_methods = true;
+    ctxt.expr(e)
+}
+
 /// Attempts to evaluate an expression only if its value is not dependent on other items.
 pub fn constant_simple<'tcx>(
     lcx: &LateContext<'tcx>,
@@ -388,6 +400,7 @@ pub struct ConstEvalLateContext<'a, 'tcx> {
     param_env: ty::ParamEnv<'tcx>,
     source: ConstantSource,
     args: GenericArgsRef<'tcx>,
+    fold_float_methods: bool,
 }
 
 impl<'a, 'tcx> ConstEvalLateContext<'a, 'tcx> {
@@ -398,6 +411,7 @@ pub fn new(lcx: &'a LateContext<'tcx>, typeck_results: &'a ty::TypeckResults<'tc
             param_env: lcx.param_env,
             source: ConstantSource::Local,
             args: List::empty(),
+            fold_float_methods: false,
         }
     }
 
@@ -454,6 +468,7 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant<'tcx>> {
                     None
                 }
             },
+            ExprKind::MethodCall(path, receiver, args, _) => self.float_method(e, path.ident.name, receiver, args),
             ExprKind::Index(arr, index, _) => self.index(arr, index),
             ExprKind::AddrOf(_, _, inner) => self.expr(inner).map(|r| Constant::Ref(Box::new(r))),
             ExprKind::Field(local_expr, ref field) => {
@@ -561,6 +576,68 @@ fn fetch_path(&mut self, qpath: &QPath<'_>, id: HirId, ty: Ty<'tcx>) -> Option<C
         }
     }
 
+    /// Evaluates a call of a pure inherent method of `f32` or `f64`, e.g. `2f64.sqrt()`.
+    ///
+    /// Rounding methods like `floor` or `trunc`, whose result is exact, are always evaluated. The
+    /// others, like `ln` or `powf`, may give different results on different platforms, so they are
+    /// only evaluated by `constant_with_float_methods`.
+    fn float_method(
+        &mut self,
+        e: &Expr<'_>,
+        name: Symbol,
+        receiver: &Expr<'_>,
+        args: &[Expr<'_>],
+    ) -> Option<Constant<'tcx>> {
+        let exact = matches!(name.as_str(), "ceil" | "floor" | "fract" | "round" | "signum" | "trunc");
+        if !exact && !self.fold_float_methods {
+            return None;
+        }
+        let def_id = self.typeck_results.type_dependent_def_id(e.hir_id)?;
+        let impl_id = self.lcx.tcx.impl_of_method(def_id)?;
+        if self.lcx.tcx.trait_id_of_impl(impl_id).is_some() {
+            return None;
+        }
+        let receiver = self.expr(receiver)?;
+        let args = self.multi(args)?;
+        let tcx = self.lcx.tcx;
+
+        macro_rules! fold {
+            ($x:expr, $variant:ident) => {{
+                let x = $x;
+                let value = match (name.as_str(), &args[..]) {
+                    ("abs", []) => x.abs(),
+                    ("cbrt", []) => x.cbrt(),
+                    ("ceil", []) => x.ceil(),
+                    ("exp", []) => x.exp(),
+                    ("exp2", []) => x.exp2(),
+                    ("exp_m1", []) => x.exp_m1(),
+                    ("floor", []) => x.floor(),
+                    ("fract", []) => x.fract(),
+                    ("ln", []) => x.ln(),
+                    ("ln_1p", []) => x.ln_1p(),
+                    ("log", [Constant::$variant(base)]) => x.log(*base),
+                    ("log10", []) => x.log10(),
+                    ("log2", []) => x.log2(),
+                    ("recip", []) => x.recip(),
+                    ("round", []) => x.round(),
+                    ("signum", []) => x.signum(),
+                    ("sqrt", []) => x.sqrt(),
+                    ("trunc", []) => x.trunc(),
+                    ("powf", [Constant::$variant(n)]) => x.powf(*n),
+                    ("powi", [Constant::Int(n)]) => x.powi(i32::try_from(sext(tcx, *n, IntTy::I32)).ok()?),
+                    _ => return None,
+                };
+                Some(Constant::$variant(value))
+            }};
+        }
+
+        match receiver {
+            Constant::F32(x) => fold!(x, F32),
+            Constant::F64(x) => fold!(x, F64),
+            _ => None,
+        }
+    }
+
     fn index(&mut self, lhs: &'_ Expr<'_>, index: &'_ Expr<'_>) -> Option<Constant<'tcx>> {
         let lhs = self.expr(lhs);
         let index = self.expr(index);
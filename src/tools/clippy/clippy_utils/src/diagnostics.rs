@@ -8,13 +8,86 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, Diag, MultiSpan};
-use rustc_hir::HirId;
+use rustc_hir::{HirId, CRATE_HIR_ID};
 use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TyCtxt;
 use rustc_span::Span;
 use std::env;
+use std::sync::{Mutex, OnceLock};
+
+static LINT_GROUPS: OnceLock<FxHashMap<&'static str, &'static str>> = OnceLock::new();
+
+/// Sets the group of every lint, keyed by the lint name, e.g. `clippy::style` for
+/// `clippy::NEEDLESS_RETURN`. Used in `clippy_lints` when registering the lints.
+pub fn set_lint_groups(groups: FxHashMap<&'static str, &'static str>) {
+    // The groups are the same for every session
+    let _ = LINT_GROUPS.set(groups);
+}
+
+/// A lint expected to be emitted within `span` by a `#[clippy::expect(..)]` attribute
+struct Expectation {
+    lint: String,
+    span: Span,
+    attr_span: Span,
+    fulfilled: bool,
+}
+
+static EXPECTATIONS: Mutex<Vec<Expectation>> = Mutex::new(Vec::new());
+
+/// Expects `lint`, e.g. `clippy::needless_return`, to be emitted within `span`, the span of the
+/// node with the `#[clippy::expect(..)]` attribute at `attr_span`. The lint is suppressed there.
+pub fn expect_lint(lint: String, span: Span, attr_span: Span) {
+    EXPECTATIONS.lock().unwrap().push(Expectation {
+        lint,
+        span,
+        attr_span,
+        fulfilled: false,
+    });
+}
+
+/// Emits `lint` at every `#[clippy::expect(..)]` attribute expecting a lint that was not emitted.
+/// Must be called once all the lints are emitted.
+pub fn lint_unfulfilled_expectations(tcx: TyCtxt<'_>, lint: &'static Lint) {
+    for expectation in EXPECTATIONS.lock().unwrap().iter().filter(|e| !e.fulfilled) {
+        #[expect(clippy::disallowed_methods)]
+        tcx.node_span_lint(
+            lint,
+            CRATE_HIR_ID,
+            expectation.attr_span,
+            format!("the expected lint `{}` was not emitted", expectation.lint),
+            |diag| {
+                docs_link(diag, lint);
+            },
+        );
+    }
+}
+
+// Checks if `lint` is expected at `sp`, fulfilling the expectations
+fn is_expected(lint: &'static Lint, sp: &MultiSpan) -> bool {
+    let Some(span) = sp.primary_span() else {
+        return false;
+    };
+    let name = lint.name_lower();
+    let mut expected = false;
+    for expectation in EXPECTATIONS.lock().unwrap().iter_mut() {
+        if expectation.lint == name && expectation.span.contains(span) {
+            expectation.fulfilled = true;
+            expected = true;
+        }
+    }
+    expected
+}
 
 fn docs_link(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
+    // Opt-in note for tools that filter diagnostics by group, which JSON diagnostics have no field for
+    if env::var("CLIPPY_NOTE_LINT_GROUPS").is_ok()
+        && let Some(group) = LINT_GROUPS.get().and_then(|groups| groups.get(lint.name))
+    {
+        diag.note(format!("`{}` is in the `{group}` group", lint.name_lower()));
+    }
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
             diag.help(format!(
@@ -46,6 +119,10 @@ fn docs_link(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    let sp = sp.into();
+    if is_expected(lint, &sp) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, sp, msg.to_string(), |diag| {
         docs_link(diag, lint);
@@ -80,6 +157,10 @@ pub fn span_lint_and_help<T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    let span = span.into();
+    if is_expected(lint, &span) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, span, msg.to_string(), |diag| {
         let help = help.to_string();
@@ -123,6 +204,10 @@ pub fn span_lint_and_note<T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    let span = span.into();
+    if is_expected(lint, &span) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, span, msg.to_string(), |diag| {
         let note = note.to_string();
@@ -145,6 +230,10 @@ pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str
     S: Into<MultiSpan>,
     F: FnOnce(&mut Diag<'_, ()>),
 {
+    let sp = sp.into();
+    if is_expected(lint, &sp) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, sp, msg.to_string(), |diag| {
         f(diag);
@@ -153,6 +242,9 @@ pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    if is_expected(lint, &sp.into()) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.tcx.node_span_lint(lint, hir_id, sp, msg.to_string(), |diag| {
         docs_link(diag, lint);
@@ -167,6 +259,10 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut Diag<'_, ()>),
 ) {
+    let sp = sp.into();
+    if is_expected(lint, &sp) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.tcx.node_span_lint(lint, hir_id, sp, msg.to_string(), |diag| {
         f(diag);
@@ -208,6 +304,40 @@ pub fn span_lint_and_sugg<T: LintContext>(
     });
 }
 
+/// Like [`span_lint_and_sugg`], but takes the macro provenance of `sp` into account: nothing is
+/// emitted if `sp` comes from the expansion of an external macro, as its code can't be changed, and
+/// a suggestion for the expansion of a local macro is at most `MaybeIncorrect`, as the replaced
+/// code may be shared with other invocations of the macro.
+///
+/// See [`applicability_for_span`].
+pub fn span_lint_and_sugg_unless_external_macro<T: LintContext>(
+    cx: &T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: &str,
+    help: &str,
+    sugg: String,
+    applicability: Applicability,
+) {
+    if let Some(applicability) = applicability_for_span(cx, sp, applicability) {
+        span_lint_and_sugg(cx, lint, sp, msg, help, sugg, applicability);
+    }
+}
+
+/// Returns the applicability a suggestion replacing the code at `sp` should have, or `None` if no
+/// suggestion should be made because `sp` comes from the expansion of an external macro.
+///
+/// Suggestions for code from the expansion of a local macro are downgraded to `MaybeIncorrect`.
+pub fn applicability_for_span<T: LintContext>(cx: &T, sp: Span, applicability: Applicability) -> Option<Applicability> {
+    if in_external_macro(cx.sess(), sp) {
+        None
+    } else if sp.from_expansion() && applicability == Applicability::MachineApplicable {
+        Some(Applicability::MaybeIncorrect)
+    } else {
+        Some(applicability)
+    }
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
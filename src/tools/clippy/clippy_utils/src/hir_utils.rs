@@ -32,6 +32,7 @@ pub struct SpanlessEq<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     maybe_typeck_results: Option<(&'tcx TypeckResults<'tcx>, &'tcx TypeckResults<'tcx>)>,
     allow_side_effects: bool,
+    allow_pure_calls: bool,
     expr_fallback: Option<Box<SpanlessEqCallback<'a>>>,
 }
 
@@ -41,6 +42,7 @@ pub fn new(cx: &'a LateContext<'tcx>) -> Self {
             cx,
             maybe_typeck_results: cx.maybe_typeck_results().map(|x| (x, x)),
             allow_side_effects: true,
+            allow_pure_calls: false,
             expr_fallback: None,
         }
     }
@@ -54,6 +56,16 @@ pub fn deny_side_effects(self) -> Self {
         }
     }
 
+    /// When side effects are denied, still consider calls to inherent methods of primitive types
+    /// taking only primitive arguments as equal, e.g. `x.sin()` or `f64::max(a, b)`.
+    #[must_use]
+    pub fn allow_pure_calls(self) -> Self {
+        Self {
+            allow_pure_calls: true,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn expr_fallback(self, expr_fallback: impl FnMut(&Expr<'_>, &Expr<'_>) -> bool + 'a) -> Self {
         Self {
@@ -246,6 +258,16 @@ pub fn eq_body(&mut self, left: BodyId, right: BodyId) -> bool {
         res
     }
 
+    /// Checks whether two calls can be considered equal with respect to their side effects.
+    fn allow_call(&self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+        self.inner.allow_side_effects
+            || self.inner.allow_pure_calls
+                && self.inner.maybe_typeck_results.is_some_and(|(typeck_lhs, typeck_rhs)| {
+                    is_pure_primitive_call(self.inner.cx, typeck_lhs, left)
+                        && is_pure_primitive_call(self.inner.cx, typeck_rhs, right)
+                })
+    }
+
     #[expect(clippy::too_many_lines)]
     pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
         if !self.check_ctxt(left.span.ctxt(), right.span.ctxt()) {
@@ -289,7 +311,7 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                     && both(le, re, |l, r| self.eq_expr(l, r))
             },
             (&ExprKind::Call(l_fun, l_args), &ExprKind::Call(r_fun, r_args)) => {
-                self.inner.allow_side_effects && self.eq_expr(l_fun, r_fun) && self.eq_exprs(l_args, r_args)
+                self.allow_call(left, right) && self.eq_expr(l_fun, r_fun) && self.eq_exprs(l_args, r_args)
             },
             (&ExprKind::Cast(lx, lt), &ExprKind::Cast(rx, rt)) => {
                 self.eq_expr(lx, rx) && self.eq_ty(lt, rt)
@@ -327,7 +349,7 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                 &ExprKind::MethodCall(l_path, l_receiver, l_args, _),
                 &ExprKind::MethodCall(r_path, r_receiver, r_args, _),
             ) => {
-                self.inner.allow_side_effects
+                self.allow_call(left, right)
                     && self.eq_path_segment(l_path, r_path)
                     && self.eq_expr(l_receiver, r_receiver)
                     && self.eq_exprs(l_args, r_args)
@@ -660,6 +682,39 @@ pub fn eq_expr_value(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) ->
     SpanlessEq::new(cx).deny_side_effects().eq_expr(left, right)
 }
 
+/// Checks if two expressions evaluate to the same value, and don't contain any side effects. Unlike
+/// `eq_expr_value`, this allows calls which are known to be free of side effects, as per
+/// `is_pure_primitive_call`.
+pub fn eq_expr_value_allow_pure_calls(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) -> bool {
+    SpanlessEq::new(cx)
+        .deny_side_effects()
+        .allow_pure_calls()
+        .eq_expr(left, right)
+}
+
+/// Checks if the expression is a call to an inherent method or associated function of a primitive
+/// type, whose arguments are all of primitive types, e.g. `x.sin()` or `u32::max(a, b)`. Besides
+/// panicking, such calls can't have any side effects.
+pub fn is_pure_primitive_call(cx: &LateContext<'_>, typeck: &TypeckResults<'_>, expr: &Expr<'_>) -> bool {
+    let (def_id, receiver, args) = match expr.kind {
+        ExprKind::MethodCall(_, receiver, args, _) => (typeck.type_dependent_def_id(expr.hir_id), Some(receiver), args),
+        ExprKind::Call(fun, args) => match fun.kind {
+            ExprKind::Path(ref qpath) => (typeck.qpath_res(qpath, fun.hir_id).opt_def_id(), None, args),
+            _ => return false,
+        },
+        _ => return false,
+    };
+    def_id
+        .and_then(|def_id| cx.tcx.impl_of_method(def_id))
+        .is_some_and(|impl_id| {
+            cx.tcx.trait_id_of_impl(impl_id).is_none() && cx.tcx.type_of(impl_id).instantiate_identity().is_primitive()
+        })
+        && receiver
+            .into_iter()
+            .chain(args)
+            .all(|arg| typeck.expr_ty(arg).is_primitive())
+}
+
 /// Type used to hash an ast element. This is different from the `Hash` trait
 /// on ast types as this
 /// trait would consider IDs and spans.
@@ -75,7 +75,8 @@
 pub use self::attrs::*;
 pub use self::check_proc_macro::{is_from_proc_macro, is_span_if, is_span_match};
 pub use self::hir_utils::{
-    both, count_eq, eq_expr_value, hash_expr, hash_stmt, is_bool, over, HirEqInterExpr, SpanlessEq, SpanlessHash,
+    both, count_eq, eq_expr_value, eq_expr_value_allow_pure_calls, hash_expr, hash_stmt, is_bool,
+    is_pure_primitive_call, over, HirEqInterExpr, SpanlessEq, SpanlessHash,
 };
 
 use core::mem;
@@ -321,6 +322,18 @@ pub fn is_diag_trait_item(cx: &LateContext<'_>, def_id: DefId, diag_item: Symbol
     false
 }
 
+/// Checks if a method with the given name is defined in an impl of a diagnostic item, e.g.
+/// `String::push_str` for `sym::String` and `"push_str"`.
+pub fn is_diag_item_method_named(cx: &LateContext<'_>, def_id: DefId, diag_item: Symbol, name: &str) -> bool {
+    is_diag_item_method(cx, def_id, diag_item) && cx.tcx.item_name(def_id).as_str() == name
+}
+
+/// Checks if an item with the given name is in a diagnostic item trait, e.g. `Clone::clone` for
+/// `sym::Clone` and `"clone"`.
+pub fn is_diag_trait_item_named(cx: &LateContext<'_>, def_id: DefId, diag_item: Symbol, name: &str) -> bool {
+    is_diag_trait_item(cx, def_id, diag_item) && cx.tcx.item_name(def_id).as_str() == name
+}
+
 /// Checks if the method call given in `expr` belongs to the given trait.
 pub fn is_trait_method(cx: &LateContext<'_>, expr: &Expr<'_>, diag_item: Symbol) -> bool {
     cx.typeck_results()
@@ -2350,6 +2363,37 @@ pub fn search_same<T, Hash, Eq>(exprs: &[T], hash: Hash, eq: Eq) -> Vec<(&T, &T)
     match_expr_list
 }
 
+/// Groups the subexpressions of `node` which evaluate to the same value and are free of side
+/// effects, as per `eq_expr_value_allow_pure_calls`. Literals and expressions from macro
+/// expansions are skipped. Only groups of at least two expressions are returned, ordered by their
+/// first occurrence.
+///
+/// e.g. for `(x.sin() + 1.0) * (x.sin() + 1.0)` this returns the two occurrences of `x.sin() + 1.0`,
+/// the two occurrences of `x.sin()` and the two occurrences of `x`.
+pub fn repeated_subexprs<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> Vec<Vec<&'tcx Expr<'tcx>>> {
+    let mut groups: Vec<Vec<&'tcx Expr<'tcx>>> = Vec::new();
+    let mut map: UnhashMap<u64, Vec<usize>> = UnhashMap::default();
+
+    for_each_expr(node, |e| {
+        if !matches!(e.kind, ExprKind::Lit(_)) && !e.span.from_expansion() {
+            let indices = map.entry(hash_expr(cx, e)).or_default();
+            if let Some(&i) = indices
+                .iter()
+                .find(|&&i| eq_expr_value_allow_pure_calls(cx, groups[i][0], e))
+            {
+                groups[i].push(e);
+            } else {
+                indices.push(groups.len());
+                groups.push(vec![e]);
+            }
+        }
+        ControlFlow::<!>::Continue(())
+    });
+
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
 /// Peels off all references on the pattern. Returns the underlying pattern and the number of
 /// references removed.
 pub fn peel_hir_pat_refs<'a>(pat: &'a Pat<'a>) -> (&'a Pat<'a>, usize) {
@@ -2534,6 +2578,43 @@ pub fn is_in_cfg_test(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
         .any(|parent_id| is_cfg_test(tcx, parent_id))
 }
 
+/// Checks if the node is in a `#[test]` function or has any parent node marked `#[cfg(test)]`
+pub fn is_in_test(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    is_in_test_function(tcx, id) || is_in_cfg_test(tcx, id)
+}
+
+/// Returns the pointer width set by a `#[cfg(target_pointer_width = "..")]` attribute applied to
+/// `id`, either directly, inside `all(..)`, or in every arm of `any(..)` with the same width.
+pub fn cfg_pointer_width(tcx: TyCtxt<'_>, id: hir::HirId) -> Option<u64> {
+    fn pinned_width(item: &ast::NestedMetaItem) -> Option<u64> {
+        if item.has_name(sym::target_pointer_width) {
+            item.value_str()?.as_str().parse().ok()
+        } else if item.has_name(sym::all) {
+            item.meta_item_list()?.iter().find_map(pinned_width)
+        } else if item.has_name(sym::any) {
+            let mut widths = item.meta_item_list()?.iter().map(pinned_width);
+            let width = widths.next()??;
+            widths.all(|w| w == Some(width)).then_some(width)
+        } else {
+            None
+        }
+    }
+
+    tcx.hir()
+        .attrs(id)
+        .iter()
+        .filter(|attr| attr.has_name(sym::cfg))
+        .find_map(|attr| attr.meta_item_list()?.iter().find_map(pinned_width))
+}
+
+/// Returns the pointer width set by a `#[cfg(target_pointer_width = "..")]` attribute applied to
+/// `HirId` or the closest of its parent nodes, see [`cfg_pointer_width`].
+pub fn enclosing_cfg_pointer_width(tcx: TyCtxt<'_>, id: hir::HirId) -> Option<u64> {
+    once(id)
+        .chain(tcx.hir().parent_id_iter(id))
+        .find_map(|id| cfg_pointer_width(tcx, id))
+}
+
 /// Checks if the item of any of its parents has `#[cfg(...)]` attribute applied.
 pub fn inherits_cfg(tcx: TyCtxt<'_>, def_id: LocalDefId) -> bool {
     let hir = tcx.hir();
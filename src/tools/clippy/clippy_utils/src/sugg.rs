@@ -358,6 +358,21 @@ pub fn maybe_par(self) -> Self {
             },
         }
     }
+
+    /// Adds parentheses to the expression if it needs them to be the receiver of a method call,
+    /// e.g. to build `(x as u32).foo()`, `(-x).foo()` or `(a..b).foo()`.
+    ///
+    /// Unlike `maybe_par`, this also parenthesizes float literals ending with a dot, as `1..foo()`
+    /// would be parsed as a range.
+    #[must_use]
+    pub fn maybe_par_for_method_receiver(self) -> Self {
+        match self {
+            Sugg::NonParen(ref sugg) if sugg.starts_with(|c: char| c.is_ascii_digit()) && sugg.ends_with('.') => {
+                Sugg::NonParen(format!("({sugg})").into())
+            },
+            _ => self.maybe_par(),
+        }
+    }
 }
 
 /// Generates a string from the operator and both sides.
@@ -555,6 +570,8 @@ fn needs_paren(op: AssocOp, other: AssocOp, dir: Associativity) -> bool {
                     || (op == other && associativity(op) != Associativity::Both)))
             || is_shift(op) && is_arith(other)
             || is_shift(other) && is_arith(op)
+            // `x as u32 < y` would parse `<` as the start of generic arguments
+            || other == AssocOp::As && dir == Associativity::Left && matches!(op, AssocOp::Less | AssocOp::ShiftLeft)
     }
 
     let lhs_paren = if let Sugg::BinOp(lop, _, _) = *lhs {
@@ -1087,6 +1104,39 @@ fn binop_maybe_par() {
         let sugg = Sugg::BinOp(AssocOp::Add, "(1 + 1)".into(), "(1 + 1)".into());
         assert_eq!("((1 + 1) + (1 + 1))", sugg.maybe_par().to_string());
     }
+    #[test]
+    fn maybe_par_for_method_receiver() {
+        let sugg = Sugg::BinOp(AssocOp::As, "x".into(), "u32".into());
+        assert_eq!("(x as u32)", sugg.maybe_par_for_method_receiver().to_string());
+
+        let sugg = Sugg::MaybeParen("-x".into());
+        assert_eq!("(-x)", sugg.maybe_par_for_method_receiver().to_string());
+
+        let sugg = Sugg::BinOp(AssocOp::DotDot, "a".into(), "b".into());
+        assert_eq!("(a..b)", sugg.maybe_par_for_method_receiver().to_string());
+
+        let sugg = Sugg::NonParen("1.".into());
+        assert_eq!("(1.)", sugg.maybe_par_for_method_receiver().to_string());
+
+        let sugg = Sugg::NonParen("1.5".into());
+        assert_eq!("1.5", sugg.maybe_par_for_method_receiver().to_string());
+
+        let sugg = Sugg::MaybeParen("(-x)".into());
+        assert_eq!("(-x)", sugg.maybe_par_for_method_receiver().to_string());
+    }
+
+    #[test]
+    fn cast_before_less_than() {
+        use super::make_assoc;
+
+        let cast = Sugg::BinOp(AssocOp::As, "x".into(), "u32".into());
+        let y = Sugg::NonParen("y".into());
+        assert_eq!("(x as u32) < y", make_assoc(AssocOp::Less, &cast, &y).to_string());
+        assert_eq!("(x as u32) << y", make_assoc(AssocOp::ShiftLeft, &cast, &y).to_string());
+        assert_eq!("x as u32 > y", make_assoc(AssocOp::Greater, &cast, &y).to_string());
+        assert_eq!("y < x as u32", make_assoc(AssocOp::Less, &y, &cast).to_string());
+    }
+
     #[test]
     fn not_op() {
         use AssocOp::{Add, Equal, Greater, GreaterEqual, LAnd, LOr, Less, LessEqual, NotEqual};
@@ -3,13 +3,14 @@
 use core::ops::ControlFlow;
 use hir::def::Res;
 use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{self as hir, Expr, ExprKind, HirId, HirIdSet};
+use rustc_hir::{self as hir, Expr, ExprKind, HirId, HirIdMap, HirIdSet};
 use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, Place, PlaceBase, PlaceWithHirId};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
 use rustc_middle::mir::FakeReadCause;
 use rustc_middle::ty;
+use rustc_span::def_id::LocalDefId;
 
 /// Returns a set of mutated local variable IDs, or `None` if mutations could not be determined.
 pub fn mutated_variables<'tcx>(expr: &'tcx Expr<'_>, cx: &LateContext<'tcx>) -> Option<HirIdSet> {
@@ -37,6 +38,20 @@ pub fn is_potentially_mutated<'tcx>(variable: HirId, expr: &'tcx Expr<'_>, cx: &
     mutated_variables(expr, cx).map_or(true, |mutated| mutated.contains(&variable))
 }
 
+/// Returns the set of local variables whose value is moved, entirely or partially, in `body`.
+pub fn moved_variables(cx: &LateContext<'_>, body_owner: LocalDefId, body: &hir::Body<'_>) -> HirIdSet {
+    variable_moves(cx, body_owner, body).into_keys().collect()
+}
+
+/// Returns the ids of the expressions moving the value of each local variable, entirely or
+/// partially, in `body`.
+pub fn variable_moves(cx: &LateContext<'_>, body_owner: LocalDefId, body: &hir::Body<'_>) -> HirIdMap<Vec<HirId>> {
+    let mut delegate = MovedVarsDelegate::default();
+    let infcx = cx.tcx.infer_ctxt().build();
+    ExprUseVisitor::new(&mut delegate, &infcx, body_owner, cx.param_env, cx.typeck_results()).consume_body(body);
+    delegate.moves
+}
+
 pub fn is_potentially_local_place(local_id: HirId, place: &Place<'_>) -> bool {
     match place.base {
         PlaceBase::Local(id) => id == local_id,
@@ -48,6 +63,25 @@ pub fn is_potentially_local_place(local_id: HirId, place: &Place<'_>) -> bool {
     }
 }
 
+#[derive(Default)]
+struct MovedVarsDelegate {
+    moves: HirIdMap<Vec<HirId>>,
+}
+
+impl<'tcx> Delegate<'tcx> for MovedVarsDelegate {
+    fn consume(&mut self, cmt: &PlaceWithHirId<'tcx>, diag_expr_id: HirId) {
+        if let PlaceBase::Local(vid) = cmt.place.base {
+            self.moves.entry(vid).or_default().push(diag_expr_id);
+        }
+    }
+
+    fn borrow(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId, _: ty::BorrowKind) {}
+
+    fn mutate(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
+
 struct MutVarsDelegate {
     used_mutably: HirIdSet,
     skip: bool,
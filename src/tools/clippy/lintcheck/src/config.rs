@@ -41,6 +41,12 @@ pub(crate) struct LintcheckConfig {
     /// Run clippy on the dependencies of crates specified in crates-toml
     #[clap(long, conflicts_with("max_jobs"))]
     pub recursive: bool,
+    /// Print the warnings added and removed since the previous run
+    #[clap(long, conflicts_with("fix"))]
+    pub diff: bool,
+    /// Print the time clippy spent checking lints for each crate
+    #[clap(long, conflicts_with_all(["fix", "recursive"]))]
+    pub timings: bool,
 }
 
 impl LintcheckConfig {
@@ -298,9 +298,16 @@ fn is_cache_dir(entry: &DirEntry) -> bool {
     }
 }
 
+/// An entry of the `-Ztime-passes-format=json` output of rustc
+#[derive(Deserialize)]
+struct TimePassesEntry {
+    pass: String,
+    time: f64,
+}
+
 impl Crate {
     /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
-    /// issued
+    /// issued, and with `--timings` the time spent checking lints
     #[allow(clippy::too_many_arguments)]
     fn run_clippy_lints(
         &self,
@@ -311,7 +318,7 @@ fn run_clippy_lints(
         config: &LintcheckConfig,
         lint_filter: &[String],
         server: &Option<LintcheckServer>,
-    ) -> Vec<ClippyWarning> {
+    ) -> (Vec<ClippyWarning>, Option<Duration>) {
         // advance the atomic index by one
         let index = target_dir_index.fetch_add(1, Ordering::SeqCst);
         // "loop" the index within 0..thread_limit
@@ -356,6 +363,11 @@ fn run_clippy_lints(
             clippy_args.extend(lint_filter.iter().map(std::string::String::as_str));
         }
 
+        if config.timings {
+            // rustc reports the duration of each of its passes, clippy's lints run in `lint_checking`
+            clippy_args.extend(["-Ztime-passes", "-Ztime-passes-format=json"]);
+        }
+
         if let Some(server) = server {
             let target = shared_target_dir.join("recursive");
 
@@ -383,14 +395,27 @@ fn run_clippy_lints(
 
             assert_eq!(status.code(), Some(0));
 
-            return Vec::new();
+            return (Vec::new(), None);
         }
 
         cargo_clippy_args.extend(clippy_args);
 
+        // use the looping index to create individual target dirs
+        let target_dir = shared_target_dir.join(format!("_{thread_index:?}"));
+
+        if config.timings {
+            // make sure the crate is checked again rather than replaying its cached diagnostics
+            let status = Command::new(env::var("CARGO").unwrap_or("cargo".into()))
+                .args(["clean", "--quiet", "--package", &self.name])
+                .env("CARGO_TARGET_DIR", &target_dir)
+                .current_dir(&self.path)
+                .status()
+                .expect("failed to run cargo");
+            assert!(status.success(), "failed to clean {} {}", self.name, self.version);
+        }
+
         let all_output = Command::new(&cargo_clippy_path)
-            // use the looping index to create individual target dirs
-            .env("CARGO_TARGET_DIR", shared_target_dir.join(format!("_{thread_index:?}")))
+            .env("CARGO_TARGET_DIR", &target_dir)
             .args(&cargo_clippy_args)
             .current_dir(&self.path)
             .output()
@@ -424,7 +449,7 @@ fn run_clippy_lints(
                 );
             }
             // fast path, we don't need the warnings anyway
-            return Vec::new();
+            return (Vec::new(), None);
         }
 
         // get all clippy warnings and ICEs
@@ -435,7 +460,16 @@ fn run_clippy_lints(
             })
             .collect();
 
-        warnings
+        let lint_checking_time = config.timings.then(|| {
+            stderr
+                .lines()
+                .filter_map(|line| serde_json::from_str::<TimePassesEntry>(line.strip_prefix("time: ")?).ok())
+                .filter(|entry| entry.pass == "lint_checking")
+                .map(|entry| Duration::from_secs_f64(entry.time))
+                .sum()
+        });
+
+        (warnings, lint_checking_time)
     }
 }
 
@@ -581,6 +615,11 @@ fn main() {
 
     let (crates, recursive_options) = read_crates(&config.sources_toml_path);
     let old_stats = read_stats_from_file(&config.lintcheck_results_path);
+    let old_log = if config.diff {
+        fs::read_to_string(&config.lintcheck_results_path).unwrap_or_default()
+    } else {
+        String::new()
+    };
 
     let counter = AtomicUsize::new(1);
     let lint_filter: Vec<String> = config
@@ -635,9 +674,9 @@ fn main() {
         LintcheckServer::spawn(recursive_options)
     });
 
-    let mut clippy_warnings: Vec<ClippyWarning> = crates
+    let (clippy_warnings, timings): (Vec<Vec<ClippyWarning>>, Vec<Option<Duration>>) = crates
         .par_iter()
-        .flat_map(|krate| {
+        .map(|krate| {
             krate.run_clippy_lints(
                 &cargo_clippy_path,
                 &clippy_driver_path,
@@ -648,7 +687,8 @@ fn main() {
                 &server,
             )
         })
-        .collect();
+        .unzip();
+    let mut clippy_warnings: Vec<ClippyWarning> = clippy_warnings.into_iter().flatten().collect();
 
     if let Some(server) = server {
         clippy_warnings.extend(server.warnings());
@@ -692,9 +732,70 @@ fn main() {
 
     println!("Writing logs to {}", config.lintcheck_results_path.display());
     fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
-    fs::write(&config.lintcheck_results_path, text).unwrap();
+    fs::write(&config.lintcheck_results_path, &text).unwrap();
 
     print_stats(old_stats, new_stats, &config.lint_filter);
+
+    if config.diff {
+        print_warnings_diff(&read_warnings(&old_log), &read_warnings(&text), &config.lint_filter);
+    }
+
+    if config.timings {
+        let timings = crates
+            .iter()
+            .zip(timings)
+            .filter_map(|(krate, time)| Some((format!("{} {}", krate.name, krate.version), time?)))
+            .collect();
+        print_timings(timings);
+    }
+}
+
+/// read the reported warnings from the content of a lintcheck-log file
+fn read_warnings(log: &str) -> HashSet<&str> {
+    log.lines()
+        .skip_while(|line| *line != "### Reports")
+        // Skipping the `Reports:` label
+        .skip(1)
+        .take_while(|line| *line != "### Stats:")
+        .filter(|line| !line.is_empty() && !line.starts_with("| file |") && !line.starts_with("| --- |"))
+        .collect()
+}
+
+/// print the warnings that appeared or disappeared between runs
+fn print_warnings_diff(old_warnings: &HashSet<&str>, new_warnings: &HashSet<&str>, lint_filter: &[String]) {
+    // the filter uses `clippy::lint-name` while the logs use `clippy::lint_name`
+    let lint_filter: Vec<String> = lint_filter.iter().map(|lint| lint.replace('-', "_")).collect();
+
+    let mut added: Vec<&str> = new_warnings.difference(old_warnings).copied().collect();
+    let mut removed: Vec<&str> = old_warnings
+        .difference(new_warnings)
+        .copied()
+        .filter(|warning| lint_filter.is_empty() || lint_filter.iter().any(|lint| warning.contains(lint.as_str())))
+        .collect();
+    added.sort_unstable();
+    removed.sort_unstable();
+
+    println!("\nAdded warnings ({}):", added.len());
+    for warning in added {
+        println!("+ {warning}");
+    }
+
+    println!("\nRemoved warnings ({}):", removed.len());
+    for warning in removed {
+        println!("- {warning}");
+    }
+}
+
+/// print the time spent checking lints per crate, slowest first
+fn print_timings(mut timings: Vec<(String, Duration)>) {
+    timings.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    println!("\nTime spent checking lints:");
+    for (krate, time) in &timings {
+        println!("{:>9.3}s {krate}", time.as_secs_f64());
+    }
+    let total: Duration = timings.iter().map(|(_, time)| *time).sum();
+    println!("{:>9.3}s total", total.as_secs_f64());
 }
 
 /// read the previous stats from the lintcheck-log file
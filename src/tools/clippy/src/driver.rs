@@ -17,8 +17,10 @@
 extern crate rustc_session;
 extern crate rustc_span;
 
-use rustc_interface::interface;
+use rustc_driver::Compilation;
+use rustc_interface::{interface, Queries};
 use rustc_session::config::ErrorOutputType;
+use rustc_session::lint::Level;
 use rustc_session::parse::ParseSess;
 use rustc_session::EarlyDiagCtxt;
 use rustc_span::symbol::Symbol;
@@ -130,6 +132,19 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
     #[allow(rustc::bad_opt_access)]
     fn config(&mut self, config: &mut interface::Config) {
         let conf_path = clippy_config::lookup_conf_file();
+
+        // The lint levels from `clippy.toml` go before the ones from the command line, so that those
+        // and the `[lints]` table of `Cargo.toml`, which cargo passes as flags, take precedence
+        let levels = clippy_config::LintLevels::read(&conf_path);
+        let conf_lint_opts = levels
+            .allow
+            .iter()
+            .map(|lint| (lint, Level::Allow))
+            .chain(levels.warn.iter().map(|lint| (lint, Level::Warn)))
+            .chain(levels.deny.iter().map(|lint| (lint, Level::Deny)))
+            .map(|(lint, level)| (lint.replace('-', "_"), level));
+        config.opts.lint_opts.splice(0..0, conf_lint_opts);
+
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
         config.psess_created = Some(Box::new(move |psess| {
@@ -150,10 +165,11 @@ fn config(&mut self, config: &mut interface::Config) {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_config::Conf::read(sess, &conf_path);
+            let conf = clippy_config::Conf::read(sess.dcx(), sess.source_map(), &conf_path);
             clippy_lints::register_lints(lint_store, conf);
             clippy_lints::register_pre_expansion_lints(lint_store, conf);
             clippy_lints::register_renamed(lint_store);
+            clippy_lints::register_lint_groups(sess, lint_store, conf);
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -165,6 +181,15 @@ fn config(&mut self, config: &mut interface::Config) {
         // Disable flattening and inlining of format_args!(), so the HIR matches with the AST.
         config.opts.unstable_opts.flatten_format_args = false;
     }
+
+    fn after_analysis<'tcx>(&mut self, _: &interface::Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        // Every lint is emitted by now, so the `#[clippy::expect(..)]` attributes can be checked
+        queries
+            .global_ctxt()
+            .unwrap()
+            .enter(clippy_lints::lint_unfulfilled_expectations);
+        Compilation::Continue
+    }
 }
 
 #[allow(clippy::ignored_unit_patterns)]
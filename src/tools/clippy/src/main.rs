@@ -43,6 +43,10 @@ pub fn main() {
         return;
     }
 
+    if env::args().any(|a| a == "--print-config") {
+        process::exit(clippy_lints::print_config());
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
@@ -159,6 +163,7 @@ pub fn help_message() -> &'static str {
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint
+    <cyan,bold>--print-config</>           Print the configuration options, their values and the lints they affect
 
 See all options with <cyan,bold>cargo check --help</>.
 
@@ -0,0 +1,44 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use test_utils::{CARGO_CLIPPY_PATH, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+fn print_config(conf_dir: &Path) -> String {
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .env("CLIPPY_CONF_DIR", conf_dir)
+        .env_remove("CARGO_PKG_RUST_VERSION")
+        .arg("clippy")
+        .arg("--print-config")
+        .output()
+        .unwrap();
+
+    println!("status: {}", output.status);
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // Skip the line with the path of the configuration file
+    stdout.lines().skip(1).map(|line| format!("{line}\n")).collect()
+}
+
+#[test]
+fn test_print_config_round_trip() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let printed = print_config(&root.join("tests/print_config"));
+    assert!(printed.contains("\nmsrv = \"1.60.0\"\n"));
+    assert!(printed.contains("\ntoo-many-lines-threshold = 50\n"));
+    assert!(printed.contains("\n# trivial-copy-size-limit is not set\n"));
+
+    // The printed configuration is read back into the same configuration
+    let conf_dir = root.join("target").join("print_config_test");
+    fs::create_dir_all(&conf_dir).unwrap();
+    fs::write(conf_dir.join("clippy.toml"), &printed).unwrap();
+    assert_eq!(print_config(&conf_dir), printed);
+}
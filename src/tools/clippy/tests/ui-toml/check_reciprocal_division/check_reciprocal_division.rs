@@ -0,0 +1,11 @@
+#![warn(clippy::suboptimal_flops)]
+
+fn main() {
+    let x = 3f64;
+    let y = 2f64;
+    let _ = 1.0 / x * y;
+    let _ = y * (1.0 / (x + 1.0));
+    // Cases where the lint shouldn't be applied
+    let _ = 1.0 / x;
+    let _ = 2.0 / x * y;
+}
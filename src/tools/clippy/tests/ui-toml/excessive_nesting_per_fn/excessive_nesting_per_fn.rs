@@ -0,0 +1,53 @@
+#![warn(clippy::excessive_nesting)]
+#![allow(clippy::never_loop, clippy::redundant_closure_call)]
+
+mod a {
+    mod b {
+        pub struct X;
+        impl X {
+            // items don't count towards the nesting of a function
+            pub fn fine(&self, x: u32) {
+                if x > 0 {
+                    if x > 1 {
+                        println!("{x}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn too_deep(x: u32) {
+    if x > 0 {
+        loop {
+            if x > 1 {
+                println!("{x}");
+            }
+            if x > 2 {
+                let f = || {
+                    //~^ ERROR: this block is too nested
+                    println!("{x}");
+                };
+                f();
+            }
+            break;
+        }
+    }
+}
+
+fn outer() {
+    if true {
+        // nested functions are measured on their own
+        fn inner(x: u32) {
+            if x > 0 {
+                println!("{x}");
+            }
+        }
+        inner(1);
+    }
+}
+
+fn main() {
+    too_deep(3);
+    outer();
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::exit)]
+
+fn fatal() -> ! {
+    std::process::exit(1)
+}
+
+mod cli {
+    pub fn run() {
+        std::process::exit(0);
+    }
+
+    pub fn other() {
+        std::process::exit(0);
+        //~^ ERROR: usage of `process::exit`
+    }
+}
+
+fn main() {
+    cli::run();
+    cli::other();
+    fatal();
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::regex_creation_in_loops)]
+
+struct Grammar;
+
+impl Grammar {
+    fn compile(_source: &str) -> Self {
+        Self
+    }
+}
+
+fn main() {
+    for _ in 0..10 {
+        let _ = Grammar::compile("expr = term");
+    }
+
+    for source in ["a", "b"] {
+        let _ = Grammar::compile(source);
+    }
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::expensive_log_arguments)]
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        if $crate::log_enabled() {
+            println!($($arg)+);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! log_enabled {
+    () => {
+        $crate::log_enabled()
+    };
+}
+
+pub fn log_enabled() -> bool {
+    true
+}
+
+pub fn dump_state() -> String {
+    String::new()
+}
+
+fn main() {
+    let name = String::from("x");
+    debug!("{}", name.clone());
+    //~^ ERROR: expensive expression passed to a logging macro
+    debug!("{}", name.to_string());
+    //~^ ERROR: expensive expression passed to a logging macro
+    debug!("{}", format!("{name}!"));
+    //~^ ERROR: expensive expression passed to a logging macro
+    debug!("{}", dump_state());
+    //~^ ERROR: expensive expression passed to a logging macro
+
+    debug!("{name}");
+    debug!("{}", name.len());
+    println!("{}", dump_state());
+    if log_enabled!() {
+        debug!("{}", dump_state());
+    }
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::suboptimal_flops, clippy::imprecise_flops)]
+
+fn main() {
+    let x = 3f64;
+    let y = 2f64;
+    // Suggestions that improve accuracy are still emitted
+    let _ = 2f64.powf(x);
+    let _ = x.powf(1.0 / 2.0);
+    let _ = x.exp() - 1.0;
+
+    // Suggestions that only improve performance are not
+    let _ = x * y + 1.0;
+    let _ = x.powi(2) + y;
+    let _ = x.powf(3.0);
+    let _ = x.powf(2.0);
+    let _ = x.powf(-1.0);
+    let _ = x * x * x;
+    let _ = x.exp() * y.exp();
+    let s = x.sin();
+    let c = x.cos();
+    let _ = s + c;
+}
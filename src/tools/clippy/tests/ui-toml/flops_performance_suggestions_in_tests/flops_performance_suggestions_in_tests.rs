@@ -0,0 +1,26 @@
+//@compile-flags: --test
+#![warn(clippy::suboptimal_flops)]
+
+fn main() {
+    let x = 3f64;
+    let y = 2f64;
+    let _ = x * y + 1.0;
+}
+
+#[cfg(test)]
+mod tests {
+    fn cube(x: f64) -> f64 {
+        x * x * x
+    }
+
+    #[test]
+    fn test_fn() {
+        let x = 3f64;
+        let y = 2f64;
+        // Suggestions that improve accuracy are still emitted
+        let _ = 2f64.powf(x);
+        // Suggestions that only improve performance are not
+        let _ = x * y + 1.0;
+        let _ = x.powi(2) + cube(y);
+    }
+}
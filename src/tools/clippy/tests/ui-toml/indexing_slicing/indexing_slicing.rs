@@ -0,0 +1,31 @@
+//@compile-flags: --test
+#![warn(clippy::indexing_slicing, clippy::out_of_bounds_indexing)]
+#![allow(unconditional_panic)]
+
+fn main() {
+    let x = vec![0; 5];
+    let _ = x[2];
+    let _ = &x[1..];
+}
+
+#[test]
+fn test_fn() {
+    let x = vec![0; 5];
+    let _ = x[2];
+    let _ = &x[1..];
+    // `out_of_bounds_indexing` is still linted
+    let y = [0; 5];
+    let _ = y[5];
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper(x: &[u8]) -> u8 {
+        x[0]
+    }
+
+    #[test]
+    fn test_helper() {
+        assert_eq!(helper(&[1]), 1);
+    }
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::library_env_access)]
+#![crate_type = "lib"]
+
+pub mod config {
+    pub fn load() -> Option<String> {
+        std::env::var("KEY").ok()
+    }
+
+    pub mod nested {
+        pub fn load() -> Option<String> {
+            std::env::var("NESTED").ok()
+        }
+    }
+}
+
+pub mod configuration {
+    pub fn load() -> Option<String> {
+        std::env::var("KEY").ok()
+        //~^ ERROR: call to `std::env::var` in a library crate
+    }
+}
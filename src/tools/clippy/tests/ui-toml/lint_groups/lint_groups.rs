@@ -0,0 +1,12 @@
+//@no-rustfix
+#![warn(clippy::numerics, clippy::fused_ops)]
+
+fn compare(x: f64, y: f64) -> bool {
+    x == y
+}
+
+fn fma(a: f64, b: f64, c: f64) -> f64 {
+    a * b + c
+}
+
+fn main() {}
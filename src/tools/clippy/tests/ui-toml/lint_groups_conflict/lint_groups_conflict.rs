@@ -0,0 +1,4 @@
+//@error-in-other-file: lint group `clippy::float_cmp` from `clippy.toml` conflicts with an existing lint or lint group
+//@error-in-other-file: lint group `clippy::style` from `clippy.toml` conflicts with an existing lint or lint group
+
+fn main() {}
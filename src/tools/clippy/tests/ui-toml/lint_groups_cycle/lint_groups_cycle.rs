@@ -0,0 +1,5 @@
+//@error-in-other-file: lint group `clippy::first` from `clippy.toml` is in or depends on a cycle of lint groups
+//@error-in-other-file: lint group `clippy::second` from `clippy.toml` is in or depends on a cycle of lint groups
+//@error-in-other-file: lint group `clippy::third` from `clippy.toml` is in or depends on a cycle of lint groups
+
+fn main() {}
@@ -0,0 +1,8 @@
+//@no-rustfix
+#![warn(clippy::floats)]
+
+fn compare(x: f64, y: f64) -> bool {
+    x == y
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+//@error-in-other-file: unknown lint `clippy::float_compare` in the lint group `clippy::numerics` from `clippy.toml`
+//@error-in-other-file: lint `clippy::should_assert_eq` in the lint group `clippy::numerics` from `clippy.toml` has been removed
+
+fn main() {}
@@ -0,0 +1,20 @@
+//@no-rustfix
+
+fn compare(x: f64, y: f64) -> bool {
+    x == y
+}
+
+#[allow(clippy::float_cmp)]
+fn compare_allowed(x: f64, y: f64) -> bool {
+    x == y
+}
+
+fn fma(a: f64, b: f64, c: f64) -> f64 {
+    a * b + c
+}
+
+fn early_return(x: u32) -> u32 {
+    return x;
+}
+
+fn main() {}
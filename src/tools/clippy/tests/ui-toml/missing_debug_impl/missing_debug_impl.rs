@@ -0,0 +1,16 @@
+#![warn(clippy::missing_debug_impl)]
+#![crate_type = "lib"]
+
+pub struct Handle;
+
+pub struct Other;
+//~^ ERROR: exported type `Other` does not implement `Debug`
+
+pub mod ffi {
+    pub struct RawContext;
+
+    pub struct Handle;
+
+    pub struct RawBuffer;
+    //~^ ERROR: exported type `RawBuffer` does not implement `Debug`
+}
@@ -0,0 +1,40 @@
+#![warn(clippy::pattern_type_mismatch)]
+
+fn main() {
+    let mut values = vec![(2, 3), (3, 4)];
+    for (a, b) in &mut values {
+        //~^ ERROR: type of pattern does not match the expression type
+        *a += *b;
+    }
+
+    // only matches through shared references
+    for (a, b) in &values {
+        let _ = a + b;
+    }
+    let value = &Some(23);
+    if let Some(v) = value {
+        let _ = v;
+    }
+
+    let value = &mut Some(23);
+    if let Some(v) = value {
+        //~^ ERROR: type of pattern does not match the expression type
+        *v += 1;
+    }
+
+    let mut nested = (1, &mut (2, 3));
+    let (a, (b, c)) = &mut nested;
+    //~^ ERROR: type of pattern does not match the expression type
+    *a += *b + *c;
+
+    // the inner reference is shared, so `b` and `c` are `ref` bindings
+    let mut nested = (1, &(2, 3));
+    let (_, (b, c)) = &mut nested;
+    let _ = b + c;
+
+    // the outer reference is shared, so `b` and `c` are `ref` bindings
+    let mut inner = (2, 3);
+    let nested = &(1, &mut inner);
+    let (_, (b, c)) = nested;
+    let _ = b + c;
+}
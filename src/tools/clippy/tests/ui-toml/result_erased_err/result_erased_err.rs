@@ -0,0 +1,19 @@
+#![crate_type = "lib"]
+#![warn(clippy::result_erased_err)]
+
+pub mod report {
+    #[derive(Debug)]
+    pub struct Report(pub String);
+}
+
+pub fn erased(s: &str) -> Result<u8, report::Report> {
+    //~^ ERROR: this returns a `Result` with a type-erased `Err` type
+    s.parse().map_err(|_| report::Report(s.to_owned()))
+}
+
+#[allow(clippy::result_erased_err)]
+pub mod allowed {
+    pub fn erased(s: &str) -> Result<u8, super::report::Report> {
+        s.parse().map_err(|_| super::report::Report(s.to_owned()))
+    }
+}
@@ -0,0 +1,16 @@
+#![warn(clippy::shadow_same, clippy::shadow_reuse, clippy::shadow_unrelated)]
+
+fn main() {
+    let s = String::from("a");
+    let s = s.trim();
+    let s = &s;
+    let s = 1;
+
+    let buf = vec![1u8];
+    let buf = buf.len();
+
+    let x = 1;
+    let x = x + 1;
+    //~^ ERROR: `x` is shadowed
+    let _ = (s, buf, x);
+}
@@ -0,0 +1,26 @@
+//@no-rustfix
+#![warn(clippy::significant_drop_in_scrutinee)]
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+impl Guard {
+    fn value(&self) -> u32 {
+        1
+    }
+}
+
+fn acquire() -> Guard {
+    Guard
+}
+
+fn main() {
+    match acquire().value() {
+        //~^ ERROR: temporary with significant `Drop` in `match` scrutinee will live until the
+        1 => println!("one"),
+        _ => println!("other"),
+    }
+}
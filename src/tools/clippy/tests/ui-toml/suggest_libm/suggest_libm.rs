@@ -0,0 +1,69 @@
+#![feature(lang_items, start)]
+#![warn(clippy::imprecise_flops, clippy::suboptimal_flops)]
+#![no_std]
+
+#[allow(dead_code)]
+mod libm {
+    pub fn log1p(_x: f64) -> f64 {
+        unimplemented!()
+    }
+    pub fn log1pf(_x: f32) -> f32 {
+        unimplemented!()
+    }
+    pub fn expm1(_x: f64) -> f64 {
+        unimplemented!()
+    }
+    pub fn fma(_x: f64, _y: f64, _z: f64) -> f64 {
+        unimplemented!()
+    }
+}
+
+trait Float {
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+}
+
+impl Float for f32 {
+    fn ln(self) -> Self {
+        unimplemented!()
+    }
+    fn exp(self) -> Self {
+        unimplemented!()
+    }
+}
+
+impl Float for f64 {
+    fn ln(self) -> Self {
+        unimplemented!()
+    }
+    fn exp(self) -> Self {
+        unimplemented!()
+    }
+}
+
+fn check(x: f64, y: f32, a: f64, b: f64) {
+    let _ = (1.0 + x).ln();
+    let _ = (y + 1.0).ln();
+    let _ = x.exp() - 1.0;
+    let _ = a * b + x;
+    let _ = x - a * b;
+
+    // Cases where the lint shouldn't be applied
+    let _ = (2.0 + x).ln();
+    let _ = x.exp() - 2.0;
+    let _ = y.exp();
+}
+
+#[start]
+fn main(_argc: isize, _argv: *const *const u8) -> isize {
+    check(1.0, 1.0, 2.0, 3.0);
+    0
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
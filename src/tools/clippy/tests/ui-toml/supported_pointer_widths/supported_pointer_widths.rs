@@ -0,0 +1,34 @@
+//@ignore-32bit
+#![warn(clippy::cast_possible_truncation, clippy::transmute_pointer_sized_int)]
+
+fn main() {
+    let a: usize = 42;
+    let b: u64 = 42;
+    let c: u32 = 42;
+    let _ = a as u32;
+    let _ = a as u16;
+    let _ = a as u8;
+    let _ = b as usize;
+    let _ = c as usize;
+    let _: u64 = unsafe { std::mem::transmute(a) };
+}
+
+#[cfg(target_pointer_width = "64")]
+fn gated(a: usize, b: u64) {
+    let _ = a as u32;
+    let _ = b as usize;
+    let _ = a as u8;
+    let _: u64 = unsafe { std::mem::transmute(a) };
+}
+
+// `any(..)` with different widths doesn't pin the pointer width
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+fn gated_any(a: usize) {
+    let _ = a as u32;
+}
+
+#[cfg(all(not(target_os = "none"), target_pointer_width = "64"))]
+fn gated_all(a: usize, b: u64) {
+    let _ = a as u32;
+    let _ = b as usize;
+}
@@ -40,3 +40,11 @@ fn main() {
     let _ = HashMap;
     let _: usize = 64_usize;
 }
+
+struct Fields {
+    map: std::collections::HashMap<u8, u8>,
+}
+
+fn turbofish() {
+    let _ = Vec::<std::net::Ipv4Addr>::new();
+}
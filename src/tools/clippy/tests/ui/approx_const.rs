@@ -83,4 +83,10 @@ fn main() {
     let almost_tau = 6.28;
     //~^ ERROR: approximate value of `f{32, 64}::consts::TAU` found
     let no_tau = 6.3;
+
+    let tau_with_separators = 6.283_185_3;
+    //~^ ERROR: approximate value of `f{32, 64}::consts::TAU` found
+    let suffixed_tau = 6.283_185_3_f32;
+    //~^ ERROR: approximate value of `f32::consts::TAU` found
+    let no_tau_with_separators = 6.283_2_0;
 }
@@ -86,6 +86,34 @@ fn assign_to_uninit_mut_var(b: HasCloneFrom) {
     a = b.clone();
 }
 
+fn assign_to_moved_var(b: HasCloneFrom) {
+    let mut a = HasCloneFrom;
+    let _moved = a;
+    a = b.clone();
+}
+
+fn assign_to_maybe_moved_var(b: HasCloneFrom, cond: bool) {
+    let mut a = HasCloneFrom;
+    if cond {
+        let _moved = a;
+    }
+    a = b.clone();
+}
+
+fn assign_to_var_moved_after(b: HasCloneFrom) -> HasCloneFrom {
+    let mut a = HasCloneFrom;
+    a = b.clone();
+    a
+}
+
+fn assign_to_var_moved_in_loop(b: HasCloneFrom) {
+    let mut a = HasCloneFrom;
+    for _ in 1..10 {
+        a = b.clone();
+        let _moved = a;
+    }
+}
+
 #[derive(Clone)]
 pub struct HasDeriveClone;
 
@@ -0,0 +1,7 @@
+fn main() {
+    let x = 2.0_f64;
+    #[clippy::author]
+    let _ = x.max(std::f64::consts::PI).sqrt();
+    #[clippy::author]
+    let _ = x.max(f64::NAN);
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::cast_float_round_trip)]
+
+fn main() {
+    let x = 2.0_f32;
+    let _ = (x as f64).sqrt() as f32;
+    let _ = (x as f64).abs() as f32;
+    let _ = ((x + 1.0) as f64).floor() as f32;
+    let _ = (x as f64).sin() as f32;
+    let _ = (-x as f64).exp() as f32;
+
+    // Cases where the lint shouldn't be applied
+    let y = 2.0_f64;
+    let _ = y.sqrt() as f32;
+    let _ = (x as f64).powi(2) as f32;
+    let z = 2_i32;
+    let _ = (z as f64).sqrt() as f32;
+}
+
+#[warn(clippy::cast_lossless, clippy::cast_possible_truncation)]
+fn no_duplicate_warnings(x: f32) -> f32 {
+    (x as f64).sqrt() as f32
+}
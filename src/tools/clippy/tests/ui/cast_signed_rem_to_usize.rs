@@ -0,0 +1,48 @@
+#![warn(clippy::cast_signed_rem_to_usize)]
+#![allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::cast_possible_wrap, unused)]
+
+fn index(values: &[u8], x: i32, n: i32) -> u8 {
+    values[(x % n) as usize]
+    //~^ ERROR: casting the remainder of a possibly negative `i32` to `usize`
+}
+
+fn bucket(hash: i64, buckets: &[Vec<u8>]) -> &Vec<u8> {
+    &buckets[(hash % buckets.len() as i64) as usize]
+    //~^ ERROR: casting the remainder of a possibly negative `i64` to `usize`
+}
+
+fn negative_offset(values: &[u8], x: i8) -> u8 {
+    values[((x - 1) % 3) as usize]
+    //~^ ERROR: casting the remainder of a possibly negative `i8` to `usize`
+}
+
+fn no_lint(values: &[u8], x: i32, y: u32) {
+    let _ = values[(y % 4) as usize];
+    let _ = values[x.rem_euclid(4) as usize];
+    let _ = values[(x.saturating_abs() % 4) as usize];
+    let _ = (x % 4) as u32;
+}
+
+#[clippy::msrv = "1.37"]
+fn msrv_1_37(x: i32) -> usize {
+    (x % 4) as usize
+}
+
+#[clippy::msrv = "1.38"]
+fn msrv_1_38(x: i32) -> usize {
+    (x % 4) as usize
+    //~^ ERROR: casting the remainder of a possibly negative `i32` to `usize`
+}
+
+#[clippy::msrv = "1.51"]
+const fn const_msrv_1_51(x: i32) -> usize {
+    (x % 4) as usize
+}
+
+#[clippy::msrv = "1.52"]
+const fn const_msrv_1_52(x: i32) -> usize {
+    (x % 4) as usize
+    //~^ ERROR: casting the remainder of a possibly negative `i32` to `usize`
+}
+
+fn main() {}
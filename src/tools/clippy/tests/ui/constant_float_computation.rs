@@ -0,0 +1,49 @@
+#![warn(clippy::constant_float_computation)]
+
+const TWO: f64 = 2.0;
+
+fn main() {
+    let x = 2.0_f64;
+    let _ = 2.0_f64.powf(10.0);
+    let _ = 16.0_f32.sqrt();
+    let _ = 2.0_f64.sqrt();
+    let _ = 2.0_f32.sqrt();
+    let _ = TWO.sqrt();
+    let _ = 8.0_f64.log2();
+    let _ = (-4.0_f64).abs();
+    let _ = 2.0_f64.powi(-2);
+    let _ = 4.0_f64.sqrt() * 2.0;
+    let _ = (-2.0_f64).powi(3).max(1.0);
+    let _ = x * 4.0_f64.sqrt();
+
+    // Cases where the lint shouldn't be applied
+    let _ = x.sqrt();
+    let _ = 2.0_f64 * 3.0;
+    let _ = (-1.0_f64).sqrt();
+}
+
+fn log_with_constant_base() {
+    let x = 2.0_f64;
+    let _ = 100.0_f64.log(10.0);
+    let _ = 4.0_f32.log(2.0);
+    let _ = 2.0_f64.log(TWO);
+
+    // Cases where the lint shouldn't be applied
+    let _ = x.log(10.0);
+    let _ = 10.0_f64.log(x);
+}
+
+#[clippy::msrv = "1.42"]
+fn msrv_1_42() {
+    let _ = 10.0_f64.log2();
+}
+
+#[clippy::msrv = "1.43"]
+fn msrv_1_43() {
+    let _ = 10.0_f64.log2();
+}
+
+fn rounding() {
+    let _ = 2.5_f64.floor();
+    let _ = 2.5_f32.round();
+}
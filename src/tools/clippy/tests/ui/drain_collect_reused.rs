@@ -0,0 +1,56 @@
+#![deny(clippy::drain_collect)]
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+
+fn vec_reused() -> Vec<i32> {
+    let mut v = vec![1, 2, 3];
+    let taken: Vec<i32> = v.drain(..).collect();
+    v.push(4);
+    taken
+}
+
+fn string_reused() -> String {
+    let mut s = String::from("abc");
+    let taken: String = s.drain(..).collect();
+    s.push('d');
+    taken
+}
+
+// Behind a reference, the caller keeps the capacity of its collection
+fn vec_split_off(v: &mut Vec<i32>) -> Vec<i32> {
+    v.split_off(0)
+}
+
+fn vecdeque_split_off() -> VecDeque<i32> {
+    let mut v = VecDeque::from([1, 2, 3]);
+    v.split_off(0)
+}
+
+// Behind a reference, the caller keeps the capacity of its collection
+fn string_split_off(s: &mut String) -> String {
+    s.split_off(0)
+}
+
+fn split_off_reused() -> Vec<i32> {
+    let mut v = vec![1, 2, 3];
+    let taken = v.split_off(0);
+    v.push(4);
+    taken
+}
+
+fn split_off_not_zero(v: &mut Vec<i32>) -> Vec<i32> {
+    v.split_off(1)
+}
+
+fn vec_split_off_owned() -> Vec<i32> {
+    let mut v = vec![1, 2, 3];
+    v.split_off(0)
+}
+
+fn string_split_off_owned() -> String {
+    let mut s = String::from("abc");
+    s.split_off(0)
+}
+
+fn main() {}
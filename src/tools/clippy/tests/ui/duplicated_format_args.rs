@@ -0,0 +1,22 @@
+#![warn(clippy::duplicated_format_args)]
+#![allow(clippy::uninlined_format_args)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn next() -> i32 {
+    0
+}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    println!("{} {} {}", p.x, p.y, p.x);
+    let _ = format!("{first} {} {second}", p.y, first = p.x + 1, second = p.x + 1);
+
+    // not duplicated
+    println!("{0} {0} {1}", p.x, p.y);
+    // may have side effects
+    println!("{} {}", next(), next());
+}
@@ -26,3 +26,8 @@ extern "C" fn eh_personality() {
     loop {}
     //~^ ERROR: empty `loop {}` wastes CPU cycles
 }
+
+fn halt() -> ! {
+    // This should NOT trigger the lint either, as it is a common way to halt
+    loop {}
+}
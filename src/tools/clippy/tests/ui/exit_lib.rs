@@ -0,0 +1,31 @@
+#![warn(clippy::exit)]
+#![crate_type = "lib"]
+
+pub fn run() {
+    helper();
+}
+
+fn helper() {
+    std::process::abort();
+    //~^ ERROR: usage of `process::abort`
+}
+
+pub struct Cli;
+
+impl Cli {
+    pub fn fail(&self) {
+        let report = || std::process::exit(1);
+        //~^ ERROR: usage of `process::exit`
+        report();
+    }
+}
+
+pub mod inner {
+    pub fn outer() {
+        fn nested() {
+            std::process::exit(2);
+            //~^ ERROR: usage of `process::exit`
+        }
+        nested();
+    }
+}
@@ -0,0 +1,45 @@
+#![warn(clippy::extend_single_element)]
+
+use std::collections::{BTreeMap, BinaryHeap, HashSet, LinkedList, VecDeque};
+use std::iter;
+
+fn main() {
+    let mut vec = vec![1];
+    vec.extend(iter::once(2));
+    vec.extend([3]);
+    vec.extend([4].iter().copied());
+
+    let mut deque = VecDeque::new();
+    deque.extend(iter::once(1));
+    let mut list = LinkedList::new();
+    list.extend([1]);
+    let mut heap = BinaryHeap::new();
+    heap.extend(iter::once(1));
+
+    let mut set = HashSet::new();
+    set.extend(iter::once(1));
+    let mut map = BTreeMap::new();
+    map.extend([(1, "one")]);
+
+    let mut s = String::new();
+    s.extend(iter::once('a'));
+    s.extend(["bc"]);
+
+    let mut strings = vec![String::new()];
+    let x = String::from("x");
+    strings.extend([x].iter().cloned());
+
+    // don't lint, several elements
+    vec.extend([5, 6]);
+    // don't lint, not a tuple
+    let pair = (2, "two");
+    map.extend(iter::once(pair));
+    // don't lint, `Extend<&T>`
+    vec.extend(iter::once(&7));
+    set.extend([&3]);
+}
+
+fn returns_unit(set: &mut HashSet<i32>) {
+    // don't lint, the result of `insert` isn't `()`
+    set.extend(iter::once(2))
+}
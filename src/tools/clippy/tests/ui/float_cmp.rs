@@ -137,4 +137,8 @@ fn main() {
     // the comparison should also look through references
     &0.0 == &ZERO;
     &&&&0.0 == &&&&ZERO;
+
+    // rounding methods on constants are evaluated as well
+    x64 == (-0.4_f64).round();
+    x64 == f64::INFINITY.floor();
 }
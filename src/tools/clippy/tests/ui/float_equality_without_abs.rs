@@ -41,3 +41,24 @@ pub fn main() {
     let _ = f32::EPSILON > (a - b).abs();
     let _ = f64::EPSILON > (a as f64 - b as f64).abs();
 }
+
+const ERROR_MARGIN: f64 = f64::EPSILON;
+
+pub fn with_error_margin(a: f64, b: f64) {
+    let error_margin = f64::EPSILON;
+    let _ = a - b < error_margin;
+    //~^ ERROR: float equality check without `.abs()`
+    let _ = (a - b) < ERROR_MARGIN;
+    //~^ ERROR: float equality check without `.abs()`
+    let _ = error_margin > a - b;
+    //~^ ERROR: float equality check without `.abs()`
+    let _ = a - b < std::f64::EPSILON;
+    //~^ ERROR: float equality check without `.abs()`
+
+    // those are correct
+    let _ = (a - b).abs() < error_margin;
+    let mut margin = f64::EPSILON;
+    margin *= 2.0;
+    let _ = a - b < margin;
+    let _ = a - b < 0.5;
+}
@@ -0,0 +1,31 @@
+#![warn(clippy::float_sort_by_partial_cmp)]
+#![allow(clippy::unnecessary_sort_by)]
+
+fn main() {
+    let mut v = vec![1.0_f64, 0.5, 2.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    v.sort_unstable_by(|a, b| b.partial_cmp(a).expect("NaN"));
+
+    let mut pairs = [(1.0_f32, 'a'), (0.5, 'b')];
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    // not floats
+    let mut ints = vec![3, 1, 2];
+    ints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // already handles NaN
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    v.sort_by(|a, b| a.total_cmp(b));
+}
+
+#[clippy::msrv = "1.61"]
+fn msrv_1_61() {
+    let mut v = vec![1.0_f64, 0.5, 2.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+#[clippy::msrv = "1.62"]
+fn msrv_1_62() {
+    let mut v = vec![1.0_f64, 0.5, 2.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
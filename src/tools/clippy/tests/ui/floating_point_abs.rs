@@ -81,3 +81,13 @@ fn main() {
     not_fake_abs4(A { a: 5.0, b: 5.0 });
     not_fake_abs5(A { a: 5.0, b: 5.0 });
 }
+
+fn _signum_abs(a: A) {
+    let x = 5.0f64;
+    let _ = x * x.signum();
+    let _ = x.signum() * x;
+    let _ = a.a * a.a.signum();
+    let _ = (x + 1.0) * (x + 1.0).signum();
+    // Cases where the lint shouldn't be applied
+    let _ = a.a * a.b.signum();
+}
@@ -6,14 +6,18 @@ fn main() {
     let _ = x.exp() - 1.0;
     let _ = x.exp() - 1.0 + 2.0;
     let _ = (x as f32).exp() - 1.0 + 2.0;
-    // Cases where the lint shouldn't be applied
     let _ = x.exp() - 2.0;
     let _ = x.exp() - 1.0 * 2.0;
+    let _ = x.exp() - 2.5;
+    // Cases where the lint shouldn't be applied
+    let _ = x.exp() - 0.5;
 
     let x = 2f64;
     let _ = x.exp() - 1.0;
     let _ = x.exp() - 1.0 + 2.0;
-    // Cases where the lint shouldn't be applied
     let _ = x.exp() - 2.0;
     let _ = x.exp() - 1.0 * 2.0;
+    let _ = x.exp() - 2.5;
+    // Cases where the lint shouldn't be applied
+    let _ = x.exp() - 0.5;
 }
@@ -0,0 +1,16 @@
+#![warn(clippy::suboptimal_flops)]
+
+fn main() {
+    let x = 2f32;
+    let y = 3f32;
+    let _ = x.exp() * y.exp();
+    let _ = x.exp() / y.exp();
+    let _ = x.exp2() * (y + 1.0).exp2();
+    let _ = x.exp() / (y - 1.0).exp();
+    let _ = (x * 2.0).exp() * y.exp();
+
+    // Cases where the lint shouldn't be applied
+    let _ = x.exp() * y.exp2();
+    let _ = x.exp() + y.exp();
+    let _ = x.exp() * y;
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::suboptimal_flops)]
+
+struct A {
+    a: f64,
+}
+
+fn main() {
+    let x = 3.5f32;
+    let _ = x - x.trunc();
+    let _ = x % 1.0;
+    let _ = x.abs() - x.abs().floor();
+    let _ = (x + 1.0) - (x + 1.0).trunc();
+
+    let a = A { a: 3.5 };
+    let _ = a.a - a.a.trunc();
+    let _ = a.a % 1.0;
+    // Cases where the lint shouldn't be applied
+    let _ = x - x.floor();
+    let _ = x % 2.0;
+    let y = 2.5f32;
+    let _ = x - y.trunc();
+}
@@ -6,8 +6,12 @@ fn main() {
     let _ = (x * x + y * y).sqrt();
     let _ = ((x + 1f32) * (x + 1f32) + y * y).sqrt();
     let _ = (x.powi(2) + y.powi(2)).sqrt();
-    // Cases where the lint shouldn't be applied
-    // TODO: linting this adds some complexity, but could be done
+    let _ = (x * x + y.powi(2)).sqrt();
+    let _ = (x.powi(2) + (y + 1f32) * (y + 1f32)).sqrt();
     let _ = x.mul_add(x, y * y).sqrt();
+    let _ = x.mul_add(x, y.powi(2)).sqrt();
+    // Cases where the lint shouldn't be applied
     let _ = (x * 4f32 + y * y).sqrt();
+    let _ = (x * y + y * y).sqrt();
+    let _ = x.mul_add(y, y * y).sqrt();
 }
@@ -56,3 +56,20 @@ fn check_ln1p() {
 }
 
 fn main() {}
+
+fn check_ln1p_constants() {
+    let x = 1f32;
+    let _ = (x + 3.0).ln();
+    let _ = (3.0 + x).ln();
+    let _ = (x / 2.0 + 3.0).ln();
+    let _ = (x + 2.5).ln();
+    let _ = (x + TWO).ln();
+    // Cases where the lint shouldn't be applied
+    let _ = (x + 0.5).ln();
+    let _ = (1.0 + x + 0.5).ln();
+    let _ = (x + 1.0 + 0.5).ln();
+
+    let x = 1f64;
+    let _ = (x + 3.0).ln();
+    let _ = (3.0 + x).ln();
+}
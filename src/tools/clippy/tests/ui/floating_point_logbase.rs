@@ -15,3 +15,30 @@ fn main() {
     let _ = x.powf(3.2) / y.ln();
     let _ = x.log(5f32) / y.log(7f32);
 }
+
+fn _specialized_bases() {
+    let x = 3f32;
+    let _ = x.ln() / 2f32.ln();
+    let _ = x.ln() / 10f32.ln();
+    let _ = x.log2() / std::f32::consts::E.log2();
+    let _ = (x + 1.0).log10() / 2f32.log10();
+
+    let x = 3f64;
+    let _ = x.ln() / 2f64.ln();
+    let _ = x.log10() / 10f64.log10();
+}
+
+fn _ln_constant_division() {
+    let x = 3f32;
+    let _ = x.ln() / std::f32::consts::LN_2;
+    let _ = x.ln() / std::f32::consts::LN_10;
+    let _ = x.ln() / 0.6931472;
+    let _ = (x + 1.0).ln() / 2.302585;
+
+    let x = 3f64;
+    let _ = x.ln() / std::f64::consts::LN_2;
+    let _ = x.ln() / std::f64::consts::LN_10;
+    // Cases where the lint shouldn't be applied
+    let _ = x.ln() / 0.7;
+    let _ = x.log2() / std::f64::consts::LN_2;
+}
@@ -0,0 +1,26 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::suboptimal_flops)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+macro_rules! log_base_2 {
+    ($x:expr) => {{
+        let x: f64 = $x;
+        x.log(2.0)
+    }};
+}
+
+fn main() {
+    let x = 3f64;
+    let _ = x.log(2.0);
+
+    // The suggestion changes the macro definition, so it is only `MaybeIncorrect`
+    let _ = log_base_2!(x);
+
+    // Don't lint in external macros
+    let _ = external!({
+        let y = 3f64;
+        y.log(2.0)
+    });
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::suboptimal_flops)]
+#![allow(clippy::match_bool)]
+
+fn main() {
+    let a = 1.0f64;
+    let b = 2.0f64;
+    let _ = if a < b { a } else { b };
+    let _ = if a <= b { b } else { a };
+    let _ = if a > b { a } else { b };
+    let _ = if a >= b { b } else { a };
+    let _ = if a < 0.0 { a } else { 0.0 };
+    let _ = match a < b {
+        true => a,
+        false => b,
+    };
+    let _ = match a > b {
+        false => a,
+        _ => b,
+    };
+
+    // Cases where the lint shouldn't be applied
+    let _ = if a < b { b } else { 2.0 };
+    let _ = if a == b { a } else { b };
+    let (x, y) = (1, 2);
+    let _ = if x < y { x } else { y };
+}
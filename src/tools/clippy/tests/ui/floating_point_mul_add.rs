@@ -57,3 +57,29 @@ fn add(self, _: f64) -> Self {
 
     let _ = a + b * c;
 }
+
+fn _parenthesized_operands() {
+    let a: f64 = 1234.567;
+    let b: f64 = 45.67834;
+    let c: f64 = 0.0004;
+    let d: f64 = 0.0001;
+
+    let _ = (a + b) * c + d;
+    let _ = a * (b - c) + d;
+    let _ = d - a * (b + c);
+    let _ = d + (a - b) * (c + d);
+}
+
+fn _compound_assignment() {
+    let a: f64 = 1234.567;
+    let b: f64 = 45.67834;
+    let mut c: f64 = 0.0004;
+
+    c += a * b;
+    c -= a * (b + 1.0);
+    let _ = c;
+}
+
+// Constant and static items are constant contexts as well
+const _: f64 = 1234.567 * 45.67834 + 0.0004;
+static IN_STATIC: f64 = 1234.567 * 45.67834 + 0.0004;
@@ -47,4 +47,20 @@ fn main() {
     let _ = x.powf(-2.1);
     let _ = x.powf(-2_147_483_649.0);
     let _ = x.powf(2_147_483_648.0);
+
+    // Squares
+    let _ = x.powf(2.0);
+    let _ = 1.0 / x.powf(2.0);
+    let _ = &x.powf(2.0);
+    let _ = (x + 1.0).powf(2.0);
+}
+
+fn _reciprocal() {
+    let x = 3f32;
+    let _ = x.powf(-1.0);
+    let _ = (x + 1.0).powf(-1.0);
+    let y = 3f64;
+    let _ = y.powf(-1.0);
+    // `1.0 / x` is only linted with the `check-reciprocal-division` configuration
+    let _ = 1.0 / x * 2.0;
 }
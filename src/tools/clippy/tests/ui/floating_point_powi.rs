@@ -30,3 +30,28 @@ fn main() {
     let _ = x.powi(one + 1);
     let _ = (x.powi(2) + y.powi(2)).sqrt();
 }
+
+fn _repeated_multiplication() {
+    let x = 3f32;
+    let _ = x * x * x;
+    let _ = (x * x) * (x * x);
+    let _ = (x + 1.0) * (x + 1.0) * (x + 1.0);
+    let _ = x * x * x * x * x;
+
+    // Cases where the lint shouldn't be applied
+    let y = 2f32;
+    let _ = x * x;
+    let _ = x * x * y;
+    let _ = x * (x + 1.0) * x;
+}
+
+fn _repeated_pure_calls() {
+    let x = 3f32;
+    let _ = x.sin() * x.sin() * x.sin();
+
+    // Cases where the lint shouldn't be applied
+    let mut v = vec![1f32, 2.0, 3.0];
+    let _ = v.pop().unwrap() * v.pop().unwrap() * v.pop().unwrap();
+    let b = [0u8; 4];
+    let _ = f32::from_be_bytes(b) * f32::from_be_bytes(b) * f32::from_be_bytes(b);
+}
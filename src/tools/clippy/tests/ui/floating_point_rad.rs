@@ -27,3 +27,20 @@ fn main() {
     let _ = x * 180f32 / std::f32::consts::E;
     let _ = x * std::f32::consts::E / 180f32;
 }
+
+fn _folded_and_approximate_factors() {
+    let x = 3f64;
+    let _ = x * (180.0 / std::f64::consts::PI);
+    let _ = x * (std::f64::consts::PI / 180.0);
+    let _ = (180.0 / std::f64::consts::PI) * x;
+    let _ = 180.0 * x / std::f64::consts::PI;
+    let _ = x * 57.29578;
+    let _ = x * 0.017453;
+    let y = 3f32;
+    let _ = y * 57.29578;
+    let _ = 0.0174533 * y;
+    // Cases where the lint shouldn't be applied
+    let _ = x * 57.3;
+    let _ = x * 0.0175;
+    let _ = x * (90.0 / std::f64::consts::PI);
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::suboptimal_flops)]
+
+fn main() {
+    let x = 1.5f64;
+    let s = x.sin();
+    let c = x.cos();
+
+    let angle = 0.5f32;
+    let cosine = (angle * 2.0).cos();
+    let other = 3.0;
+    let sine = (angle * 2.0).sin();
+    let _ = x.sin() * x.cos();
+    let _ = ((angle * 2.0).sin(), (angle * 2.0).cos());
+
+    // Cases where the lint shouldn't be applied
+    let mut y = 1.5f64;
+    let _s = y.sin();
+    y += 1.0;
+    let _c = y.cos();
+
+    let _s = x.sin();
+    let _c = (x + 1.0).cos();
+    let _ = x.sin() * (x + 1.0).cos();
+    let _ = x.sin() * x.sin();
+
+    let _ = (s, c, cosine, other, sine);
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::hardcoded_paths)]
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let _ = File::create("/tmp/output.log");
+    //~^ ERROR: hardcoded path starting with `/tmp`
+    let _ = fs::read_to_string("C:\\Users\\me\\config.toml");
+    //~^ ERROR: hardcoded path starting with `C:\`
+    let _ = Path::new("data\\input.txt");
+    //~^ ERROR: hardcoded path with a `\` separator
+    let home = PathBuf::from("/home/me");
+    //~^ ERROR: hardcoded path starting with `/home/`
+
+    // Should not lint
+    let _ = File::open("data/input.txt");
+    let _ = Path::new("relative").join("file");
+    let _ = "/tmp/not-a-path".len();
+    let _ = std::env::temp_dir().join("output.log");
+}
@@ -0,0 +1,42 @@
+#![warn(clippy::hash_order_dependent_iteration)]
+#![allow(clippy::iter_over_hash_type, clippy::needless_collect)]
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+fn main() {
+    let map: HashMap<String, u32> = HashMap::new();
+    let set: HashSet<u32> = HashSet::new();
+    let other: HashSet<u32> = HashSet::new();
+
+    let _: Vec<&String> = map.keys().collect();
+    //~^ ERROR: collecting the elements of a hash-based type into an ordered collection
+    let _: Vec<u32> = set.iter().map(|x| x * 2).filter(|x| *x > 3).collect();
+    //~^ ERROR: collecting the elements of a hash-based type into an ordered collection
+    let _: String = map.keys().map(String::as_str).collect();
+    //~^ ERROR: collecting the elements of a hash-based type into an ordered collection
+
+    let mut v = vec![1];
+    v.extend(set.iter().copied());
+    //~^ ERROR: extending an ordered collection with the elements of a hash-based type
+    v.extend(&set);
+    //~^ ERROR: extending an ordered collection with the elements of a hash-based type
+
+    let _ = set.iter().eq(other.iter());
+    //~^ ERROR: comparing the elements of a hash-based type in iteration order
+    let _ = v.iter().cmp(&set);
+    //~^ ERROR: comparing the elements of a hash-based type in iteration order
+
+    // the order does not end up in the result
+    let _: HashSet<u32> = set.iter().map(|x| x + 1).collect();
+    let _: BTreeSet<&String> = map.keys().collect();
+    let _: u32 = set.iter().sum();
+    let _ = set == other;
+    let _: Vec<u32> = set.iter().copied().collect::<BTreeSet<_>>().into_iter().collect();
+
+    // the result is sorted afterwards
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort_unstable();
+
+    // not hash-based
+    let _: Vec<u32> = v.iter().copied().collect();
+}
@@ -135,3 +135,26 @@ const fn issue12103(x: u32) -> Option<u32> {
     // Should not issue an error in `const` context
     if x > 42 { Some(150) } else { None }
 }
+
+fn side_effects(b: bool, x: u32) {
+    // Should suggest `bool::then` since the value has side effects
+    let _ = if b { Some(foo()) } else { None };
+    //~^ ERROR: this could be simplified with `bool::then`
+
+    // Should suggest `bool::then_some` since the value is a plain local
+    let _ = if b { Some(x) } else { None };
+    //~^ ERROR: this could be simplified with `bool::then_some`
+}
+
+#[clippy::msrv = "1.61"]
+fn _msrv_1_61(b: bool, x: u32) {
+    // `bool::then_some` was stabilized in 1.62
+    let _ = if b { Some(x) } else { None };
+    //~^ ERROR: this could be simplified with `bool::then`
+}
+
+fn try_in_value(b: bool, v: Option<u32>) -> Option<u32> {
+    // Should not issue an error since the `?` would return from the closure
+    let _ = if b { Some(v?) } else { None };
+    None
+}
@@ -28,3 +28,14 @@ fn main() {
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
 }
+
+fn chars(s: &str, owned: String) {
+    let _: String = s.chars().collect();
+    let _ = owned.chars().collect::<String>();
+
+    // `to_owned` would create a `&str`
+    let r = &s;
+    let _: String = r.chars().collect();
+    // not collecting into a `String`
+    let _: Vec<char> = s.chars().collect();
+}
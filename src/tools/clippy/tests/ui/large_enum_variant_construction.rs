@@ -0,0 +1,34 @@
+#![warn(clippy::large_enum_variant)]
+#![allow(dead_code)]
+
+enum Tuple {
+    //~^ ERROR: large size difference between variants
+    A(u8),
+    B(u8, [u8; 1000]),
+}
+fn tuple(x: [u8; 1000]) -> Tuple {
+    Tuple::B(0, x)
+}
+
+enum Struct {
+    //~^ ERROR: large size difference between variants
+    A(u8),
+    B { x: u8, y: [u8; 1000] },
+}
+fn struct_variant(y: [u8; 1000]) -> [Struct; 2] {
+    [Struct::B { x: 0, y }, Struct::B { y: [0; 1000], x: 0 }]
+}
+
+enum Matched {
+    //~^ ERROR: large size difference between variants
+    A(u8),
+    B([u8; 1000]),
+}
+fn matched(m: Matched) -> u8 {
+    match m {
+        Matched::A(x) => x,
+        Matched::B(x) => x[0],
+    }
+}
+
+fn main() {}
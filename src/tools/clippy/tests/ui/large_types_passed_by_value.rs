@@ -64,3 +64,13 @@ fn foo(x: LargeAndCopy) {
 }
 
 fn main() {}
+
+fn not_copy_consumed(a: Large) -> Box<Large> {
+    Box::new(a)
+}
+fn not_copy_partially_consumed(a: (Large, Small)) -> Small {
+    a.1
+}
+fn not_copy_borrowed(a: (Large, Small)) -> u8 {
+    a.0.0[0]
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::library_env_access)]
+#![crate_type = "lib"]
+
+use std::env;
+
+pub fn config() -> Option<String> {
+    let _ = env::args().count();
+    //~^ ERROR: call to `std::env::args` in a library crate
+    env::set_var("KEY", "value");
+    //~^ ERROR: call to `std::env::set_var` in a library crate
+    std::env::var("KEY").ok()
+    //~^ ERROR: call to `std::env::var` in a library crate
+}
+
+pub fn not_linted() {
+    let _ = env::current_dir();
+    let _ = env::temp_dir();
+    let _ = option_env!("KEY");
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::manual_filter)]
+
+fn main() {
+    let _ = match Some(0) {
+        Some(x) if x > 0 => Some(x),
+        _ => None,
+    };
+
+    let _ = match Some(String::new()) {
+        Some(s) if s.is_empty() => Some(s),
+        _ => None,
+    };
+
+    // don't lint, `None` doesn't cover the `Some` values failing the guard
+    let _ = match Some(0) {
+        Some(x) if x > 0 => Some(x),
+        Some(_) => Some(1),
+        _ => None,
+    };
+
+    // don't lint, not the bound value
+    let _ = match Some(0) {
+        Some(x) if x > 0 => Some(x + 1),
+        _ => None,
+    };
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::manual_ignore_case_cmp)]
+
+fn main() {}
+
+fn ascii(a: &str, b: String, c: char, d: u8) {
+    let _ = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+    let _ = a.to_ascii_uppercase() != b.to_ascii_uppercase();
+    let _ = a.to_ascii_lowercase() == "abc";
+    let _ = "ABC" == b.to_ascii_uppercase();
+    let _ = c.to_ascii_lowercase() == 'a';
+    let _ = d.to_ascii_uppercase() == b'A';
+}
+
+fn unicode(a: &str, b: &str) {
+    let _ = a.to_lowercase() == "abc";
+    let _ = a.to_lowercase() == b.to_lowercase();
+}
+
+fn not_linted(a: &str, b: &str) {
+    // different cases
+    let _ = a.to_ascii_lowercase() == b.to_ascii_uppercase();
+    // literal is not in the converted case, so this is always false
+    let _ = a.to_ascii_lowercase() == "Abc";
+    // literal is not ASCII
+    let _ = a.to_lowercase() == "äbc";
+    let _ = a == b;
+}
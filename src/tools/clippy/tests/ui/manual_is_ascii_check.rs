@@ -1,4 +1,4 @@
-#![allow(unused, dead_code)]
+#![allow(unused, dead_code, clippy::manual_range_contains)]
 #![warn(clippy::manual_is_ascii_check)]
 
 fn main() {
@@ -55,3 +55,16 @@ fn msrv_1_47() {
     const FOO: bool = matches!('x', '0'..='9');
     const BAR: bool = matches!('x', '0'..='9' | 'a'..='f' | 'A'..='F');
 }
+
+fn comparisons(c: char, b: u8) {
+    let _ = c >= '0' && c <= '9';
+    let _ = 'a' <= c && c <= 'z';
+    let _ = c <= 'Z' && c >= 'A';
+    let _ = b >= b'0' && b <= b'9';
+    let _ = b'z' >= b && b >= b'a';
+
+    // not linted
+    let _ = c >= 'a' && c <= 'f';
+    let _ = c > '0' && c <= '9';
+    let _ = c >= '0' && b <= b'9';
+}
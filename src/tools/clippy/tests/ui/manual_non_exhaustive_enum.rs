@@ -85,4 +85,20 @@ enum ExpectLint {
     _C,
 }
 
+// hidden variant is matched on in the crate
+enum MatchedHidden {
+    //~^ ERROR: this seems like a manual implementation of the non-exhaustive pattern
+    A,
+    B,
+    #[doc(hidden)]
+    _C,
+}
+fn bar(x: MatchedHidden) -> u8 {
+    match x {
+        MatchedHidden::A => 1,
+        MatchedHidden::B => 2,
+        MatchedHidden::_C => unreachable!(),
+    }
+}
+
 fn main() {}
@@ -0,0 +1,59 @@
+#![warn(clippy::manual_option_xor)]
+
+fn main() {
+    let a = Some(1);
+    let b = None;
+
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(x)) => Some(x),
+        _ => None,
+    };
+
+    let _ = match (a, b) {
+        (None, Some(y)) => Some(y),
+        (Some(x), None) => Some(x),
+        _ => None,
+    };
+
+    let _ = match (a, b) {
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        _ => None,
+    };
+
+    // don't lint, one of the cases is missing
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        _ => None,
+    };
+
+    // don't lint, not the same value
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(_)) => Some(0),
+        _ => None,
+    };
+
+    // don't lint, with a guard
+    let _ = match (a, b) {
+        (Some(x), None) if x > 0 => Some(x),
+        (None, Some(x)) => Some(x),
+        _ => None,
+    };
+}
+
+#[clippy::msrv = "1.36"]
+fn msrv_1_36(a: Option<u8>, b: Option<u8>) -> Option<u8> {
+    match (a, b) {
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        _ => None,
+    }
+}
+
+#[clippy::msrv = "1.37"]
+fn msrv_1_37(a: Option<u8>, b: Option<u8>) -> Option<u8> {
+    match (a, b) {
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        _ => None,
+    }
+}
@@ -0,0 +1,56 @@
+#![warn(clippy::manual_option_zip)]
+
+fn main() {
+    let a = Some(1);
+    let b = Some("b");
+
+    let _ = match (a, b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    };
+
+    let _ = if let (Some(x), Some(y)) = (a, b) {
+        Some((x, y))
+    } else {
+        None
+    };
+
+    let _ = match (a.map(|x| x + 1), b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    };
+
+    // don't lint, the values are swapped
+    let _ = match (a, b) {
+        (Some(x), Some(y)) => Some((y, x)),
+        _ => None,
+    };
+
+    // don't lint, the values are not the bindings
+    let _ = match (a, b) {
+        (Some(x), Some(_)) => Some((x, "c")),
+        _ => None,
+    };
+
+    // don't lint, bound by reference
+    let _ = match (&a, &b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    };
+}
+
+#[clippy::msrv = "1.45"]
+fn msrv_1_45(a: Option<u8>, b: Option<u8>) -> Option<(u8, u8)> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    }
+}
+
+#[clippy::msrv = "1.46"]
+fn msrv_1_46(a: Option<u8>, b: Option<u8>) -> Option<(u8, u8)> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    }
+}
@@ -0,0 +1,30 @@
+#![warn(clippy::manual_path_join)]
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+fn main() {}
+
+fn direct(dir: &str, name: &str) {
+    let _ = File::open(format!("{dir}/{name}"));
+    let _ = File::create(format!("{}/{}.txt", dir, name));
+    let _ = fs::read_to_string(dir.to_owned() + "/" + name);
+    let _ = Path::new(&format!("{dir}/file"));
+    let _ = PathBuf::from(format!("{dir}/{name}"));
+}
+
+fn through_binding(dir: &str, name: &str) {
+    let path = format!("{dir}/{name}");
+    let _ = File::open(&path);
+    // only linted once
+    let _ = fs::read(&path);
+}
+
+fn not_linted(dir: &str, name: &str) {
+    // no separator between two parts
+    let _ = File::open(format!("/tmp/{name}"));
+    let _ = File::open(format!("{dir}{name}"));
+    // not used as a path
+    let _ = format!("{dir}/{name}");
+    let _ = Path::new(dir).join(name);
+}
@@ -0,0 +1,51 @@
+#![warn(clippy::missing_debug_impl)]
+#![crate_type = "lib"]
+
+use std::fmt;
+
+pub struct A;
+//~^ ERROR: exported type `A` does not implement `Debug`
+
+pub enum B {
+    //~^ ERROR: exported type `B` does not implement `Debug`
+    X,
+}
+
+pub union C {
+    //~^ ERROR: exported type `C` does not implement `Debug`
+    a: u32,
+}
+
+pub mod inner {
+    pub struct D<T>(pub T);
+    //~^ ERROR: exported type `D` does not implement `Debug`
+}
+
+// Should not lint
+#[derive(Debug)]
+pub struct Derived;
+
+pub struct Manual;
+
+impl fmt::Debug for Manual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Manual")
+    }
+}
+
+pub struct Generic<T>(T);
+
+impl<T> fmt::Debug for Generic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Generic")
+    }
+}
+
+struct Private;
+
+mod private {
+    pub struct NotExported;
+}
+
+#[allow(clippy::missing_debug_impl)]
+pub struct Allowed;
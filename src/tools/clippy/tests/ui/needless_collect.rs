@@ -78,3 +78,10 @@ fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
 fn foo(_: impl IntoIterator<Item = usize>) {}
 fn bar<I: IntoIterator<Item = usize>>(_: Vec<usize>, _: I) {}
 fn baz<I: IntoIterator<Item = usize>>(_: I, _: (), _: impl IntoIterator<Item = char>) {}
+
+#[warn(clippy::needless_collect)]
+fn first_char(s: &str) {
+    let _ = s.chars().collect::<Vec<_>>()[0];
+    let _ = s.chars().collect::<Vec<_>>().first();
+    let _ = s.chars().collect::<Vec<_>>().len();
+}
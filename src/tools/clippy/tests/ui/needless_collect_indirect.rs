@@ -322,3 +322,8 @@ fn test_while_let_cond_false_case() {
         w.len();
     }
 }
+
+fn first_char(s: &str) {
+    let chars = s.chars().collect::<Vec<_>>();
+    let _ = chars.first();
+}
@@ -182,3 +182,8 @@ fn main() {
     // https://github.com/rust-lang/rust-clippy/issues/3144
     is_sync(HashSet::<usize>::new());
 }
+
+// don't lint exported functions, `avoid-breaking-exported-api` is `true` by default
+pub fn exported(v: Vec<i32>) {
+    assert_eq!(v.len(), 42);
+}
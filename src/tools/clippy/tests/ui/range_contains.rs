@@ -30,7 +30,7 @@ fn main() {
     999 < x || 1 > x;
 
     // not a range.contains
-    x > 8 && x < 12; // lower bound not inclusive
+    x > 8 && x < 9; // empty once the lower bound is made inclusive
     x < 8 && x <= 12; // same direction
     x >= 12 && 12 >= x; // same bounds
     x < 8 && x > 12; // wrong direction
@@ -75,3 +75,27 @@ fn msrv_1_35() {
     let x = 5;
     x >= 8 && x < 35;
 }
+
+fn exclusive_lower_bound() {
+    let x = 9_i32;
+    x > 8 && x < 12;
+    x > -8 && x <= 12;
+    12 > x && 8 < x;
+
+    // not linted: not an integer literal
+    const LO: i32 = 8;
+    x > LO && x < 12;
+    let y = 3.;
+    y > 0. && y < 1.;
+}
+
+fn ascii_ranges() {
+    let c = 'x';
+    // linted by `manual_is_ascii_check` instead
+    c >= 'a' && c <= 'z';
+    #[allow(clippy::manual_is_ascii_check)]
+    {
+        c >= 'a' && c <= 'z';
+        c >= '0' && c <= '9';
+    }
+}
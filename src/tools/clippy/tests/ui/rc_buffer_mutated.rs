@@ -0,0 +1,27 @@
+#![warn(clippy::rc_buffer)]
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct S {
+    // triggers lint, never mutated
+    bad: Rc<String>,
+    // does not trigger lint, mutated with `Rc::make_mut`
+    good1: Rc<Vec<u8>>,
+    // does not trigger lint, taken back out of the `Arc`
+    good2: Arc<PathBuf>,
+    // triggers lint, only the `Rc<Vec<u8>>` is mutated
+    bad2: Arc<Vec<u8>>,
+}
+
+fn push(s: &mut S) {
+    Rc::make_mut(&mut s.good1).push(0);
+}
+
+fn take(paths: Vec<Arc<PathBuf>>) -> Vec<PathBuf> {
+    paths.into_iter().filter_map(|p| Arc::try_unwrap(p).ok()).collect()
+}
+
+fn main() {}
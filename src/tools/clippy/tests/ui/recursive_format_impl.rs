@@ -332,3 +332,17 @@ fn main() {
     let c = C;
     c.to_string();
 }
+
+struct M(u8);
+
+impl fmt::Display for M {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 == 0 {
+            f.write_str(&self.to_string())
+            //~^ ERROR: using `self.to_string` in `fmt::Display` implementation will cause inf
+        } else {
+            write!(f, "{}", ToString::to_string(self))
+            //~^ ERROR: using `self.to_string` in `fmt::Display` implementation will cause inf
+        }
+    }
+}
@@ -156,6 +156,28 @@ fn main() {
     });
 }
 
+fn panics() {
+    if foo() {
+        panic!("I'm a teapot");
+    } else {
+        //~^ ERROR: redundant else block
+        println!("Well begun is half done.");
+    }
+    if foo() {
+        unreachable!()
+    } else {
+        //~^ ERROR: redundant else block
+        foo::<()>()
+    };
+
+    // `assert!` doesn't always panic
+    if foo() {
+        assert!(foo());
+    } else {
+        println!("Haste makes waste.");
+    }
+}
+
 fn foo<T>() -> T {
     unimplemented!("I'm not Santa Claus")
 }
@@ -0,0 +1,38 @@
+#![warn(clippy::regex_creation_in_loops)]
+
+extern crate regex;
+
+use regex::bytes::Regex as BRegex;
+use regex::{Regex, RegexSet};
+use std::sync::OnceLock;
+
+const PATTERN: &str = r"^\d+$";
+
+fn lint(lines: &[&str]) {
+    for line in lines {
+        let _ = Regex::new(r"^\d+$").unwrap().is_match(line);
+    }
+    let mut i = 0;
+    while i < lines.len() {
+        let _ = BRegex::new(PATTERN);
+        let _ = RegexSet::new(["a", "b"]);
+        i += 1;
+    }
+    let _ = lines.iter().filter(|line| Regex::new("[a-z]+").unwrap().is_match(line));
+}
+
+fn no_lint(lines: &[&str], patterns: &[&str]) {
+    let re = Regex::new(r"^\d+$").unwrap();
+    for line in lines {
+        let _ = re.is_match(line);
+    }
+    // the pattern changes in every iteration
+    for pattern in patterns {
+        let _ = Regex::new(pattern);
+    }
+    // lazily initialized once
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let _ = lines.iter().filter(|line| RE.get_or_init(|| Regex::new(PATTERN).unwrap()).is_match(line));
+}
+
+fn main() {}
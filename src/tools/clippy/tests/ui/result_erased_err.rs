@@ -0,0 +1,49 @@
+#![crate_type = "lib"]
+#![warn(clippy::result_erased_err)]
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::num::ParseIntError;
+
+pub fn boxed(s: &str) -> Result<u8, Box<dyn Error>> {
+    //~^ ERROR: this returns a `Result` with a type-erased `Err` type
+    Ok(s.parse()?)
+}
+
+pub fn boxed_send_sync(s: &str) -> Result<u8, Box<dyn Error + Send + Sync>> {
+    //~^ ERROR: this returns a `Result` with a type-erased `Err` type
+    Ok(s.parse()?)
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self, s: &str) -> Result<u8, Box<dyn Error>> {
+        //~^ ERROR: this returns a `Result` with a type-erased `Err` type
+        Ok(s.parse()?)
+    }
+}
+
+pub trait Parse {
+    fn parse(&self, s: &str) -> Result<u8, Box<dyn Error>>;
+    //~^ ERROR: this returns a `Result` with a type-erased `Err` type
+}
+
+// trait implementations can't change the error type
+impl Parse for Parser {
+    fn parse(&self, s: &str) -> Result<u8, Box<dyn Error>> {
+        Ok(s.parse()?)
+    }
+}
+
+fn private(s: &str) -> Result<u8, Box<dyn Error>> {
+    Ok(s.parse()?)
+}
+
+pub fn concrete(s: &str) -> Result<u8, ParseIntError> {
+    s.parse()
+}
+
+pub fn boxed_concrete(s: &str) -> Result<u8, Box<ParseIntError>> {
+    Ok(s.parse()?)
+}
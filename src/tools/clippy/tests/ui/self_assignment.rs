@@ -78,3 +78,19 @@ fn next(n: &mut usize) -> usize {
 }
 
 fn main() {}
+
+pub fn negatives_deliberate(mut a: usize) {
+    macro_rules! touch {
+        ($e:expr) => {
+            $e = $e
+        };
+    }
+    touch!(a);
+}
+
+pub fn raw_ptr(p: *mut u32) {
+    unsafe {
+        *p = *p;
+        //~^ ERROR: self-assignment of `*p` to `*p`
+    }
+}
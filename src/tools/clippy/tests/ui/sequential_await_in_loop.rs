@@ -0,0 +1,85 @@
+//@no-rustfix
+#![warn(clippy::sequential_await_in_loop)]
+#![allow(clippy::never_loop)]
+
+async fn fetch(id: u32) -> u32 {
+    id
+}
+
+async fn fetch_with(base: &str, id: u32) -> usize {
+    base.len() + id as usize
+}
+
+async fn lint(ids: Vec<u32>, base: String) {
+    for id in ids.iter() {
+        let value = fetch(*id).await;
+        //~^ ERROR: independent futures are awaited one after another in this loop
+        println!("{value}");
+    }
+
+    let mut total = 0;
+    for id in &ids {
+        let url = format!("{base}/{id}");
+        total += fetch_with(&url, *id).await;
+        //~^ ERROR: independent futures are awaited one after another in this loop
+    }
+    println!("{total}");
+
+    // `.await`s of closures and `async` blocks aren't counted
+    for id in &ids {
+        let value = fetch(*id).await;
+        //~^ ERROR: independent futures are awaited one after another in this loop
+        let _next = async move { fetch(value + 1).await };
+        let _retry = || async move { fetch(value).await };
+    }
+}
+
+async fn no_lint(ids: Vec<u32>) {
+    // the futures depend on the result of the previous iteration
+    let mut last = 0;
+    for id in &ids {
+        last = fetch(last + id).await;
+    }
+
+    // more than one await
+    for id in &ids {
+        let a = fetch(*id).await;
+        let b = fetch(a).await;
+        println!("{b}");
+    }
+
+    // the loop can be left early
+    for id in &ids {
+        if fetch(*id).await == 0 {
+            break;
+        }
+    }
+
+    // the future does not depend on the loop item
+    for _ in &ids {
+        fetch(0).await;
+    }
+
+    // the future depends on a local changed by the loop
+    let mut offset = 0;
+    for id in &ids {
+        offset += 1;
+        fetch(id + offset).await;
+    }
+
+    // the future is created from a binding of the loop body which isn't a `let`
+    for id in &ids {
+        if let Some(x) = id.checked_add(offset) {
+            fetch(x).await;
+        }
+    }
+
+    // the only `.await` is inside of an `async` block, which isn't awaited in the loop
+    let mut futures = Vec::new();
+    for id in &ids {
+        futures.push(async move { fetch(*id).await });
+    }
+    drop(futures);
+}
+
+fn main() {}
@@ -668,6 +668,46 @@ fn should_trigger_lint_without_significant_drop_in_arm() {
     };
 }
 
+fn should_trigger_lint_in_if_let() {
+    let mutex = Mutex::new(State {});
+
+    if let true = mutex.lock().unwrap().foo() {
+        //~^ ERROR: temporary with significant `Drop` in `if let` scrutinee will live until the
+        //~| NOTE: this might lead to deadlocks or other unexpected behavior
+        do_bar(&mutex);
+    }
+}
+
+fn should_not_trigger_lint_in_if_let() {
+    let mutex = Mutex::new(State {});
+
+    // Should not trigger lint because the guard is dropped before the `if let`.
+    let value = mutex.lock().unwrap().foo();
+    if let true = value {
+        do_bar(&mutex);
+    }
+
+    // Should not trigger lint because temporaries in an `if` condition are dropped before the body
+    // is executed.
+    if mutex.lock().unwrap().foo() {
+        do_bar(&mutex);
+    }
+}
+
+fn should_trigger_lint_in_nested_if_let() {
+    let mutex = Mutex::new(State {});
+
+    if let true = mutex.lock().unwrap().foo() {
+        //~^ ERROR: temporary with significant `Drop` in `if let` scrutinee will live until the
+        //~| NOTE: this might lead to deadlocks or other unexpected behavior
+        if let true = mutex.lock().unwrap().foo() {
+            //~^ ERROR: temporary with significant `Drop` in `if let` scrutinee will live until the
+            //~| NOTE: this might lead to deadlocks or other unexpected behavior
+            do_bar(&mutex);
+        }
+    }
+}
+
 fn should_not_trigger_on_significant_iterator_drop() {
     let lines = std::io::stdin().lines();
     for line in lines {
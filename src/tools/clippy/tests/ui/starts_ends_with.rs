@@ -51,3 +51,16 @@ fn ends_with() {
     "".chars().last() == Some('\n');
     Some('\n') != "".chars().last();
 }
+
+#[allow(clippy::unnecessary_operation)]
+fn bytes(s: &str) {
+    s.as_bytes()[0] == b'a';
+    b'a' != s.as_bytes()[0];
+    s.as_bytes().first() == Some(&b'a');
+    s.as_bytes()[s.len() - 1] == b'\n';
+    s.as_bytes().last() != Some(&b'a');
+
+    // not an ASCII byte, or not the first or last one
+    s.as_bytes()[0] == 0xC3;
+    s.as_bytes()[1] == b'a';
+}
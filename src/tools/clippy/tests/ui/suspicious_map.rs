@@ -32,3 +32,10 @@ fn negative() {
 fn do_something<T>(t: T) -> String {
     unimplemented!()
 }
+
+fn pure_closure() {
+    let _ = (0..3).map(|x| (x, x)).count();
+    //~^ ERROR: this call to `map()` won't have an effect on the call to `count()`
+    let _ = (0..3).map(|x| x as f64).count();
+    //~^ ERROR: this call to `map()` won't have an effect on the call to `count()`
+}
@@ -208,3 +208,9 @@ fn unary_minus_and_an_if_expression(s1: &S, s2: &S) -> i32 {
 }
 
 fn main() {}
+
+fn two_term_dot_product(a: &Vec3, b: &Vec3) -> f64 {
+    // There's no `b.y`
+    a.x * b.x + a.y * b.x
+    //~^ ERROR: this sequence of operators looks suspiciously like a bug
+}
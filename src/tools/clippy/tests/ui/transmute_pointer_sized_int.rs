@@ -0,0 +1,34 @@
+//@ignore-32bit
+#![warn(clippy::transmute_pointer_sized_int)]
+
+fn main() {
+    let a: usize = 42;
+    let b: i64 = 42;
+    unsafe {
+        let _: u64 = std::mem::transmute(a);
+        let _: isize = std::mem::transmute(b);
+        let _: i64 = std::mem::transmute::<usize, i64>(a);
+
+        // Not pointer-sized
+        let _: u64 = std::mem::transmute(b);
+        // Both pointer-sized
+        let _: isize = std::mem::transmute(a);
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+fn gated(a: usize) -> u64 {
+    unsafe { std::mem::transmute(a) }
+}
+
+#[cfg(any(target_pointer_width = "64", target_os = "linux"))]
+mod gated {
+    fn nested(a: u64) -> usize {
+        unsafe { std::mem::transmute(a) }
+    }
+}
+
+#[cfg(any(all(target_pointer_width = "64", unix), all(target_pointer_width = "64", windows)))]
+fn gated_any(a: usize) -> u64 {
+    unsafe { std::mem::transmute(a) }
+}
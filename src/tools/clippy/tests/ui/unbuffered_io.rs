@@ -0,0 +1,54 @@
+#![warn(clippy::unbuffered_io)]
+#![allow(clippy::unused_io_amount)]
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::net::TcpStream;
+
+fn write_lines(lines: &[&str]) -> io::Result<()> {
+    let mut file = File::create("out.txt")?;
+    for line in lines {
+        writeln!(file, "{line}")?;
+    }
+    let mut i = 0;
+    while i < lines.len() {
+        file.write_all(lines[i].as_bytes())?;
+        i += 1;
+    }
+    Ok(())
+}
+
+fn read_stream(stream: &mut TcpStream) -> io::Result<()> {
+    let mut byte = [0];
+    loop {
+        stream.read_exact(&mut byte)?;
+        if byte[0] == b'\n' {
+            return Ok(());
+        }
+    }
+}
+
+fn no_lint(lines: &[&str]) -> io::Result<()> {
+    // already buffered
+    let mut file = BufWriter::new(File::create("out.txt")?);
+    for line in lines {
+        writeln!(file, "{line}")?;
+    }
+    let mut reader = BufReader::new(File::open("in.txt")?);
+    let mut byte = [0];
+    for _ in 0..10 {
+        reader.read(&mut byte)?;
+    }
+
+    // not in a loop
+    let mut file = File::create("out.txt")?;
+    writeln!(file, "{}", lines.len())?;
+
+    // a new file every iteration
+    for line in lines {
+        let mut file = File::create(line)?;
+        file.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn main() {}
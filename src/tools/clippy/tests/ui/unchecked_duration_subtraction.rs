@@ -1,6 +1,6 @@
 #![warn(clippy::unchecked_duration_subtraction)]
 
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 fn main() {
     let _first = Instant::now();
@@ -13,4 +13,6 @@ fn main() {
     let _ = _first - Duration::from_secs(5);
 
     let _ = Instant::now() - second;
+
+    let _ = SystemTime::now() - second;
 }
@@ -0,0 +1,33 @@
+#![warn(clippy::unchecked_instant_difference)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let earlier = Instant::now();
+    let later = Instant::now();
+    let timeout = Duration::from_secs(5);
+
+    let _ = later - earlier;
+    let _ = earlier.elapsed() - timeout;
+    let _ = earlier.elapsed() - Duration::from_millis(10);
+
+    // Linted by `manual_instant_elapsed`
+    let _ = Instant::now() - earlier;
+    // Not an elapsed time
+    let _ = timeout - Duration::from_secs(1);
+    let _ = later.saturating_duration_since(earlier);
+}
+
+#[clippy::msrv = "1.38"]
+fn msrv_1_38() {
+    let earlier = Instant::now();
+    let later = Instant::now();
+    let _ = later - earlier;
+}
+
+#[clippy::msrv = "1.39"]
+fn msrv_1_39() {
+    let earlier = Instant::now();
+    let later = Instant::now();
+    let _ = later - earlier;
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::unchecked_system_time_difference)]
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let start = SystemTime::now();
+    let _ = SystemTime::now().duration_since(start).unwrap();
+    let _ = start.elapsed().unwrap();
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH).expect("time went backwards");
+
+    // Handled errors
+    let _ = start.elapsed().unwrap_or_default();
+    let _ = start.duration_since(UNIX_EPOCH).unwrap_or(Duration::ZERO);
+    if let Ok(elapsed) = start.elapsed() {}
+
+    // `Instant` is monotonic
+    let start = Instant::now();
+    let _ = Instant::now().duration_since(start);
+}
@@ -0,0 +1,35 @@
+//@no-rustfix
+#![allow(dead_code)]
+#![warn(clippy::float_cmp)]
+
+struct S {
+    a: u32,
+}
+
+#[clippy::expect(clippy::needless_return)]
+fn fulfilled() -> u32 {
+    return 1;
+}
+
+#[clippy::expect(clippy::needless_return)]
+//~^ ERROR: the expected lint `clippy::needless_return` was not emitted
+//~| NOTE: `-D clippy::unfulfilled-clippy-expectations` implied by `-D warnings`
+fn unfulfilled() -> u32 {
+    1
+}
+
+// `redundant_field_names` is an early lint
+#[clippy::expect(clippy::redundant_field_names)]
+fn early_lint(a: u32) -> S {
+    S { a: a }
+}
+
+fn statements(x: f64, y: f64) {
+    #[clippy::expect(clippy::float_cmp)]
+    let _ = x == y;
+    #[clippy::expect(clippy::float_cmp, clippy::needless_return)]
+    //~^ ERROR: the expected lint `clippy::needless_return` was not emitted
+    let _ = x != y;
+}
+
+fn main() {}
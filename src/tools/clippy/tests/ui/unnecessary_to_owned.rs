@@ -566,3 +566,14 @@ fn foo<Q: Borrow<str>>(&self, _: &String) {}
     HashSet::<i32>::new().foo::<&str>(&"".to_owned());
     HashSet::<String>::new().get(&1.to_string());
 }
+
+fn string_from(s: &str) {
+    require_str(&String::from(s)); //~ ERROR: unnecessary use of `String::from`
+    require_as_ref_str(String::from(s)); //~ ERROR: unnecessary use of `String::from`
+    require_impl_as_ref_str(String::from(s)); //~ ERROR: unnecessary use of `String::from`
+    require_deref_str(String::from("x")); //~ ERROR: unnecessary use of `String::from`
+
+    // Should not warn.
+    require_string(&String::from(s));
+    let _ = String::from(s);
+}
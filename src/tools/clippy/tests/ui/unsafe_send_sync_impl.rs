@@ -0,0 +1,36 @@
+#![warn(clippy::unsafe_send_sync_impl)]
+
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+struct Undocumented(Cell<u8>);
+
+unsafe impl Send for Undocumented {}
+//~^ ERROR: manual `unsafe impl Send`
+
+struct RawPointers {
+    ptr: *mut u8,
+    list: Vec<NonNull<u8>>,
+    len: usize,
+}
+
+// SAFETY: the buffer is never shared
+unsafe impl Send for RawPointers {}
+//~^ ERROR: manual `unsafe impl Send`
+
+struct Shared<T> {
+    value: Rc<T>,
+}
+
+unsafe impl<T> Sync for Shared<T> {}
+//~^ ERROR: manual `unsafe impl Sync`
+
+// Should not lint
+
+struct Documented(Cell<u8>);
+
+// SAFETY: the cell is only accessed from one thread at a time
+unsafe impl Sync for Documented {}
+
+fn main() {}
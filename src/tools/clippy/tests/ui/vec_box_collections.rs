@@ -0,0 +1,26 @@
+#![warn(clippy::vec_box)]
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::BuildHasherDefault;
+
+struct SizedStruct(i32);
+struct BigStruct([i32; 10000]);
+
+trait Trait {}
+
+struct ShouldTrigger {
+    deque: VecDeque<Box<SizedStruct>>,
+    hash_map: HashMap<u32, Box<SizedStruct>>,
+    btree_map: BTreeMap<String, Box<u8>>,
+    hasher: HashMap<u32, Box<u8>, BuildHasherDefault<std::collections::hash_map::DefaultHasher>>,
+}
+
+struct ShouldNotTrigger {
+    big: HashMap<u32, Box<BigStruct>>,
+    dyn_trait: HashMap<u32, Box<dyn Trait>>,
+    keys: BTreeMap<Box<u8>, u8>,
+    unsized_values: VecDeque<Box<[u8]>>,
+}
+
+fn main() {}
@@ -1,7 +1,8 @@
 #![warn(clippy::verbose_file_reads)]
+#![allow(clippy::unused_io_amount)]
 use std::env::temp_dir;
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 
 struct Struct;
 // To make sure we only warn on File::{read_to_end, read_to_string} calls
@@ -28,3 +29,47 @@ fn main() -> std::io::Result<()> {
     //~^ ERROR: use of `File::read_to_string`
     Ok(())
 }
+
+fn opened_once(path: &str) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    //~^ ERROR: use of `File::read_to_string`
+    Ok(contents)
+}
+
+fn read_loop(path: &str) -> io::Result<Vec<u8>> {
+    let mut file = File::open(path).expect("failed to open");
+    let mut bytes = Vec::new();
+    let mut chunk = [0; 1024];
+    loop {
+        //~^ ERROR: use of `File::read` in a loop
+        let n = file.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&chunk[..n]);
+    }
+    Ok(bytes)
+}
+
+fn no_read_loop(path: &str) -> io::Result<()> {
+    // not a loop
+    let mut file = File::open(path)?;
+    let mut chunk = [0; 1024];
+    file.read(&mut chunk)?;
+
+    // the file is used more than once
+    let mut file = File::open(path)?;
+    let mut bytes = Vec::new();
+    let mut chunk = [0; 1024];
+    loop {
+        let n = file.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&chunk[..n]);
+    }
+    file.read(&mut chunk)?;
+    Ok(())
+}